@@ -821,6 +821,45 @@ class GameLogic(object):
             self._validate_all_numeric(p, 'map_min')
         if t := self.special.get('graph_exclude_tags'):
             self._validate_list(t, 'graph_exclude_tags')
+        self.process_monotone()
+
+    def process_monotone(self):
+        """Reads special.monotone, which maps fields to the direction in which they only get better:
+        increasing, decreasing, or exact (the default). 'items' and 'visits' set every item and
+        visited flag, and can be overridden for individual fields listed after them."""
+        mono = self.special.get('monotone', {})
+        if not isinstance(mono, dict):
+            self._errors.append(f'Invalid monotone: {mono!r}')
+            mono = {}
+        fields = {}
+        for name, direction in mono.items():
+            if direction not in ('increasing', 'decreasing', 'exact'):
+                self._errors.append(f'Invalid monotone direction for {name}: {direction!r} '
+                                    '(must be increasing, decreasing, or exact)')
+            elif name == 'items':
+                fields.update((item, direction) for item in self.item_max_counts)
+            elif name == 'visits':
+                fields.update(('VISITED_' + canon, direction) for canon in self.canon_places)
+            elif name in self.item_max_counts:
+                fields[name] = direction
+            elif name == 'position' or name not in self.context_types:
+                self._errors.append(f'Unrecognized monotone field: {name}')
+            elif not re.fullmatch(r'bool|[iu]\d+', self.context_types[name]):
+                self._errors.append(f'Monotone field {name} must be numeric or bool, '
+                                    f'not {self.context_types[name]}')
+            else:
+                fields[name] = direction
+
+        self.monotone_fields = {f: d for f, d in fields.items()
+                                if f not in self.bfp.varmap and d != 'exact'}
+        # (increasing, decreasing) bit masks for each flag group
+        self.monotone_masks = []
+        for group in self.bfp.flag_groups:
+            masks = {'increasing': 0, 'decreasing': 0, 'exact': 0}
+            for i, v in enumerate(group.vars):
+                if d := fields.get(v):
+                    masks[d] |= 1 << i
+            self.monotone_masks.append((masks['increasing'], masks['decreasing']))
 
     def _validate_scale(self, sc, name):
         if not self._validate_pair(sc, name):
@@ -2163,7 +2202,7 @@ class GameLogic(object):
             'benches': ['bench.rs'],
             'bin': ['main.rs'],
             'solutions': [],
-            'tests': ['unittest.rs'],
+            'tests': ['unittest.rs', 'fuzz.rs'],
         }
         reformat = [
             'items.rs',
@@ -0,0 +1,164 @@
+//! Simulated annealing over the order of collection steps in a solution.
+
+use crate::context::*;
+use crate::direct::DirectPaths;
+use crate::minimize::{collection_checkpoints, rediscover_routes};
+use crate::solutions::Solution;
+use crate::steiner::{EdgeId, NodeId, ShortestPaths};
+use crate::util::SplitMix64;
+use crate::world::*;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Debug)]
+pub struct AnnealOptions {
+    pub seed: u64,
+    pub time_budget: Duration,
+    /// Stops after this many iterations, decaying the temperature over iterations instead of time
+    /// so that a seed reproduces the same run.
+    pub max_iterations: Option<usize>,
+    /// Temperature (in ms of route time) at the start of the run.
+    pub initial_temp: f64,
+    /// Temperature (in ms of route time) at the end of the time budget.
+    pub final_temp: f64,
+    pub max_depth: usize,
+    pub max_states: usize,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct AnnealStats {
+    pub iterations: usize,
+    pub accepted: usize,
+    pub worse_accepted: usize,
+    pub failed: usize,
+}
+
+/// Polishes a winning solution by simulated annealing over the order of its collection steps.
+///
+/// Each iteration swaps or moves one collection step, and re-paths between collections from that point on.
+/// Worse routes are accepted with probability `exp(-delta / temp)`, where the temperature decays
+/// geometrically from `initial_temp` to `final_temp` over the time budget (or `max_iterations`, if set).
+///
+/// Calls `on_improvement` with every new best route, and returns the best route found, if it improved
+/// on the original solution.
+pub fn anneal_collection_order<W, T, L>(
+    world: &W,
+    startctx: &T,
+    solution: Arc<Solution<T>>,
+    options: &AnnealOptions,
+    shortest_paths: &ShortestPaths<NodeId<W>, EdgeId<W>>,
+    direct_paths: &impl DirectPaths<W, T>,
+    mut on_improvement: impl FnMut(&ContextWrapper<T>),
+) -> (Option<ContextWrapper<T>>, AnnealStats)
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+{
+    assert!(
+        [options.initial_temp, options.final_temp]
+            .iter()
+            .all(|t| *t > 0.0 && t.is_finite()),
+        "Annealing temperatures must be positive: {} to {}",
+        options.initial_temp,
+        options.final_temp
+    );
+    let start = Instant::now();
+    let mut rng = SplitMix64::new(options.seed);
+    let mut stats = AnnealStats::default();
+    let mut best_elapsed = solution.elapsed;
+    let mut best = None;
+
+    let mut current = solution;
+    let mut colls: Vec<_> =
+        collection_history_with_range_info::<T, _>(current.history.iter().copied()).collect();
+    let mut checkpoints = collection_checkpoints(world, startctx, &current, &colls);
+
+    while colls.len() >= 2
+        && start.elapsed() < options.time_budget
+        && !options
+            .max_iterations
+            .is_some_and(|m| stats.iterations >= m)
+    {
+        let frac = if let Some(m) = options.max_iterations {
+            stats.iterations as f64 / m as f64
+        } else {
+            start.elapsed().as_secs_f64() / options.time_budget.as_secs_f64()
+        };
+        stats.iterations += 1;
+        let temp = options.initial_temp * (options.final_temp / options.initial_temp).powf(frac);
+
+        let n = colls.len();
+        let mut order: Vec<usize> = (0..n).collect();
+        let i = rng.below(n);
+        let mut j = rng.below(n - 1);
+        if j >= i {
+            j += 1;
+        }
+        if rng.below(2) == 0 {
+            order.swap(i, j);
+        } else {
+            let k = order.remove(i);
+            order.insert(j, k);
+        }
+        let first = std::cmp::min(i, j);
+
+        // Worse routes beyond a few temperatures are too unlikely to be accepted to be worth searching for.
+        let max_time = current.elapsed.saturating_add((3.0 * temp) as u32);
+        let Some(mut candidate) = rediscover_routes(
+            world,
+            checkpoints[first].clone(),
+            order[first..].iter().map(|k| &colls[*k]),
+            max_time,
+            options.max_depth,
+            options.max_states,
+            &current.history,
+            shortest_paths,
+            direct_paths,
+        ) else {
+            stats.failed += 1;
+            continue;
+        };
+        // Any steps after the last collection (e.g. reaching a victory spot) need to be replayed too.
+        let tail = colls.last().unwrap().0.end() + 1;
+        if !world.won(candidate.get())
+            && !(candidate.maybe_replay_all(world, &current.history[tail..])
+                && world.won(candidate.get()))
+        {
+            stats.failed += 1;
+            continue;
+        }
+
+        let delta = candidate.elapsed() as f64 - current.elapsed as f64;
+        if delta > 0.0 && rng.unit() >= (-delta / temp).exp() {
+            continue;
+        }
+        stats.accepted += 1;
+        if delta > 0.0 {
+            stats.worse_accepted += 1;
+        }
+        if candidate.elapsed() < best_elapsed {
+            best_elapsed = candidate.elapsed();
+            on_improvement(&candidate);
+            best = Some(candidate.clone());
+        }
+        if stats.accepted % 100 == 0 {
+            log::info!(
+                "Annealing at temp {:.1}: current={}ms best={}ms after {} iterations ({:?})",
+                temp,
+                candidate.elapsed(),
+                best_elapsed,
+                stats.iterations,
+                start.elapsed(),
+            );
+        }
+
+        current = candidate.into_solution();
+        colls =
+            collection_history_with_range_info::<T, _>(current.history.iter().copied()).collect();
+        checkpoints = collection_checkpoints(world, startctx, &current, &colls);
+    }
+
+    (best, stats)
+}
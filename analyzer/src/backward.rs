@@ -0,0 +1,260 @@
+//! Backward reachability from victory over the relaxed access graph.
+//!
+//! Victory is treated as a set of item requirements, each met by collecting the item at any of its
+//! locations or by performing an action that gives it. Edges are reversed, their rules ignored, and
+//! each takes its least time with any movement, so the time from a spot to each required item is a
+//! lower bound, as is the time to the farthest item still needed.
+//!
+//! Warps and global actions are usable from anywhere. Those with fixed destinations are applied per
+//! state. Those whose destinations come from context variables can lead somewhere else later in a
+//! route, so they're treated as free jumps to whichever spot is nearest the item. Rules and
+//! penalties only ever add to base times, so the bound is admissible and the search prunes states
+//! that can't finish within the max time by it.
+//!
+//! Actions are found to give items by performing each once from the start state. An action that
+//! only gives an item in some other state isn't counted as a source.
+
+use crate::context::*;
+use crate::world::*;
+use crate::{new_hashmap, CommonHasher};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+type SpotId<W> = <<W as World>::Exit as Exit>::SpotId;
+type ItemId<W> = <<<W as World>::Location as Accessible>::Context as Ctx>::ItemId;
+
+pub struct BackwardReachability<W: World> {
+    spot_index: HashMap<SpotId<W>, usize, CommonHasher>,
+    /// For each required item, the least time from each spot to collect it by the base edges,
+    /// and the least time from any spot.
+    item_times: HashMap<ItemId<W>, (Vec<u32>, u32), CommonHasher>,
+    /// The least time of any warp or action whose destination comes from a context variable.
+    free_jump: Option<u32>,
+    /// For each spot, the required items farthest from it by the base edges.
+    last_pickups: Vec<Vec<ItemId<W>>>,
+}
+
+/// Computes the least time from each spot to any of the sources, plus the source's own time,
+/// along the reversed edges.
+fn reverse_times(rev: &[Vec<(usize, u32)>], sources: &[(usize, u32)]) -> Vec<u32> {
+    let mut times = vec![u32::MAX; rev.len()];
+    let mut heap = BinaryHeap::new();
+    for &(idx, t) in sources {
+        if t < times[idx] {
+            times[idx] = t;
+            heap.push(Reverse((t, idx)));
+        }
+    }
+    while let Some(Reverse((t, idx))) = heap.pop() {
+        if t > times[idx] {
+            continue;
+        }
+        for &(prev, wt) in &rev[idx] {
+            let nt = t.saturating_add(wt);
+            if nt < times[prev] {
+                times[prev] = nt;
+                heap.push(Reverse((nt, prev)));
+            }
+        }
+    }
+    times
+}
+
+/// Returns the least time of the edge with any movement, as its base time may assume
+/// the default movement.
+fn best_edge_time<W: World>(s: SpotId<W>, t: SpotId<W>, base: u32) -> u32 {
+    let (free, moves) = W::best_movements(s, t);
+    moves
+        .into_iter()
+        .map(|(_, mt)| mt)
+        .chain(free)
+        .fold(base, std::cmp::min)
+}
+
+impl<W: World> BackwardReachability<W> {
+    /// Builds the analysis over the world's base edges, finding which actions give required items
+    /// by performing them from the start state.
+    pub fn new<T>(world: &W, startctx: &T) -> Self
+    where
+        T: Ctx<World = W>,
+        W::Location: Location<Context = T>,
+    {
+        let spots = world.get_all_spots();
+        let mut spot_index = new_hashmap();
+        for (i, &spot) in spots.iter().enumerate() {
+            spot_index.insert(spot, i);
+        }
+
+        let mut rev = vec![Vec::new(); spots.len()];
+        for (s, t, wt) in world.base_edges() {
+            if let (Some(&si), Some(&ti)) = (spot_index.get(&s), spot_index.get(&t)) {
+                rev[ti].push((si, best_edge_time::<W>(s, t, wt)));
+            }
+        }
+
+        // Global actions are performed in place, so they're sources at every spot.
+        let actions: Vec<_> = spots
+            .iter()
+            .flat_map(|&spot| {
+                world
+                    .get_spot_actions(spot)
+                    .iter()
+                    .map(move |act| (act, Some(spot)))
+            })
+            .chain(world.get_global_actions().iter().map(|act| (act, None)))
+            .collect();
+        let free_jump = world
+            .get_warps()
+            .iter()
+            .filter(|wp| wp.dest_from_context())
+            .map(|wp| wp.base_time())
+            .chain(
+                actions
+                    .iter()
+                    .filter(|(act, _)| act.dest_from_context())
+                    .map(|(act, _)| act.base_time()),
+            )
+            .min();
+
+        let mut item_times = new_hashmap();
+        for (item, _) in world.required_items() {
+            let mut sources: Vec<_> = world
+                .get_item_locations(item)
+                .into_iter()
+                .filter_map(|loc_id| {
+                    spot_index
+                        .get(&world.get_location_spot(loc_id))
+                        .map(|&i| (i, world.get_location(loc_id).base_time()))
+                })
+                .collect();
+            for &(act, spot) in &actions {
+                let mut ctx = startctx.clone();
+                if let Some(spot) = spot {
+                    ctx.set_position_raw(spot);
+                }
+                let before = ctx.count(item);
+                act.perform(&mut ctx, world);
+                if ctx.count(item) > before {
+                    match spot.and_then(|spot| spot_index.get(&spot)) {
+                        Some(&i) => sources.push((i, act.base_time())),
+                        None => sources.extend((0..spots.len()).map(|i| (i, act.base_time()))),
+                    }
+                }
+            }
+            let times = reverse_times(&rev, &sources);
+            let nearest = times.iter().copied().min().unwrap_or(u32::MAX);
+            item_times.insert(item, (times, nearest));
+        }
+
+        let last_pickups = (0..spots.len())
+            .map(|i| {
+                let max = item_times.values().map(|(t, _)| t[i]).max().unwrap_or(0);
+                item_times
+                    .iter()
+                    .filter(|(_, (t, _))| t[i] == max)
+                    .map(|(item, _)| *item)
+                    .collect()
+            })
+            .collect();
+
+        log::info!(
+            "Built backward reachability for {} required items over {} spots",
+            item_times.len(),
+            spots.len()
+        );
+        Self {
+            spot_index,
+            item_times,
+            free_jump,
+            last_pickups,
+        }
+    }
+
+    /// Returns a lower bound on the time to win from the given state,
+    /// or u32::MAX if a needed item is unreachable even in the relaxed graph.
+    pub fn lower_bound<T>(&self, world: &W, ctx: &T) -> u32
+    where
+        T: Ctx<World = W>,
+        W::Location: Location<Context = T>,
+    {
+        if world.won(ctx) {
+            return 0;
+        }
+        let Some(&i) = self.spot_index.get(&ctx.position()) else {
+            return 0;
+        };
+        // Any route that warps at some point costs at least its last warp and the time from that
+        // warp's destination, regardless of where it warps from.
+        let jumps: Vec<_> = world
+            .get_warps()
+            .iter()
+            .filter(|wp| !wp.dest_from_context())
+            .map(|wp| (Warp::dest(wp, ctx, world), wp.base_time()))
+            .chain(
+                world
+                    .get_global_actions()
+                    .iter()
+                    .filter(|act| !act.dest_from_context())
+                    .map(|act| (Action::dest(act, ctx, world), act.base_time())),
+            )
+            .filter_map(|(dest, wt)| self.spot_index.get(&dest).map(|&di| (di, wt)))
+            .collect();
+        world
+            .items_needed(ctx)
+            .into_iter()
+            .filter_map(|(item, _)| self.item_times.get(&item))
+            .map(|(t, nearest)| {
+                let free = self
+                    .free_jump
+                    .map_or(u32::MAX, |fj| fj.saturating_add(*nearest));
+                jumps
+                    .iter()
+                    .map(|&(di, wt)| t[di].saturating_add(wt))
+                    .fold(std::cmp::min(t[i], free), std::cmp::min)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the least time to collect the required item from the given spot by the base edges,
+    /// or None if it's not a required item.
+    pub fn time_to_collect(&self, item: ItemId<W>, spot: SpotId<W>) -> Option<u32> {
+        let i = *self.spot_index.get(&spot)?;
+        self.item_times.get(&item).map(|(t, _)| t[i])
+    }
+
+    /// Returns the required items farthest from the given spot by the base edges,
+    /// which set the lower bound there while every required item is still needed.
+    pub fn last_pickups(&self, spot: SpotId<W>) -> &[ItemId<W>] {
+        self.spot_index
+            .get(&spot)
+            .map_or(&[], |&i| &self.last_pickups[i])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reverse_times() {
+        // 0 -> 1 (5), 1 -> 2 (3), 0 -> 2 (10), 3 -> 0 (1); 4 can't reach anything.
+        let mut rev = vec![Vec::new(); 5];
+        for (s, t, wt) in [(0, 1, 5), (1, 2, 3), (0, 2, 10), (3, 0, 1)] {
+            rev[t].push((s, wt));
+        }
+        assert_eq!(reverse_times(&rev, &[(2, 0)]), vec![8, 3, 0, 9, u32::MAX]);
+        // A source's own time counts, and the cheaper source wins.
+        assert_eq!(
+            reverse_times(&rev, &[(2, 4), (1, 0)]),
+            vec![5, 0, 4, 6, u32::MAX]
+        );
+        assert_eq!(reverse_times(&rev, &[]), vec![u32::MAX; 5]);
+    }
+
+    #[test]
+    fn test_reverse_times_saturates() {
+        let rev = vec![vec![(1, u32::MAX)], Vec::new()];
+        assert_eq!(reverse_times(&rev, &[(0, 1)]), vec![1, u32::MAX]);
+    }
+}
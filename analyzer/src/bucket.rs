@@ -414,6 +414,27 @@ pub trait SegmentedBucketQueue<'b, B: SegmentBucket<P> + 'b, P: Ord>: Queue<B> {
         }
     }
 
+    /// Pops up to `counts[s]` of the max elements from each segment `s`.
+    /// Will not completely empty any segment.
+    fn pop_max_by_segment(&mut self, counts: &[usize]) -> Vec<(B::Item, P)> {
+        let mut vec = Vec::with_capacity(counts.iter().sum());
+        for (segment, &count) in counts.iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+            if let Some(bucket) = self.bucket_for_replacing(segment) {
+                let pops = std::cmp::min(count, bucket.len_bucket().saturating_sub(1));
+                for _i in 0..pops {
+                    vec.push(bucket.pop_max().unwrap());
+                }
+                if pops > 0 {
+                    self.items_replaced(segment, pops, 0);
+                }
+            }
+        }
+        vec
+    }
+
     /// Finds the lowest segment S and the highest corresponding segment S' below S
     /// where S-min > S'-max, and evicts all elements below S' with priority > S-max.
     /// Requires that priorities trend to increase as segment increases.
@@ -596,4 +617,20 @@ mod test {
         assert_eq!(queue.pop_max_segment_min(), Some(("fourth", 3)));
         assert_eq!(queue.pop_max_segment_min(), Some(("first", 1)));
     }
+
+    #[test]
+    fn pop_max_by_segment() {
+        let mut queue = BucketQueue::<Segment<&str, i8>>::new();
+        queue.push("first", 0, 1);
+        queue.push("second", 0, 2);
+        queue.push("third", 0, 3);
+        queue.push("fourth", 2, 1);
+        queue.push("fifth", 2, 2);
+
+        let popped = queue.pop_max_by_segment(&[5, 3, 1]);
+        assert_eq!(popped, vec![("third", 3), ("second", 2), ("fifth", 2)]);
+        assert_eq!(queue.len_queue(), 2);
+        assert_eq!(queue.pop_min(), Some(("first", 1)));
+        assert_eq!(queue.pop_min(), Some(("fourth", 1)));
+    }
 }
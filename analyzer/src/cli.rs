@@ -1,28 +1,77 @@
+use crate::anneal::{anneal_collection_order, AnnealOptions};
 use crate::context::*;
-use crate::direct::DirectPathsMap;
+use crate::db::{RouteDb, SolutionDb};
+use crate::dbprofile::DbProfile;
+use crate::direct::{DirectPaths, DirectPathsDb, DirectPathsMap};
+use crate::distributed::run_worker;
+use crate::dot::world_to_dot;
+use crate::encoding::StateEncoding;
 use crate::estimates::ContextScorer;
+use crate::explain::explain_location;
 use crate::greedy::*;
-use crate::heap::MetricType;
+use crate::heap::*;
 use crate::matchertrie::MatcherTrie;
-use crate::minimize::*;
-use crate::observer::{debug_observations, record_observations, TrieMatcher};
+use crate::observer::{
+    debug_observations, record_observations, record_stored_observations, TrieMatcher,
+};
+use crate::passes::*;
 use crate::route::*;
-use crate::scoring::{EstimatorWrapper, ScoreMetric};
-use crate::search::{Search, SearchOptions};
-use crate::solutions::{write_graph, SolutionSuffix};
+use crate::scoring::{EstimatedTimeMetric, EstimatorWrapper, ScoreMetric, TimeSinceAndElapsed};
+use crate::search::{Search, SearchOptions, SOLUTIONS_FILE};
+use crate::settings::parse_size_str;
+use crate::softlock::find_softlocks;
+use crate::solutions::{write_graph, Solution, SolutionSuffix};
+use crate::soundness::{check_observation_soundness, SoundnessOptions};
+use crate::steiner::{EdgeId, NodeId, ShortestPaths};
 use crate::storage::serialize_state;
+use crate::svg::route_to_svg;
 use crate::world::*;
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use rustc_hash::FxHashSet;
 use similar::TextDiff;
 use std::fmt::Debug;
 use std::mem::size_of;
+use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 static DEFAULT_MAX_DEPTH: usize = 4;
 static GREEDY_MAX_DEPTH: usize = 9;
 static SEARCH_MAX_STATES: usize = 16_384;
 static MUTATE_MAX_STATES: usize = 8_192;
+static SOFTLOCK_MAX_STATES: usize = 4_096;
+static EXPLAIN_MAX_ITEMS: usize = 1;
+static WORKER_BATCH_SIZE: usize = 256;
+static WARM_START_ROUTES: usize = 16;
+
+/// The score used to order states in the search queue.
+#[derive(Copy, Clone, Debug, Default, ValueEnum)]
+pub enum Metric {
+    /// Time since the last visit, then total estimated time
+    #[default]
+    TimeSince,
+    /// Total estimated time
+    EstimatedTime,
+}
+
+/// The db backing the search queue.
+#[derive(Copy, Clone, Debug, ValueEnum)]
+pub enum Backend {
+    Rocksdb,
+    /// Requires building with `--features mysql`
+    Mysql,
+}
+
+impl Default for Backend {
+    fn default() -> Self {
+        if cfg!(feature = "mysql") {
+            Backend::Mysql
+        } else {
+            Backend::Rocksdb
+        }
+    }
+}
 
 #[derive(Parser)]
 #[command(about = "Graph algorithm analysis", long_about = None)]
@@ -34,6 +83,11 @@ pub struct Cli {
     #[arg(long, value_name = "FILE")]
     logconfig: Option<PathBuf>,
 
+    /// RocksDB tuning preset (laptop, workstation, server) or yaml file with a profile
+    /// (default: the db section of the settings file, or workstation)
+    #[arg(long, value_name = "PRESET|FILE")]
+    db_profile: Option<String>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -46,6 +100,12 @@ impl Cli {
     pub fn logconfig(&self) -> Option<&PathBuf> {
         self.logconfig.as_ref()
     }
+
+    /// Returns the db tuning from --db-profile or the settings file.
+    pub fn db_profile(&self) -> Result<DbProfile, std::io::Error> {
+        DbProfile::resolve(self.db_profile.as_deref(), self.settings.as_deref())
+            .map_err(std::io::Error::other)
+    }
 }
 
 #[derive(Subcommand)]
@@ -83,6 +143,61 @@ pub enum Commands {
         /// Max number of states to process in a single local search step
         #[arg(long, default_value_t = SEARCH_MAX_STATES)]
         greedy_max_states: usize,
+
+        /// Directory of a db of solutions to load into the solve trie and save new solutions to
+        #[arg(long, value_name = "DIR")]
+        trie_db: Option<PathBuf>,
+
+        /// Rebuild dbs written by a different build of the game by replaying the provided routes
+        /// and the stored solutions, and keep the stored direct routes that still replay
+        #[arg(long)]
+        migrate: bool,
+
+        /// Score used to order states in the queue
+        #[arg(long, value_enum, default_value_t)]
+        metric: Metric,
+
+        /// Db backing the queue
+        #[arg(long, value_enum, default_value_t)]
+        backend: Backend,
+
+        /// Encoding of states in new dbs (existing dbs keep their encoding)
+        #[arg(long, value_enum, default_value_t)]
+        state_encoding: StateEncoding,
+
+        /// Address to accept remote workers on, e.g. 0.0.0.0:7878
+        #[arg(long, value_name = "ADDR")]
+        listen: Option<SocketAddr>,
+
+        /// Skip new states dominated by a faster state at the same position
+        /// (per the game's monotone fields; all other fields must match)
+        #[arg(long)]
+        dominance: bool,
+
+        /// Memory for states held in the queue, e.g. 8G; the rest spill to the db
+        #[arg(long, value_name = "SIZE", default_value = "1G", value_parser = parse_size_str)]
+        queue_memory: usize,
+
+        /// Replay the saved solutions (even from an earlier build of the game) and the best routes
+        /// from the last search's solutions file, and start from their states.
+        /// Implies --migrate, so dbs from a different build are deleted and rebuilt
+        #[arg(long)]
+        warm_start: bool,
+
+        /// Number of routes from the last search's solutions file to replay with --warm-start
+        #[arg(long, default_value_t = WARM_START_ROUTES)]
+        warm_start_routes: usize,
+    },
+
+    /// processes states for a search running elsewhere with --listen
+    Worker {
+        /// Address of the coordinating search
+        #[arg(value_name = "ADDR")]
+        coordinator: String,
+
+        /// Number of states to request at a time
+        #[arg(long, default_value_t = WORKER_BATCH_SIZE)]
+        batch_size: usize,
     },
 
     /// evaluates a route and shows stepwise diffs
@@ -92,6 +207,17 @@ pub enum Commands {
         route: PathBuf,
     },
 
+    /// Repairs a route written against an older version of the game graph
+    MigrateRoute {
+        /// text file with route
+        #[arg(value_name = "FILE")]
+        route: PathBuf,
+
+        /// File to write the repaired route to (default: the route file with extension .migrated.txt)
+        #[arg(long, short, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+
     /// Copies a route into the db. Requires building with --features mysql
     Import {
         /// text file with route
@@ -127,6 +253,118 @@ pub enum Commands {
         /// Max number of states to process in a single local mutate step
         #[arg(long, default_value_t = MUTATE_MAX_STATES)]
         max_states: usize,
+
+        /// yaml file with the list of minimize passes to run in order
+        /// (default: the passes section of the settings file, or the default pipeline)
+        #[arg(long, value_name = "FILE")]
+        passes: Option<PathBuf>,
+
+        /// Directory of a db of solutions to load into the solve trie and save new solutions to
+        #[arg(long, value_name = "DIR")]
+        trie_db: Option<PathBuf>,
+
+        /// Directory of the databases, to reuse and save direct routes found between spots
+        /// (default: keep them in memory for this run only)
+        #[arg(long, value_name = "DIR")]
+        db: Option<PathBuf>,
+    },
+
+    /// Lists the direct routes stored in the route db, or prunes them
+    Routes {
+        /// Directory of the databases
+        #[arg(long, value_name = "DIR")]
+        db: Option<PathBuf>,
+
+        /// Only list routes to this spot
+        #[arg(long, value_name = "SPOT")]
+        dest: Option<String>,
+
+        /// Remove routes that are no longer the best known for any observations
+        /// (with --dest, first forget all routes to that spot)
+        #[arg(long)]
+        prune: bool,
+    },
+
+    /// Polishes the given route by simulated annealing over its collection order (must be a winning route)
+    Anneal {
+        /// text file with winning route
+        #[arg(value_name = "FILE")]
+        route: PathBuf,
+
+        /// Seed for the random number generator (default: based on the current time)
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Time budget in seconds
+        #[arg(long, default_value_t = 3600)]
+        time_limit: u64,
+
+        /// Stop after this many iterations, cooling per iteration instead of over time
+        /// (makes runs with the same seed reproducible)
+        #[arg(long)]
+        max_iterations: Option<usize>,
+
+        /// Starting temperature, in ms of route time
+        #[arg(long, default_value_t = 2000.0, value_parser = parse_temperature)]
+        initial_temp: f64,
+
+        /// Ending temperature, in ms of route time
+        #[arg(long, default_value_t = 10.0, value_parser = parse_temperature)]
+        final_temp: f64,
+
+        /// Max number of actions/warps in a single local mutate step
+        #[arg(long, default_value_t = DEFAULT_MAX_DEPTH)]
+        max_depth: usize,
+
+        /// Max number of states to process in a single local mutate step
+        #[arg(long, default_value_t = MUTATE_MAX_STATES)]
+        max_states: usize,
+
+        /// File to write the best route to whenever it improves
+        #[arg(long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+
+    /// Finds reachable states from which the game can no longer be won
+    Softlocks {
+        /// text file with route to start from
+        #[arg(value_name = "FILE")]
+        route: Option<PathBuf>,
+
+        /// Max number of winnable states to explore
+        #[arg(long, default_value_t = SOFTLOCK_MAX_STATES)]
+        max_states: usize,
+    },
+
+    /// Explains why a location is inaccessible and which additional items would make it accessible
+    Explain {
+        /// The location to explain
+        #[arg(value_name = "LOCATION")]
+        location: String,
+
+        /// text file with route to start from
+        #[arg(long, value_name = "FILE")]
+        route: Option<PathBuf>,
+
+        /// Max number of additional items to try in combination before reducing from all items
+        #[arg(long, default_value_t = EXPLAIN_MAX_ITEMS)]
+        max_items: usize,
+    },
+
+    /// Checks that the observations of each step in the given winning routes cover
+    /// everything its access rules read
+    Soundness {
+        /// text files with winning routes (default: the routes from the settings file)
+        #[arg(value_name = "FILE")]
+        routes: Vec<PathBuf>,
+
+        /// Seed for the random number generator
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Number of mutated states to check per step
+        #[arg(long, default_value_t = 64)]
+        mutants: usize,
     },
 
     /// Creates a graph file of the given route (must be a winning route)
@@ -136,6 +374,32 @@ pub enum Commands {
         route: PathBuf,
     },
 
+    /// Draws the given route as an SVG map over the coordinates of each area it visits
+    Map {
+        /// text file with route
+        #[arg(value_name = "FILE")]
+        route: PathBuf,
+
+        /// File to write the SVG to (default: the route file with the extension .svg)
+        #[arg(long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+
+    /// Writes the world graph as connected at runtime in Graphviz DOT format
+    Graph {
+        /// text file with route whose final state is used for warps and reachability
+        #[arg(long, value_name = "FILE")]
+        route: Option<PathBuf>,
+
+        /// Only draw spots reachable from the state, and edges usable from them
+        #[arg(long)]
+        reachable: bool,
+
+        /// File to write the graph to (default: stdout)
+        #[arg(long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+
     /// Outputs debug info about observations between steps
     Observe {
         /// text file with winning route
@@ -158,6 +422,225 @@ where
         .unwrap_or_else(|e| panic!("Couldn't read from file {:?}: {:?}", p, e))
 }
 
+fn parse_temperature(s: &str) -> Result<f64, String> {
+    match s.parse::<f64>() {
+        Ok(t) if t > 0.0 && t.is_finite() => Ok(t),
+        Ok(t) => Err(format!("temperature must be positive: {}", t)),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn print_route_diff<T: Ctx>(orig: &Solution<T>, best: &ContextWrapper<T>) {
+    let old_hist = history_str::<T, _>(orig.history.iter().copied());
+    let new_hist = history_str::<T, _>(best.recent_history().iter().copied());
+    let text_diff = TextDiff::from_lines(&old_hist, &new_hist);
+    print!(
+        "{}",
+        text_diff.unified_diff().context_radius(3).header(
+            &format!("original [{}ms]", orig.elapsed),
+            &format!(
+                "best [{}ms (-{}ms)]",
+                best.elapsed(),
+                orig.elapsed - best.elapsed()
+            )
+        )
+    );
+}
+
+/// Runs the search with the given metric and queue db.
+fn run_search<'w, W, T, TM, const KS: usize, SM, DB>(
+    world: &'w W,
+    startctx: T,
+    mut route_ctxs: Vec<ContextWrapper<T>>,
+    routes: &[PathBuf],
+    db: &Path,
+    solution_db: Option<SolutionDb<T>>,
+    options: SearchOptions,
+) -> Result<(), std::io::Error>
+where
+    W: World,
+    T: Ctx<World = W>,
+    W::Location: Location<Context = T>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    SM: ScoreMetric<'w, W, T, KS> + 'w,
+    DB: QueueDb<'w, W, T, KS, SM>,
+{
+    // This duplicates the creation later by the heap wrapper.
+    let metric = SM::new(world, &startctx);
+
+    route_ctxs.extend(routes.iter().map(|route| {
+        let rstr = read_from_file(route);
+        match route_from_string(world, &startctx, &rstr, metric.estimator().get_algo()) {
+            Ok(r) => r,
+            Err((r, e)) => {
+                log::error!("Using partial route from {:?}: {}", route, e);
+                r
+            }
+        }
+    }));
+    let search = Search::<W, T, TM, KS, SM, DB>::new(
+        world,
+        startctx,
+        route_ctxs,
+        metric,
+        db,
+        solution_db,
+        options,
+    )?;
+    search.search()
+}
+
+/// Replays the given stale routes, the solutions in the solution db and the first `top_routes`
+/// routes in the last search's solutions file, returning those that still replay in full.
+///
+/// The solutions file lists the last search's full routes fastest first, so its first routes are
+/// the top ones. The route db isn't a source: it only holds the direct routes between spots that
+/// minimize found, which start partway through a route rather than from the start state.
+fn warm_start_routes_from<W, T>(
+    world: &W,
+    startctx: &T,
+    solution_db: Option<&SolutionDb<T>>,
+    stale: Vec<String>,
+    top_routes: usize,
+) -> Result<Vec<ContextWrapper<T>>, std::io::Error>
+where
+    W: World,
+    T: Ctx<World = W>,
+    W::Location: Location<Context = T>,
+{
+    let mut texts = stale;
+    if let Some(sdb) = solution_db {
+        texts.extend(
+            sdb.solutions()
+                .map_err(std::io::Error::other)?
+                .iter()
+                .map(|sol| history_str::<T, _>(sol.history.iter().copied())),
+        );
+    }
+    match std::fs::read_to_string(SOLUTIONS_FILE) {
+        Ok(text) => texts.extend(
+            solutions_from_string(&text)
+                .into_iter()
+                .take(top_routes)
+                .map(|sol| find_route_in_solution_string(sol).trim().to_owned()),
+        ),
+        Err(e) => log::info!(
+            "No routes from {} to warm start from: {}",
+            SOLUTIONS_FILE,
+            e
+        ),
+    }
+    let mut seen = FxHashSet::default();
+    texts.retain(|text| seen.insert(text.trim().to_owned()));
+
+    let scorer = ContextScorer::shortest_paths(world, startctx, 32_768);
+    let ctxs: Vec<_> = texts
+        .iter()
+        .filter_map(
+            |text| match route_from_string(world, startctx, text, scorer.get_algo()) {
+                Ok(ctx) => Some(ctx),
+                Err((_, e)) => {
+                    log::debug!("Route no longer replays: {}", e);
+                    None
+                }
+            },
+        )
+        .collect();
+    log::info!(
+        "Warm start: {} of {} routes still replay, best {:?}",
+        ctxs.len(),
+        texts.len(),
+        ctxs.iter()
+            .filter(|c| world.won(c.get()))
+            .map(|c| c.elapsed())
+            .min()
+    );
+    Ok(ctxs)
+}
+
+/// Runs the minimize passes over a winning route, with the given store of direct paths.
+#[allow(clippy::too_many_arguments)]
+fn run_minimize<W, T, TM, DP>(
+    world: &W,
+    startctx: &T,
+    ctx: ContextWrapper<T>,
+    shortest_paths: &ShortestPaths<NodeId<W>, EdgeId<W>>,
+    direct_paths: &DP,
+    max_depth: usize,
+    max_states: usize,
+    passes: Option<&PathBuf>,
+    settings: Option<&PathBuf>,
+    trie_db: Option<&PathBuf>,
+) -> Result<(), std::io::Error>
+where
+    W: World,
+    T: Ctx<World = W>,
+    W::Location: Location<Context = T>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    DP: DirectPaths<W, T> + Sync,
+{
+    // --passes takes precedence over the passes section of the settings file.
+    let pipeline = match (passes, settings) {
+        (Some(passes), _) => read_pipeline(passes, true),
+        (None, Some(settings)) => read_pipeline(settings, false),
+        (None, None) => Ok(None),
+    }
+    .map_err(|e| std::io::Error::other(format!("Invalid minimize passes: {}", e)))?
+    .unwrap_or_else(default_pipeline);
+    let trie = MatcherTrie::<TM, SolutionSuffix<T>>::default();
+    let solution_db = trie_db
+        .map(SolutionDb::open)
+        .transpose()
+        .map_err(std::io::Error::other)?;
+    if let Some(sdb) = &solution_db {
+        let count = record_stored_observations(startctx, world, sdb, &trie)
+            .map_err(std::io::Error::other)?;
+        println!("Loaded {} stored solutions into the trie", count);
+    }
+    let solution = ctx.to_solution();
+    record_observations(startctx, world, solution.clone(), 0, &trie);
+    println!(
+        "Initial solution ({}ms) of length {} produces trie of size {} depth {} and num values {}",
+        solution.elapsed,
+        solution.history.len(),
+        trie.size(),
+        trie.max_depth(),
+        trie.num_values(),
+    );
+    let env = PassEnv {
+        world,
+        startctx,
+        max_depth,
+        max_states,
+        shortest_paths,
+        direct_paths,
+        trie: &trie,
+    };
+    let (best, reports) = run_pipeline(&env, &pipeline, solution.clone());
+    println!(
+        "After observing new routes, trie has: size {} depth {} and num values {}",
+        trie.size(),
+        trie.max_depth(),
+        trie.num_values(),
+    );
+    println!("{}", format_reports(&reports));
+    if let Some(sdb) = &solution_db {
+        sdb.insert(startctx, world, &solution)
+            .map_err(std::io::Error::other)?;
+        if let Some(best) = &best {
+            sdb.insert(startctx, world, &best.to_solution())
+                .map_err(std::io::Error::other)?;
+        }
+    }
+
+    if let Some(best) = best {
+        print_route_diff(&solution, &best);
+    } else {
+        println!("Could not improve solution.");
+    }
+    Ok(())
+}
+
 pub fn run<W, T, TM, DM>(
     world: &W,
     startctx: T,
@@ -188,48 +671,142 @@ where
             local_max_states,
             mutate_max_states,
             greedy_max_states,
+            trie_db,
+            migrate,
+            metric,
+            backend,
+            state_encoding,
+            listen,
+            dominance,
+            queue_memory,
+            warm_start,
+            warm_start_routes,
         } => {
-            // This duplicates the creation later by the heap wrapper.
-            let metric = MetricType::new(world, &startctx);
-
-            route_ctxs.extend(routes.into_iter().map(|route| {
-                let rstr = read_from_file(route);
-                match route_from_string(world, &startctx, &rstr, metric.estimator().get_algo()) {
-                    Ok(r) => r,
-                    Err((r, e)) => {
-                        log::error!("Using partial route from {:?}: {}", route, e);
-                        r
-                    }
+            let db = db.clone().unwrap_or_else(|| ".db".into());
+            let (solution_db, stale) = match trie_db {
+                Some(p) => {
+                    let (sdb, stale) = SolutionDb::open_keeping_stale(p, *warm_start || *migrate)
+                        .map_err(std::io::Error::other)?;
+                    (Some(sdb), stale)
                 }
-            }));
-            let search = Search::<W, T, TM>::new(
-                world,
-                startctx,
-                route_ctxs,
+                None => (None, Vec::new()),
+            };
+            if *warm_start && !*migrate {
+                log::info!(
+                    "--warm-start implies --migrate: dbs from a different build will be deleted \
+                    and rebuilt from the replayed routes"
+                );
+            }
+            if *warm_start || *migrate {
+                // Migrating only re-checks the stored solutions, not the last search's routes.
+                route_ctxs.extend(warm_start_routes_from::<W, T>(
+                    world,
+                    &startctx,
+                    solution_db.as_ref(),
+                    stale,
+                    if *warm_start { *warm_start_routes } else { 0 },
+                )?);
+            }
+            let options = SearchOptions {
+                mutate_max_depth: *mutate_max_depth,
+                mutate_max_states: *mutate_max_states,
+                local_max_depth: *local_max_depth,
+                local_max_states: *local_max_states,
+                greedy_max_depth: *greedy_max_depth,
+                greedy_max_states: *greedy_max_states,
+                // Dbs from before the edit are rebuilt from the replayed routes.
+                migrate: *migrate || *warm_start,
+                state_encoding: *state_encoding,
+                listen: *listen,
+                dominance: *dominance,
+                queue_memory: *queue_memory,
+                db_profile: args.db_profile()?,
+            };
+            log::info!(
+                "Searching with metric {:?} and backend {:?}",
                 metric,
-                db.as_ref().unwrap_or(&".db".into()),
-                SearchOptions {
-                    mutate_max_depth: *mutate_max_depth,
-                    mutate_max_states: *mutate_max_states,
-                    local_max_depth: *local_max_depth,
-                    local_max_states: *local_max_states,
-                    greedy_max_depth: *greedy_max_depth,
-                    greedy_max_states: *greedy_max_states,
-                },
-            )?;
-            search.search()
+                backend
+            );
+            // The metric and backend only change the type parameters of the search.
+            macro_rules! search_with {
+                ($ks:literal, $sm:ty, $db:ty) => {
+                    run_search::<W, T, TM, $ks, $sm, $db>(
+                        world,
+                        startctx,
+                        route_ctxs,
+                        routes,
+                        &db,
+                        solution_db,
+                        options,
+                    )
+                };
+            }
+            match (metric, backend) {
+                (Metric::TimeSince, Backend::Rocksdb) => {
+                    search_with!(16, TimeSinceAndElapsed<W>, TimeSinceRocksDb<W, T>)
+                }
+                (Metric::EstimatedTime, Backend::Rocksdb) => {
+                    search_with!(12, EstimatedTimeMetric<W>, ElapsedTimeRocksDb<W, T>)
+                }
+                #[cfg(feature = "mysql")]
+                (Metric::TimeSince, Backend::Mysql) => {
+                    search_with!(16, TimeSinceAndElapsed<W>, TimeSinceMySQLDB<W, T>)
+                }
+                #[cfg(feature = "mysql")]
+                (Metric::EstimatedTime, Backend::Mysql) => {
+                    search_with!(12, EstimatedTimeMetric<W>, ElapsedTimeMySQLDB<W, T>)
+                }
+                #[cfg(not(feature = "mysql"))]
+                (_, Backend::Mysql) => Err(std::io::Error::other(
+                    "Backend mysql requires building with feature \"mysql\"",
+                )),
+            }
+        }
+        Commands::Worker {
+            coordinator,
+            batch_size,
+        } => {
+            let processed = run_worker(world, coordinator.as_str(), *batch_size)
+                .map_err(std::io::Error::other)?;
+            log::info!("Worker processed {} states", processed);
+            Ok(())
         }
         Commands::Route { route, .. } => {
-            let metric = MetricType::new(world, &startctx);
+            let scorer = ContextScorer::shortest_paths(world, &startctx, 32_768);
             let rstr = read_from_file(route);
             println!(
                 "{}",
-                match debug_route(world, &startctx, &rstr, metric.estimator(),) {
+                match debug_route(world, &startctx, &rstr, &scorer) {
                     Ok(s) | Err(s) => s,
                 }
             );
             Ok(())
         }
+        Commands::MigrateRoute { route, output } => {
+            let scorer = ContextScorer::shortest_paths(world, &startctx, 32_768);
+            let migrated =
+                migrate_route(world, &startctx, &read_from_file(route), scorer.get_algo());
+            for change in &migrated.changes {
+                println!("{}", change);
+            }
+            let output = output
+                .clone()
+                .unwrap_or_else(|| route.with_extension("migrated.txt"));
+            std::fs::write(&output, &migrated.route)?;
+            println!(
+                "Wrote route with {} change(s) to {:?}: {}ms",
+                migrated.changes.len(),
+                output,
+                migrated.ctx.elapsed()
+            );
+            if !world.won(migrated.ctx.get()) {
+                println!(
+                    "Route did not win: still need {:?}",
+                    world.items_needed(migrated.ctx.get())
+                );
+            }
+            Ok(())
+        }
         #[cfg_attr(not(feature = "mysql"), allow(unused))]
         Commands::Import { route, process } => {
             #[cfg(not(feature = "mysql"))]
@@ -237,7 +814,7 @@ where
 
             #[cfg(feature = "mysql")]
             {
-                let metric = MetricType::new(world, &startctx);
+                let metric = TimeSinceAndElapsed::new(world, &startctx);
                 let rstr = read_from_file(route);
                 let proc = recreate_from_string(world, &startctx, &rstr, metric, *process).unwrap();
                 if *process {
@@ -272,11 +849,13 @@ where
             route,
             max_depth,
             max_states,
+            passes,
+            trie_db,
+            db,
         } => {
             let scorer = ContextScorer::shortest_paths(world, &startctx, 32_768);
             let free_sp = ContextScorer::shortest_paths_tree_free_edges(world, &startctx);
-            let direct_paths = DirectPathsMap::<W, T, DM>::new(free_sp);
-            let mut ctx =
+            let ctx =
                 route_from_string(world, &startctx, &read_from_file(route), scorer.get_algo())
                     .unwrap();
             if !world.won(ctx.get()) {
@@ -284,149 +863,224 @@ where
                 println!("Route did not win: still need {:?}", left);
                 return Ok(());
             }
-            let mut trie = MatcherTrie::<TM, SolutionSuffix<T>>::default();
-            let mut solution = ctx.to_solution();
-            let orig = solution.clone();
-            record_observations(&startctx, world, solution.clone(), 0, &mut trie);
-            println!(
-                "Initial solution ({}ms) of length {} produces trie of size {} depth {} and num values {}",
-                solution.elapsed,
-                solution.history.len(),
-                trie.size(),
-                trie.max_depth(),
-                trie.num_values(),
-            );
-            let mut improvements = Vec::new();
-            if let Some(better) = trie_minimize(world, &startctx, solution.clone(), &trie) {
-                ctx = better;
-                println!(
-                    "Improved route via trie from {}ms to {}ms",
-                    solution.elapsed,
-                    ctx.elapsed()
-                );
-                improvements.push(ctx.clone());
-                solution = ctx.to_solution();
-            }
-            if let Some(better) = mutate_greedy_collections(
-                world,
-                &startctx,
-                solution.elapsed,
-                *max_depth,
-                *max_states,
-                solution.clone(),
-                scorer.get_algo(),
-                &direct_paths,
-            ) {
-                ctx = better;
+            if let Some(db) = db {
+                let (ropts, rcache) = RouteDb::<T>::options(&args.db_profile()?);
+                let route_db =
+                    RouteDb::<T>::open(db, ropts, rcache, false).map_err(std::io::Error::other)?;
+                let direct_paths = DirectPathsDb::new(free_sp, route_db);
+                let (routes, osets) = direct_paths.totals();
                 println!(
-                    "Improved route via greedy single-collection-steps from {}ms to {}ms",
-                    solution.elapsed,
-                    ctx.elapsed()
+                    "Loaded route db with {} routes and {} observation sets",
+                    routes, osets
                 );
-                improvements.push(ctx.clone());
-                solution = ctx.to_solution();
+                run_minimize::<W, T, TM, _>(
+                    world,
+                    &startctx,
+                    ctx,
+                    scorer.get_algo(),
+                    &direct_paths,
+                    *max_depth,
+                    *max_states,
+                    passes.as_ref(),
+                    args.settings_file(),
+                    trie_db.as_ref(),
+                )
+            } else {
+                let direct_paths = DirectPathsMap::<W, T, DM>::new(free_sp);
+                run_minimize::<W, T, TM, _>(
+                    world,
+                    &startctx,
+                    ctx,
+                    scorer.get_algo(),
+                    &direct_paths,
+                    *max_depth,
+                    *max_states,
+                    passes.as_ref(),
+                    args.settings_file(),
+                    trie_db.as_ref(),
+                )
             }
-
-            let mut mutations = mutate_spot_revisits(world, &startctx, solution.clone());
-            let old_len = mutations.len();
-            mutations.retain(|c| world.won(c.get()));
-            let mutations: Vec<_> = mutations.into_iter().map(|m| m.into_solution()).collect();
-            if !mutations.is_empty() {
-                let min = mutations.iter().min_by_key(|sol| sol.elapsed).unwrap();
-                println!(
-                    "Route swapping got {} solutions (best={}ms) and {} partials",
-                    mutations.len(),
-                    min.elapsed,
-                    old_len - mutations.len()
-                );
-                for sol in &mutations {
-                    record_observations(&startctx, world, sol.clone(), 0, &mut trie);
+        }
+        Commands::Routes { db, dest, prune } => {
+            let db = db.clone().unwrap_or_else(|| ".db".into());
+            let dest = match dest
+                .as_deref()
+                .map(<W::Exit as Exit>::SpotId::from_str)
+                .transpose()
+            {
+                Ok(dest) => dest,
+                Err(e) => {
+                    println!("{}", e);
+                    return Ok(());
                 }
+            };
+            let (ropts, rcache) = RouteDb::<T>::options(&args.db_profile()?);
+            let route_db =
+                RouteDb::<T>::open(&db, ropts, rcache, false).map_err(std::io::Error::other)?;
+            if *prune {
+                let (keys, routes) = route_db.prune(dest).map_err(std::io::Error::other)?;
                 println!(
-                    "After observing new routes, trie has: size {} depth {} and num values {}",
-                    trie.size(),
-                    trie.max_depth(),
-                    trie.num_values(),
+                    "Removed {} observation sets and {} routes; {} observation sets remain",
+                    keys,
+                    routes,
+                    route_db.trie_size()
                 );
-                if let Some(better) = trie_minimize(world, &startctx, min.clone(), &trie) {
-                    improvements.push(better);
+            } else {
+                let routes = route_db.list_routes(dest).map_err(std::io::Error::other)?;
+                for r in &routes {
+                    if let Some(d) = r.dest {
+                        println!(
+                            "#{}: {} steps, {}ms to {}, best for {} observation sets",
+                            r.route_id, r.steps, r.time, d, r.references
+                        );
+                    } else {
+                        println!("#{}: {} steps, {}ms, unused", r.route_id, r.steps, r.time);
+                    }
                 }
+                println!("{} routes", routes.len());
             }
-
-            let mut replans = 0;
-            while let Some(alternative) = mutate_canon_locations(
-                world,
-                &startctx,
-                solution.elapsed,
-                *max_depth,
-                *max_states,
-                solution.clone(),
-                scorer.get_algo(),
-                &direct_paths,
-                |_| {},
-            ) {
-                replans += 1;
-                println!(
-                    "With alternative canon locations #{}: {}ms",
-                    replans,
-                    alternative.elapsed()
-                );
-                solution = alternative.to_solution();
-                improvements.push(alternative);
-                record_observations(&startctx, world, solution.clone(), 0, &mut trie);
+            Ok(())
+        }
+        Commands::Anneal {
+            route,
+            seed,
+            time_limit,
+            max_iterations,
+            initial_temp,
+            final_temp,
+            max_depth,
+            max_states,
+            output,
+        } => {
+            let scorer = ContextScorer::shortest_paths(world, &startctx, 32_768);
+            let free_sp = ContextScorer::shortest_paths_tree_free_edges(world, &startctx);
+            let direct_paths = DirectPathsMap::<W, T, DM>::new(free_sp);
+            let ctx =
+                route_from_string(world, &startctx, &read_from_file(route), scorer.get_algo())
+                    .unwrap();
+            if !world.won(ctx.get()) {
+                let left = world.items_needed(ctx.get());
+                println!("Route did not win: still need {:?}", left);
+                return Ok(());
             }
-
-            let mut reorders = 0;
-            while let Some(reordered) = mutate_collection_steps(
+            let seed = seed.unwrap_or_else(|| {
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap()
+                    .as_nanos() as u64
+            });
+            let options = AnnealOptions {
+                seed,
+                time_budget: Duration::from_secs(*time_limit),
+                max_iterations: *max_iterations,
+                initial_temp: *initial_temp,
+                final_temp: *final_temp,
+                max_depth: *max_depth,
+                max_states: *max_states,
+            };
+            log::info!("Annealing with {:?}", options);
+            let orig = ctx.to_solution();
+            let (best, stats) = anneal_collection_order(
                 world,
                 &startctx,
-                solution.elapsed,
-                *max_depth,
-                *max_states,
-                solution.clone(),
+                orig.clone(),
+                &options,
                 scorer.get_algo(),
                 &direct_paths,
-            ) {
-                reorders += 1;
-                println!(
-                    "Reorder got an improvement #{}: {}ms",
-                    reorders,
-                    reordered.elapsed()
-                );
-                solution = reordered.to_solution();
-                improvements.push(reordered);
-                record_observations(&startctx, world, solution.clone(), 0, &mut trie);
-            }
+                |better| {
+                    println!("Annealing found an improvement: {}ms", better.elapsed());
+                    if let Some(path) = output {
+                        std::fs::write(
+                            path,
+                            history_str::<T, _>(better.recent_history().iter().copied()),
+                        )
+                        .unwrap_or_else(|e| panic!("Couldn't write to file {:?}: {:?}", path, e));
+                    }
+                },
+            );
             println!(
-                "After observing new routes, trie has: size {} depth {} and num values {}",
-                trie.size(),
-                trie.max_depth(),
-                trie.num_values(),
+                "Annealing (seed {}) ran {} iterations: {} accepted ({} worse), {} failed re-paths",
+                seed, stats.iterations, stats.accepted, stats.worse_accepted, stats.failed
             );
-            if let Some(better) = trie_minimize(world, &startctx, solution.clone(), &trie) {
-                improvements.push(better);
-            }
-
-            if let Some(best) = improvements.into_iter().min_by_key(|c| c.elapsed()) {
-                let old_hist = history_str::<T, _>(orig.history.iter().copied());
-                let new_hist = history_str::<T, _>(best.recent_history().iter().copied());
-                let text_diff = TextDiff::from_lines(&old_hist, &new_hist);
-                print!(
-                    "{}",
-                    text_diff.unified_diff().context_radius(3).header(
-                        &format!("original [{}ms]", orig.elapsed),
-                        &format!(
-                            "best [{}ms (-{}ms)]",
-                            best.elapsed(),
-                            orig.elapsed - best.elapsed()
-                        )
-                    )
-                );
+            if let Some(best) = best {
+                print_route_diff(&orig, &best);
             } else {
                 println!("Could not improve solution.");
             }
             Ok(())
         }
+        Commands::Softlocks { route, max_states } => {
+            let ctx = if let Some(r) = route {
+                let scorer = ContextScorer::shortest_paths(world, &startctx, 32_768);
+                route_from_string(world, &startctx, &read_from_file(r), scorer.get_algo()).unwrap()
+            } else {
+                ContextWrapper::new(startctx)
+            };
+            let report = find_softlocks(world, ctx, u32::MAX, *max_states);
+            println!("{}", report.summary());
+            Ok(())
+        }
+        Commands::Explain {
+            location,
+            route,
+            max_items,
+        } => {
+            let loc_id = match <W::Location as Location>::LocId::from_str(location) {
+                Ok(loc_id) => loc_id,
+                Err(e) => {
+                    println!("{}", e);
+                    return Ok(());
+                }
+            };
+            let ctx = if let Some(r) = route {
+                let scorer = ContextScorer::shortest_paths(world, &startctx, 32_768);
+                route_from_string(world, &startctx, &read_from_file(r), scorer.get_algo()).unwrap()
+            } else {
+                ContextWrapper::new(startctx)
+            };
+            let explanation = explain_location(world, ctx, loc_id, u32::MAX, *max_items);
+            println!("{}", explanation.summary());
+            Ok(())
+        }
+        Commands::Soundness {
+            routes,
+            seed,
+            mutants,
+        } => {
+            if !routes.is_empty() {
+                let scorer = ContextScorer::shortest_paths(world, &startctx, 32_768);
+                route_ctxs = routes
+                    .iter()
+                    .map(|r| {
+                        route_from_string(world, &startctx, &read_from_file(r), scorer.get_algo())
+                            .unwrap()
+                    })
+                    .collect();
+            }
+            let solutions: Vec<_> = route_ctxs
+                .iter()
+                .filter(|c| world.won(c.get()))
+                .map(|c| c.to_solution())
+                .collect();
+            if solutions.is_empty() {
+                println!("No winning routes to check");
+                return Ok(());
+            }
+            let options = SoundnessOptions {
+                seed: *seed,
+                mutants_per_step: *mutants,
+                ..SoundnessOptions::default()
+            };
+            match check_observation_soundness(world, &startctx, &solutions, &options) {
+                Ok(checked) => println!(
+                    "Checked {} steps in {} solutions: no unsound observations found",
+                    checked,
+                    solutions.len()
+                ),
+                Err(unsound) => println!("{}", unsound.summary()),
+            }
+            Ok(())
+        }
         Commands::Draw { route } => {
             let scorer = ContextScorer::shortest_paths(world, &startctx, 32_768);
             let ctx =
@@ -436,6 +1090,37 @@ where
             write_graph(world, &startctx, ctx.recent_history()).unwrap();
             Ok(())
         }
+        Commands::Map { route, output } => {
+            let scorer = ContextScorer::shortest_paths(world, &startctx, 32_768);
+            let ctx =
+                route_from_string(world, &startctx, &read_from_file(route), scorer.get_algo())
+                    .unwrap();
+            let path = output
+                .clone()
+                .unwrap_or_else(|| route.with_extension("svg"));
+            std::fs::write(&path, route_to_svg(world, &startctx, ctx.recent_history()))?;
+            println!("Wrote route of {}ms to {:?}", ctx.elapsed(), path);
+            Ok(())
+        }
+        Commands::Graph {
+            route,
+            reachable,
+            output,
+        } => {
+            let ctx = if let Some(r) = route {
+                let scorer = ContextScorer::shortest_paths(world, &startctx, 32_768);
+                route_from_string(world, &startctx, &read_from_file(r), scorer.get_algo()).unwrap()
+            } else {
+                ContextWrapper::new(startctx)
+            };
+            let dot = world_to_dot(world, &ctx, *reachable);
+            if let Some(path) = output {
+                std::fs::write(path, dot)?;
+            } else {
+                print!("{}", dot);
+            }
+            Ok(())
+        }
         Commands::Observe { route } => {
             let scorer = ContextScorer::shortest_paths(world, &startctx, 32_768);
             let ctx =
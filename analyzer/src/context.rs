@@ -51,6 +51,9 @@ pub trait Ctx:
     // test helpers for context vars
     fn parse_set_context(&mut self, ckey: &str, cval: &Yaml) -> Result<(), String>;
     fn parse_expect_context(ckey: &str, cval: &Yaml) -> Result<Self::Expectation, String>;
+    /// Sets one of the context vars other than position, chosen by `field`, to a value derived
+    /// from `value`. Does nothing if there are no such vars.
+    fn mutate_context(&mut self, field: usize, value: u64);
     fn assert_expectations(&self, exps: &Vec<Self::Expectation>) -> Result<(), String>;
 
     //fn build_verify_func(ckey: &str, cval: &Yaml) -> Result<impl Fn(&Self) -> bool, String>;
@@ -173,6 +176,12 @@ pub trait Ctx:
     fn count_visits(&self) -> usize;
     fn progress(&self) -> u32;
 
+    /// Returns whether this state can do anything the other can, i.e. every field the game
+    /// declares monotone is at least as good here, and every other field is equal.
+    fn dominates(&self, other: &Self) -> bool {
+        self == other
+    }
+
     fn diff(&self, old: &Self) -> String;
 
     /// Observes the access checks and, if they pass, any side effects of the step.
@@ -1055,6 +1064,15 @@ impl<T: Ctx> ContextWrapper<T> {
     }
 }
 
+/// Compares bitflags for `Ctx::dominates`: bits in `increasing` may be set in `a` and not `b`,
+/// bits in `decreasing` may be set in `b` and not `a`, and all other bits must match.
+pub fn dominates_bits(a: u64, b: u64, increasing: u64, decreasing: u64) -> bool {
+    let exact = !(increasing | decreasing);
+    (a & exact) == (b & exact)
+        && (a & b & increasing) == (b & increasing)
+        && (a & b & decreasing) == (a & decreasing)
+}
+
 pub fn history_str<T, I>(history: I) -> String
 where
     T: Ctx,
@@ -1299,3 +1317,45 @@ where
         time: ctx.elapsed(),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dominates_bits_exact() {
+        assert!(dominates_bits(0b1010, 0b1010, 0, 0));
+        assert!(!dominates_bits(0b1010, 0b1000, 0, 0));
+        assert!(!dominates_bits(0b1000, 0b1010, 0, 0));
+    }
+
+    #[test]
+    fn test_dominates_bits_increasing() {
+        // Bit 1 may only be gained, bit 3 must match.
+        assert!(dominates_bits(0b1010, 0b1000, 0b0010, 0));
+        assert!(dominates_bits(0b1010, 0b1010, 0b0010, 0));
+        assert!(!dominates_bits(0b1000, 0b1010, 0b0010, 0));
+        assert!(!dominates_bits(0b0010, 0b1000, 0b0010, 0));
+    }
+
+    #[test]
+    fn test_dominates_bits_decreasing() {
+        // Bit 1 may only be lost, bit 3 must match.
+        assert!(dominates_bits(0b1000, 0b1010, 0, 0b0010));
+        assert!(dominates_bits(0b1010, 0b1010, 0, 0b0010));
+        assert!(!dominates_bits(0b1010, 0b1000, 0, 0b0010));
+        assert!(!dominates_bits(0b0000, 0b1010, 0, 0b0010));
+    }
+
+    #[test]
+    fn test_dominates_bits_mixed() {
+        let (inc, dec) = (0b0011, 0b1100);
+        assert!(dominates_bits(0b0011, 0b1100, inc, dec));
+        assert!(dominates_bits(0b0001, 0b0100, inc, dec));
+        // Each direction is checked per bit.
+        assert!(!dominates_bits(0b0010, 0b0001, inc, dec));
+        assert!(!dominates_bits(0b0100, 0b1000, inc, dec));
+        // Bits outside either mask still must match.
+        assert!(!dominates_bits(0b1_0011, 0b1100, inc, dec));
+    }
+}
@@ -2,10 +2,13 @@
 extern crate rocksdb;
 
 use crate::context::*;
+use crate::dbprofile::DbProfile;
+use crate::encoding::{resolve_codec, EncodingRecord, StateCodec};
 use crate::matchertrie::{MatcherRocksDb, MatcherTrieDb};
-use crate::observer::short_observations;
+use crate::observer::{for_each_solution_observation, short_observations};
 use crate::route::{PartialRoute, RouteStep};
 use crate::scoring::*;
+use crate::solutions::Solution;
 use crate::storage::*;
 use crate::world::*;
 use crate::{new_hashmap, CommonHasher};
@@ -18,7 +21,7 @@ use plotlib::view::ContinuousView;
 use rmp_serde::Deserializer;
 use rocksdb::{
     perf, BlockBasedOptions, Cache, ColumnFamily, ColumnFamilyDescriptor, Env, IteratorMode,
-    MergeOperands, Options, ReadOptions, WriteBatchWithTransaction, WriteOptions, DB,
+    MergeOperands, Options, PrefixRange, ReadOptions, WriteBatchWithTransaction, WriteOptions, DB,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -27,17 +30,25 @@ use std::marker::PhantomData;
 use std::ops::Range;
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering};
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 const KB: usize = 1 << 10;
-const MB: usize = 1 << 20;
-const GB: usize = 1 << 30;
 const BEST: &str = "best";
 const NEXT: &str = "next";
+const DOMINANCE: &str = "dominance";
 const ROUTE: &str = "route";
 const TRIE: &str = "trie";
+const SOLUTION: &str = "solution";
+pub(crate) const METADATA_KEY: &[u8] = b"metadata";
+const ENCODING_KEY: &[u8] = b"encoding";
+const QUEUE_DIR: &str = "queue";
+pub(crate) const STATES_DIR: &str = "states";
+pub(crate) const ROUTES_DIR: &str = "routes";
+const SOLUTIONS_NAME: &str = "solutions";
 const TOO_MANY_STEPS: usize = 1024 << 3;
+/// How many seen states at the same position to compare against before giving up.
+const DOMINANCE_SCAN_LIMIT: usize = 256;
 
 // We need the following in this wrapper impl:
 // 1. The queue db is mainly iterated over, via either
@@ -49,6 +60,7 @@ const TOO_MANY_STEPS: usize = 1024 << 3;
 // 1. the queue: (progress, elapsed, seq) -> Ctx
 // 2. next: (Ctx, history step) -> (elapsed, Ctx)
 // 3. best: Ctx -> (elapsed, history step, prev Ctx)
+// 4. dominance (optional): (position, elapsed, Ctx) -> ()
 
 fn min_merge(
     _new_key: &[u8],
@@ -70,6 +82,175 @@ fn min_merge(
     }
 }
 
+/// Identifies the build of the game that wrote a db.
+///
+/// Serialized contexts and ids are only meaningful to the same build, so dbs written by
+/// another build can't be trusted.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DbMetadata {
+    pub version: String,
+    pub ctx_size: usize,
+    /// The key size of the score metric that ordered the states, or 0 for dbs not ordered by
+    /// score. States queued by one metric are keyed by scores the other can't read.
+    #[serde(default)]
+    pub key_size: usize,
+}
+
+impl DbMetadata {
+    pub fn current<T: Ctx>() -> Self {
+        DbMetadata {
+            version: <T::World as World>::VERSION.to_owned(),
+            ctx_size: std::mem::size_of::<T>(),
+            key_size: 0,
+        }
+    }
+
+    /// The metadata of a state db whose states are ordered by a metric with the given key size.
+    pub fn for_states<T: Ctx>(key_size: usize) -> Self {
+        DbMetadata {
+            key_size,
+            ..Self::current::<T>()
+        }
+    }
+}
+
+impl std::fmt::Display for DbMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "version {} (context size {}",
+            self.version, self.ctx_size
+        )?;
+        if self.key_size > 0 {
+            write!(f, ", metric key size {}", self.key_size)?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[derive(Debug)]
+pub struct VersionMismatch {
+    pub db: String,
+    /// The metadata the db was stamped with, or None if it has data but no metadata.
+    pub stored: Option<DbMetadata>,
+    pub current: DbMetadata,
+}
+
+impl std::fmt::Display for VersionMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.stored {
+            Some(stored) => write!(
+                f,
+                "{} db was written by {}, but this is {}",
+                self.db, stored, self.current
+            ),
+            None => write!(
+                f,
+                "{} db has no build metadata, so it was written by an older build, but this is {}",
+                self.db, self.current
+            ),
+        }
+    }
+}
+
+impl std::error::Error for VersionMismatch {}
+
+/// Returns whether the db has no keys in the default column family or any of the given ones.
+fn is_empty_db(db: &DB, cfs: &[&str]) -> bool {
+    db.iterator(IteratorMode::Start).next().is_none()
+        && cfs.iter().all(|cf| {
+            db.cf_handle(cf)
+                .is_none_or(|cf| db.iterator_cf(cf, IteratorMode::Start).next().is_none())
+        })
+}
+
+/// Returns whether the db has metadata, or a `VersionMismatch` error if it was written by a different
+/// build. A db with data in any of the given column families but no metadata was written before dbs
+/// were stamped, so it's treated as a different build.
+fn verify_metadata(db: &DB, name: &str, cfs: &[&str], current: DbMetadata) -> Result<bool> {
+    let Some(data) = db.get(METADATA_KEY)? else {
+        if is_empty_db(db, cfs) {
+            return Ok(false);
+        }
+        return Err(Error::new(VersionMismatch {
+            db: name.to_owned(),
+            stored: None,
+            current,
+        }));
+    };
+    let stored: DbMetadata = get_obj_from_data(&data)?;
+    if stored != current {
+        return Err(Error::new(VersionMismatch {
+            db: name.to_owned(),
+            stored: Some(stored),
+            current,
+        }));
+    }
+    Ok(true)
+}
+
+/// Stamps the db with the current metadata if it was just created,
+/// or returns a `VersionMismatch` error if it was written by a different build.
+fn check_metadata(db: &DB, name: &str, cfs: &[&str], current: DbMetadata) -> Result<()> {
+    if !verify_metadata(db, name, cfs, current.clone())? {
+        db.put(METADATA_KEY, serialize_data(current))?;
+    }
+    Ok(())
+}
+
+/// Returns the codec the state db was written with, recording the given codec if the db is new.
+///
+/// Dbs with states but no recorded encoding predate encoding records and use MessagePack.
+fn open_codec(db: &DB, codec: StateCodec, has_states: bool) -> Result<StateCodec> {
+    let record: EncodingRecord = match db.get(ENCODING_KEY)? {
+        Some(data) => get_obj_from_data(&data)?,
+        None if has_states => StateCodec::rmp().record().clone(),
+        None => {
+            db.put(ENCODING_KEY, serialize_data(codec.record()))?;
+            return Ok(codec);
+        }
+    };
+    Ok(resolve_codec(record, codec.encoding()))
+}
+
+/// Reads the encoding recorded by the existing state db in the given directory without opening it
+/// for writing, or None if there's no state db yet.
+///
+/// Dbs with states but no recorded encoding predate encoding records and use MessagePack.
+pub fn read_db_encoding<P: AsRef<Path>>(p: P) -> Result<Option<EncodingRecord>> {
+    let mut path = p.as_ref().to_owned();
+    path.push(STATES_DIR);
+    if !std::fs::exists(&path)? {
+        return Ok(None);
+    }
+    let opts = Options::default();
+    let cfs = DB::list_cf(&opts, &path)?;
+    let db = DB::open_cf_for_read_only(&opts, &path, &cfs, false)?;
+    Ok(Some(match db.get(ENCODING_KEY)? {
+        Some(data) => get_obj_from_data(&data)?,
+        None => StateCodec::rmp().record().clone(),
+    }))
+}
+
+/// Checks the metadata of the existing state db in the given directory without opening it for writing.
+///
+/// Returns a `VersionMismatch` error if it was written by a different build, or its states were
+/// ordered by a metric with a different key size. The route db isn't checked, since it discards
+/// its own routes when opened by a different build.
+pub fn check_db_dir<T: Ctx, P: AsRef<Path>>(p: P, key_size: usize) -> Result<()> {
+    let mut path = p.as_ref().to_owned();
+    path.push(STATES_DIR);
+    if !std::fs::exists(&path)? {
+        return Ok(());
+    }
+    let opts = Options::default();
+    let cfs = DB::list_cf(&opts, &path)?;
+    let db = DB::open_cf_for_read_only(&opts, &path, &cfs, false)?;
+    let cfs: Vec<_> = cfs.iter().map(String::as_str).collect();
+    verify_metadata(&db, STATES_DIR, &cfs, DbMetadata::for_states::<T>(key_size))?;
+    Ok(())
+}
+
 // Essentially a workaround for inherent associated types.
 pub trait HeapMetric {
     type Score: Copy + Debug + Ord;
@@ -111,14 +292,18 @@ pub struct HeapDB<'w, W: World + 'w, T: Ctx, const KS: usize, SM> {
     statedb: DB,
     _cache: Cache,
     _state_cache: Cache,
+    cache_size: usize,
     write_opts: WriteOptions,
 
     max_time: AtomicU32,
 
     metric: SM,
+    codec: StateCodec,
+    dominance: bool,
     recovery: AtomicBool,
     cached_estimates: CachedEstimates,
     iskips: AtomicUsize,
+    dom_iskips: AtomicUsize,
     pskips: AtomicUsize,
     dup_iskips: AtomicUsize,
     dup_pskips: AtomicUsize,
@@ -160,6 +345,14 @@ where
         &self.metric
     }
 
+    fn encode_state(&self, el: &T) -> Result<Vec<u8>> {
+        self.codec.encode(el)
+    }
+
+    fn decode_state(&self, buf: &[u8]) -> Result<T> {
+        self.codec.decode(buf)
+    }
+
     /// Returns the number of elements in the heap (tracked separately from the db).
     fn len(&self) -> usize {
         self.cached_estimates.size.load(Ordering::Acquire)
@@ -238,13 +431,23 @@ where
 
     fn extra_stats(&self) -> String {
         format!(
-            "skips: push: {} time, {} dups; pop: {} time, {} dups; readds={}; bgdel={}",
+            "skips: push: {} time, {} dups, {} dominated; pop: {} time, {} dups; readds={}; bgdel={}; {:?} states on disk: {}",
             self.iskips.load(Ordering::Acquire),
             self.dup_iskips.load(Ordering::Acquire),
+            self.dom_iskips.load(Ordering::Acquire),
             self.pskips.load(Ordering::Acquire),
             self.dup_pskips.load(Ordering::Acquire),
             self.readds.load(Ordering::Acquire),
-            self.bg_deletes.load(Ordering::Acquire)
+            self.bg_deletes.load(Ordering::Acquire),
+            self.codec.encoding(),
+            SizeFormatter::new(
+                self.statedb
+                    .property_int_value("rocksdb.total-sst-files-size")
+                    .ok()
+                    .flatten()
+                    .unwrap_or(0),
+                BINARY
+            )
         )
     }
 
@@ -278,7 +481,7 @@ where
 
     fn estimated_remaining_time(&self, ctx: &T) -> u32 {
         if let Some(sd) = self
-            .get_deserialize_state_data(&serialize_state(ctx))
+            .get_deserialize_state_data(&self.encode_state(ctx).unwrap())
             .unwrap()
         {
             sd.estimated_remaining
@@ -314,7 +517,7 @@ where
         else {
             return Err(Error::msg(format!(
                 "Could not find state entry for {:?}",
-                deserialize_state::<T>(state_key)
+                self.decode_state(state_key)
                     .expect("Failed to deserialize while reporting an error")
             )));
         };
@@ -352,7 +555,7 @@ where
             } else {
                 return Err(Error::msg(format!(
                     "Could not find intermediate state entry for {:?}",
-                    deserialize_state::<T>(&prev)
+                    self.decode_state(&prev)
                         .expect("Failed to deserialize while reporting an error")
                 )));
             }
@@ -364,7 +567,7 @@ where
 
     fn get_last_history_step(&self, el: &T) -> Result<Option<HistoryAlias<T>>> {
         Ok(self
-            .get_deserialize_state_data(&serialize_state(el))?
+            .get_deserialize_state_data(&self.encode_state(el)?)?
             .and_then(|sd| sd.hist.last().copied()))
     }
 
@@ -383,7 +586,7 @@ where
             return Ok(());
         }
         let key = self.metric.get_heap_key(el.get(), score);
-        let val = serialize_state(el.get());
+        let val = self.encode_state(el.get())?;
         self.db.put_opt(key, val, &self.write_opts)?;
         self.cached_estimates.size.fetch_add(1, Ordering::Release);
         Ok(())
@@ -421,7 +624,7 @@ where
                 log::debug!("Compacting took {:?}", start.elapsed());
             }
 
-            let el = deserialize_state(&value)?;
+            let el = self.decode_state(&value)?;
             let BestTimes {
                 elapsed,
                 time_since_visit,
@@ -480,7 +683,7 @@ where
                 continue;
             }
 
-            let val = serialize_state(&el);
+            let val = self.encode_state(&el)?;
 
             if self.was_processed_raw(&val).unwrap() {
                 dups += 1;
@@ -547,7 +750,7 @@ where
         batch.delete(key);
 
         let mut res = Vec::with_capacity(count);
-        let el = deserialize_state(&value)?;
+        let el = self.decode_state(&value)?;
         let score = self.lookup_score_raw(&value)?;
         let max_time = self.max_time();
         if self.metric.total_estimate_from_score(score) > max_time {
@@ -574,7 +777,7 @@ where
                     batch.delete(key);
                     pops += 1;
 
-                    let el = match deserialize_state(&value) {
+                    let el = match self.decode_state(&value) {
                         Ok(el) => el,
                         Err(e) => {
                             log::error!("Corrupt value in queue: {}\n{:?}", e, value);
@@ -646,11 +849,11 @@ where
         el: &mut ContextWrapper<T>,
         prev: Option<&T>,
     ) -> Result<Option<SM::Score>> {
-        let state_key = serialize_state(el.get());
+        let state_key = self.encode_state(el.get())?;
 
         // Look up the prev state and recalculate time_since and elapsed in case prev was improved since
         let (prev_key, best_since_from_prev, best_elapsed_from_prev) = if let Some(c) = prev {
-            let prev_key = serialize_state(c);
+            let prev_key = self.encode_state(c)?;
             if let Some(sd) = self.get_deserialize_state_data(&prev_key).unwrap() {
                 (
                     prev_key,
@@ -688,6 +891,14 @@ where
             // state not seen before, determine time remaining
             (true, 0, self.metric.estimated_remaining_time(el.get()))
         };
+        if self.dominance {
+            // Improvements to states already seen are kept without checking.
+            if is_new && self.is_dominated(el.get(), best_elapsed_from_prev, &state_key)? {
+                self.dom_iskips.fetch_add(1, Ordering::Release);
+                return Ok(None);
+            }
+            self.index_dominance(el.get(), best_elapsed_from_prev, old_elapsed, &state_key)?;
+        }
         // In every other case (no such state, or we do better than that state),
         // we will rewrite the data.
 
@@ -739,18 +950,22 @@ where
         let max_time = self.max_time();
         let mut results = Vec::with_capacity(vec.len());
         let mut dups = 0;
+        let mut dominated = 0;
         let mut new_seen = 0;
         let cf = self.best_cf();
 
         // sorting doesn't have an advantage except when states are identical
         vec.sort_by_key(ContextWrapper::elapsed);
-        let prev_key = serialize_state(prev);
+        let prev_key = self.encode_state(prev)?;
         let prev_scoreinfo = self
             .get_deserialize_state_data(&prev_key)
             .unwrap()
             .map(|sd| (sd.time_since_visit, sd.elapsed));
 
-        let seeing: Vec<_> = vec.iter().map(|el| serialize_state(el.get())).collect();
+        let seeing = vec
+            .iter()
+            .map(|el| self.encode_state(el.get()))
+            .collect::<Result<Vec<_>>>()?;
 
         let seen_values = self.get_state_values(cf, seeing.iter())?;
 
@@ -790,10 +1005,18 @@ where
                 }
                 (elapsed, estimated_remaining)
             } else {
+                if self.dominance && self.is_dominated(el.get(), best_elapsed, &state_key)? {
+                    results.push(None);
+                    dominated += 1;
+                    continue;
+                }
                 // state not seen before, determine time remaining
                 new_seen += 1;
                 (0, self.metric.estimated_remaining_time(el.get()))
             };
+            if self.dominance {
+                self.index_dominance(el.get(), best_elapsed, old_elapsed, &state_key)?;
+            }
             // In every other case (no such state, or we do better than that state),
             // we will rewrite the data.
             self.record_one_internal(
@@ -838,6 +1061,7 @@ where
             .fetch_add(1, Ordering::Release);
 
         self.dup_iskips.fetch_add(dups, Ordering::Release);
+        self.dom_iskips.fetch_add(dominated, Ordering::Release);
         self.cached_estimates
             .seen
             .fetch_add(new_seen, Ordering::Release);
@@ -903,7 +1127,7 @@ where
                         batch.delete(&key);
                         rescores += 1;
                     }
-                    if !compact && self._cache.get_usage() > 2 * GB {
+                    if !compact && self._cache.get_usage() > self.cache_size {
                         compact = true;
                     }
                 } else {
@@ -981,13 +1205,17 @@ where
                     .processed
                     .fetch_add(1, Ordering::Release);
             } else {
-                let state: T = get_obj_from_data(key.as_ref()).unwrap();
+                let state = self.decode_state(key.as_ref()).unwrap();
                 let data: StateDataAlias<T> = get_obj_from_data(val.as_ref()).unwrap();
                 let score = SM::score_from_times(data.best_times());
                 let heap_key_min = self.metric().get_heap_key(&state, score);
                 if self
                     .db
-                    .put_opt(&heap_key_min, serialize_state(&state), &self.write_opts)
+                    .put_opt(
+                        &heap_key_min,
+                        self.encode_state(&state).unwrap(),
+                        &self.write_opts,
+                    )
                     .is_ok()
                 {
                     self.cached_estimates.size.fetch_add(1, Ordering::Release);
@@ -1014,45 +1242,43 @@ where
         initial_max_time: u32,
         metric: SM,
         delete_first: bool,
+        codec: StateCodec,
+        dominance: bool,
+        profile: &DbProfile,
     ) -> Result<HeapDB<'w, W, T, KS, SM>>
     where
         P: AsRef<Path>,
     {
+        log::info!("Opening queue and state dbs with {}", profile);
         let mut opts = Options::default();
         opts.create_if_missing(true);
         // change compression options?
-        // 4 write buffers at 256 MiB = 1 GiB
-        opts.set_write_buffer_size(256 * MB);
-        opts.set_max_write_buffer_number(4);
-        opts.set_target_file_size_base(128 * 1024 * 1024);
-        // use half the logical cores, clamp between 2 and 32
-        opts.increase_parallelism(std::cmp::max(
-            2,
-            std::cmp::min(num_cpus::get() / 2, 32).try_into().unwrap(),
-        ));
-        opts.set_max_background_jobs(8);
+        opts.set_write_buffer_size(profile.write_buffer_size);
+        opts.set_max_write_buffer_number(profile.max_write_buffers);
+        opts.set_target_file_size_base(profile.target_file_size as u64);
+        opts.increase_parallelism(profile.parallelism());
+        opts.set_max_background_jobs(profile.max_background_jobs);
 
         let mut env = Env::new().unwrap();
-        env.set_low_priority_background_threads(6);
+        env.set_low_priority_background_threads(profile.low_priority_threads);
         opts.set_env(&env);
-        opts.set_max_open_files(1024);
+        opts.set_max_open_files(profile.max_open_files);
 
         let mut opts2 = opts.clone();
 
         let mut block_opts = BlockBasedOptions::default();
-        // blockdb caches = 2 GiB
-        let cache = Cache::new_lru_cache(2 * GB);
+        let cache = Cache::new_lru_cache(profile.queue_cache);
         block_opts.set_block_cache(&cache);
-        block_opts.set_block_size(16 * 1024);
+        block_opts.set_block_size(profile.block_size);
         block_opts.set_cache_index_and_filter_blocks(true);
         block_opts.set_pin_l0_filter_and_index_blocks_in_cache(true);
-        block_opts.set_ribbon_filter(9.9);
+        block_opts.set_ribbon_filter(profile.ribbon_filter_bits);
         opts.set_block_based_table_factory(&block_opts);
 
         let mut path = p.as_ref().to_owned();
         let mut path2 = path.clone();
-        path.push("queue");
-        path2.push("states");
+        path.push(QUEUE_DIR);
+        path2.push(STATES_DIR);
 
         let recovery = if delete_first {
             let _ = DB::destroy(&opts, &path);
@@ -1065,19 +1291,17 @@ where
             false
         };
 
-        // 1 + 2 = 3 GiB roughly for this db
         let db = DB::open(&opts, &path)?;
 
         opts2.set_merge_operator_associative("min", min_merge);
 
         let mut block_opts2 = BlockBasedOptions::default();
-        // blockdb caches = 5 GiB
-        let blockdb_cache = Cache::new_lru_cache(5 * GB);
+        let blockdb_cache = Cache::new_lru_cache(profile.state_cache);
         block_opts2.set_block_cache(&blockdb_cache);
-        block_opts2.set_block_size(16 * 1024);
+        block_opts2.set_block_size(profile.block_size);
         block_opts2.set_cache_index_and_filter_blocks(true);
         block_opts2.set_pin_l0_filter_and_index_blocks_in_cache(true);
-        block_opts2.set_ribbon_filter(9.9);
+        block_opts2.set_ribbon_filter(profile.ribbon_filter_bits);
         opts2.set_block_based_table_factory(&block_opts2);
 
         let cf_opts = opts2.clone();
@@ -1085,10 +1309,18 @@ where
         opts2.create_missing_column_families(true);
 
         let bestcf = ColumnFamilyDescriptor::new(BEST, cf_opts.clone());
-        let nextcf = ColumnFamilyDescriptor::new(NEXT, cf_opts);
-
-        // Same 1 + 2 = 3 GiB for this one
-        let statedb = DB::open_cf_descriptors(&opts2, &path2, vec![bestcf, nextcf])?;
+        let nextcf = ColumnFamilyDescriptor::new(NEXT, cf_opts.clone());
+        let domcf = ColumnFamilyDescriptor::new(DOMINANCE, cf_opts);
+
+        let statedb = DB::open_cf_descriptors(&opts2, &path2, vec![bestcf, nextcf, domcf])?;
+        // The queue db is only valid alongside the state db, so the latter holds the metadata.
+        check_metadata(
+            &statedb,
+            STATES_DIR,
+            &[BEST, NEXT, DOMINANCE],
+            DbMetadata::for_states::<T>(KS),
+        )?;
+        let codec = open_codec(&statedb, codec, recovery)?;
 
         let mut write_opts = WriteOptions::default();
         write_opts.disable_wal(true);
@@ -1106,12 +1338,16 @@ where
             statedb,
             _cache: cache,
             _state_cache: blockdb_cache,
+            cache_size: profile.queue_cache,
             write_opts,
             max_time: initial_max_time.into(),
             metric,
+            codec,
+            dominance,
             recovery: recovery.into(),
             cached_estimates,
             iskips: 0.into(),
+            dom_iskips: 0.into(),
             pskips: 0.into(),
             dup_iskips: 0.into(),
             dup_pskips: 0.into(),
@@ -1132,8 +1368,68 @@ where
         self.statedb.cf_handle(NEXT).unwrap()
     }
 
+    fn dominance_cf(&self) -> &ColumnFamily {
+        self.statedb.cf_handle(DOMINANCE).unwrap()
+    }
+
+    fn dominance_key(position: &[u8], elapsed: u32, state_key: &[u8]) -> Vec<u8> {
+        let mut key = Vec::with_capacity(position.len() + 4 + state_key.len());
+        key.extend_from_slice(position);
+        key.extend_from_slice(&elapsed.to_be_bytes());
+        key.extend_from_slice(state_key);
+        key
+    }
+
+    /// Returns whether a state seen at the same position with no more elapsed time dominates
+    /// the given state. Only the fastest few such states are compared.
+    fn is_dominated(&self, ctx: &T, elapsed: u32, state_key: &[u8]) -> Result<bool> {
+        let position = serialize_data(ctx.position());
+        let mut upper = position.clone();
+        upper.extend_from_slice(&elapsed.saturating_add(1).to_be_bytes());
+        let mut read_opts = ReadOptions::default();
+        read_opts.set_iterate_lower_bound(position.clone());
+        read_opts.set_iterate_upper_bound(upper);
+        let iter =
+            self.statedb
+                .iterator_cf_opt(self.dominance_cf(), read_opts, IteratorMode::Start);
+        for item in iter.take(DOMINANCE_SCAN_LIMIT) {
+            let (key, _) = item?;
+            let seen_key = &key[position.len() + 4..];
+            if seen_key != state_key && self.decode_state(seen_key)?.dominates(ctx) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Indexes the state by position and its new best time, replacing the entry for its old time.
+    fn index_dominance(
+        &self,
+        ctx: &T,
+        elapsed: u32,
+        old_elapsed: u32,
+        state_key: &[u8],
+    ) -> Result<()> {
+        let position = serialize_data(ctx.position());
+        let cf = self.dominance_cf();
+        if old_elapsed > elapsed {
+            self.statedb.delete_cf_opt(
+                cf,
+                Self::dominance_key(&position, old_elapsed, state_key),
+                &self.write_opts,
+            )?;
+        }
+        self.statedb.put_cf_opt(
+            cf,
+            Self::dominance_key(&position, elapsed, state_key),
+            [],
+            &self.write_opts,
+        )?;
+        Ok(())
+    }
+
     fn get_queue_entry_wrapper(&self, value: &[u8]) -> Result<ContextWrapper<T>> {
-        let ctx = deserialize_state(value)?;
+        let ctx = self.decode_state(value)?;
         let sd = self
             .get_deserialize_state_data(value)?
             .expect("Got unrecognized state from db!");
@@ -1262,7 +1558,7 @@ where
                         depth - existing_depth,
                         existing_depth,
                         hist.into_iter().rev().collect::<Vec<_>>(),
-                        deserialize_state::<T>(key)
+                        self.decode_state(key)
                             .expect("Failed to deserialize while reporting an error")
                     );
                 }
@@ -1319,6 +1615,24 @@ impl Ord for SavedRoute {
     }
 }
 
+type RouteSpotId<T> = <<<T as Ctx>::World as World>::Exit as Exit>::SpotId;
+
+/// Summary of a route stored in a `RouteDb`.
+#[derive(Clone, Debug)]
+pub struct StoredRoute<S> {
+    pub route_id: usize,
+    /// The destination the route was stored for, if any observation set still refers to it.
+    pub dest: Option<S>,
+    pub steps: usize,
+    pub time: u32,
+    /// The number of observation sets for which this route (or a suffix of it) is the best known.
+    pub references: usize,
+}
+
+/// Stores direct routes between spots, keyed by destination and the observations along the route.
+///
+/// The route db persists across runs under the db directory. Opening it with a different build
+/// discards all stored routes.
 pub struct RouteDb<T>
 where
     T: Ctx,
@@ -1336,34 +1650,31 @@ where
     T: Ctx,
     T::PropertyObservation: Serialize + for<'a> Deserialize<'a>,
 {
-    pub fn default_options() -> (Options, Cache) {
+    /// Returns options for the route db per the profile. The route db is smaller and less busy
+    /// than the state db, so it gets half the background jobs, threads and open files.
+    pub fn options(profile: &DbProfile) -> (Options, Cache) {
+        log::info!("Opening route db with {}", profile);
         let mut opts = Options::default();
         opts.create_if_missing(true);
         // change compression options?
-        // 4 write buffers at 256 MiB = 1 GiB
-        opts.set_write_buffer_size(256 * MB);
-        opts.set_max_write_buffer_number(4);
-        opts.set_target_file_size_base(128 * MB as u64);
-        // use half the logical cores, clamp between 2 and 32
-        opts.increase_parallelism(std::cmp::max(
-            2,
-            std::cmp::min(num_cpus::get() / 2, 32).try_into().unwrap(),
-        ));
-        opts.set_max_background_jobs(4);
+        opts.set_write_buffer_size(profile.write_buffer_size);
+        opts.set_max_write_buffer_number(profile.max_write_buffers);
+        opts.set_target_file_size_base(profile.target_file_size as u64);
+        opts.increase_parallelism(profile.parallelism());
+        opts.set_max_background_jobs(std::cmp::max(1, profile.max_background_jobs / 2));
 
         let mut env = Env::new().unwrap();
-        env.set_low_priority_background_threads(3);
+        env.set_low_priority_background_threads(std::cmp::max(1, profile.low_priority_threads / 2));
         opts.set_env(&env);
-        opts.set_max_open_files(512);
+        opts.set_max_open_files(std::cmp::max(64, profile.max_open_files / 2));
 
         let mut block_opts = BlockBasedOptions::default();
-        // blockdb caches = 2 GiB
-        let cache = Cache::new_lru_cache(2 * GB);
+        let cache = Cache::new_lru_cache(profile.route_cache);
         block_opts.set_block_cache(&cache);
-        block_opts.set_block_size(16 * KB);
+        block_opts.set_block_size(profile.block_size);
         block_opts.set_cache_index_and_filter_blocks(true);
         block_opts.set_pin_l0_filter_and_index_blocks_in_cache(true);
-        block_opts.set_ribbon_filter(9.9);
+        block_opts.set_ribbon_filter(profile.ribbon_filter_bits);
         opts.set_block_based_table_factory(&block_opts);
         (opts, cache)
     }
@@ -1385,12 +1696,86 @@ where
         (opts, cache)
     }
 
-    pub fn open<P>(
+    pub fn open<P>(p: P, opts: Options, cache: Cache, delete_first: bool) -> anyhow::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        Ok(Self::open_keeping_stale(p, opts, cache, delete_first, false)?.0)
+    }
+
+    /// Opens the db like `open`, but if the stored routes are from a different build, re-checks each
+    /// of them by replaying it from the start state at the route's first spot. Routes that still
+    /// replay are stored again, with their observations recorded anew. Routes that no longer replay
+    /// and routes that start with movement or a warp (whose first spot isn't known) are discarded.
+    pub fn open_migrating<P, W>(
+        p: P,
+        opts: Options,
+        cache: Cache,
+        startctx: &T,
+        world: &W,
+    ) -> anyhow::Result<Self>
+    where
+        P: AsRef<Path>,
+        W: World,
+        T: Ctx<World = W>,
+        W::Location: Location<Context = T>,
+        W::Exit: Exit<Context = T, Currency = <W::Location as Accessible>::Currency>,
+    {
+        let (rdb, stale) = Self::open_keeping_stale(p, opts, cache, false, true)?;
+        let total = stale.len();
+        let mut kept = 0;
+        for steps in stale {
+            let start = match steps.first().map(|rs| rs.step) {
+                Some(History::E(exit_id)) => world.get_exit_spot(exit_id),
+                Some(History::A(act_id)) if !world.is_global_action(act_id) => {
+                    world.get_action_spot(act_id)
+                }
+                Some(History::G(_, loc_id) | History::V(_, loc_id, _)) => {
+                    world.get_location_spot(loc_id)
+                }
+                _ => continue,
+            };
+            let mut ctx = startctx.clone();
+            ctx.set_position_raw(start);
+            let mut replay = ContextWrapper::new(ctx.clone());
+            let mut route = Vec::with_capacity(steps.len());
+            // Times are taken from the replay, in case they changed.
+            let replays = steps.iter().all(|rs| {
+                let before = replay.elapsed();
+                replay.maybe_replay(world, rs.step) && {
+                    route.push(RouteStep::<T> {
+                        step: rs.step,
+                        time: replay.elapsed() - before,
+                    });
+                    true
+                }
+            });
+            if !replays {
+                continue;
+            }
+            rdb.insert_route(
+                &ctx,
+                world,
+                replay.get().position(),
+                &PartialRoute::from(route),
+            );
+            kept += 1;
+        }
+        if total > 0 {
+            log::info!("Migrated {} of {} stored routes", kept, total);
+        }
+        Ok(rdb)
+    }
+
+    /// Opens the db. If the stored routes are from a different build, they're discarded, though if
+    /// `keep_stale` is set, the routes that still deserialize are returned, so they can be re-checked.
+    fn open_keeping_stale<P>(
         p: P,
         mut opts: Options,
         cache: Cache,
         delete_first: bool,
-    ) -> anyhow::Result<Self>
+        keep_stale: bool,
+    ) -> anyhow::Result<(Self, Vec<Vec<RouteStep<T>>>)>
     where
         P: AsRef<Path>,
     {
@@ -1402,16 +1787,37 @@ where
         opts.create_missing_column_families(true);
 
         let mut path = p.as_ref().to_owned();
-        path.push("routes");
+        path.push(ROUTES_DIR);
         if delete_first {
             let _ = DB::destroy(&opts, &path);
         }
 
-        let routecf = ColumnFamilyDescriptor::new(ROUTE, cf_opts);
-        let triecf = ColumnFamilyDescriptor::new(TRIE, cf_opts_trie);
-        let db = DB::open_cf_descriptors(&opts, &path, vec![routecf, triecf])?;
+        let descriptors = || {
+            vec![
+                ColumnFamilyDescriptor::new(ROUTE, cf_opts.clone()),
+                ColumnFamilyDescriptor::new(TRIE, cf_opts_trie.clone()),
+            ]
+        };
+        let mut db = DB::open_cf_descriptors(&opts, &path, descriptors())?;
+        let mut reset = delete_first;
+        let mut stale = Vec::new();
+        if let Err(e) = check_metadata(&db, ROUTES_DIR, &[ROUTE, TRIE], DbMetadata::current::<T>())
+        {
+            if !e.is::<VersionMismatch>() {
+                return Err(e);
+            }
+            log::warn!("{}; discarding stored routes", e);
+            if keep_stale {
+                stale = Self::read_stale_routes(&db)?;
+            }
+            drop(db);
+            DB::destroy(&opts, &path)?;
+            db = DB::open_cf_descriptors(&opts, &path, descriptors())?;
+            check_metadata(&db, ROUTES_DIR, &[ROUTE, TRIE], DbMetadata::current::<T>())?;
+            reset = true;
+        }
 
-        let next_route_id = if !delete_first {
+        let next_route_id = if !reset {
             // Read last key of route table to get next id
             let mut iter = db.raw_iterator_cf(db.cf_handle(ROUTE).unwrap());
             iter.seek_to_last();
@@ -1426,12 +1832,43 @@ where
             1
         };
 
-        Ok(RouteDb {
-            db: MatcherRocksDb::from_db_cf(db, TRIE),
-            _cache: cache,
-            phantom: PhantomData::default(),
-            next_route_id: next_route_id.into(),
-        })
+        Ok((
+            RouteDb {
+                db: MatcherRocksDb::from_db_cf(db, TRIE),
+                _cache: cache,
+                phantom: PhantomData::default(),
+                next_route_id: next_route_id.into(),
+            },
+            stale,
+        ))
+    }
+
+    /// Reads every stored route whose steps still deserialize.
+    fn read_stale_routes(db: &DB) -> Result<Vec<Vec<RouteStep<T>>>> {
+        let mut routes = Vec::new();
+        let mut current: Option<(usize, Option<Vec<RouteStep<T>>>)> = None;
+        for el in db.iterator_cf(db.cf_handle(ROUTE).unwrap(), IteratorMode::Start) {
+            let (key, val) = el?;
+            let (route_id, _) = get_obj_from_data::<(usize, usize)>(&key)?;
+            if current.as_ref().is_none_or(|(id, _)| *id != route_id) {
+                if let Some((_, Some(steps))) = current.take() {
+                    routes.push(steps);
+                }
+                current = Some((route_id, Some(Vec::new())));
+            }
+            let Some((_, steps)) = current.as_mut() else {
+                unreachable!();
+            };
+            // Ids may not even deserialize in this version.
+            match (steps.as_mut(), get_obj_from_data::<RouteStep<T>>(&val)) {
+                (Some(steps), Ok(step)) => steps.push(step),
+                (_, _) => *steps = None,
+            }
+        }
+        if let Some((_, Some(steps))) = current {
+            routes.push(steps);
+        }
+        Ok(routes)
     }
 
     pub fn route_cf(&self) -> &ColumnFamily {
@@ -1470,6 +1907,102 @@ where
         self.db.size()
     }
 
+    /// Returns the routes referenced by the trie, with their destinations and reference counts.
+    fn referenced_routes(&self) -> Result<HashMap<usize, (RouteSpotId<T>, usize), CommonHasher>> {
+        let db = self.db.db();
+        let mut refs = new_hashmap();
+        for el in db.iterator_cf(self.trie_cf(), IteratorMode::Start) {
+            let (key, val) = el?;
+            let mut de = Deserializer::from_read_ref(&key);
+            let dest = Deserialize::deserialize(&mut de)?;
+            let saved: SavedRoute = get_obj_from_data(&val)?;
+            refs.entry(saved.route_id).or_insert((dest, 0)).1 += 1;
+        }
+        Ok(refs)
+    }
+
+    /// Lists the stored routes in order of id, optionally only those to the given destination.
+    pub fn list_routes(
+        &self,
+        dest: Option<RouteSpotId<T>>,
+    ) -> Result<Vec<StoredRoute<RouteSpotId<T>>>> {
+        let refs = self.referenced_routes()?;
+        let db = self.db.db();
+        let mut routes: Vec<StoredRoute<_>> = Vec::new();
+        for el in db.iterator_cf(self.route_cf(), IteratorMode::Start) {
+            let (key, val) = el?;
+            let (route_id, _) = Self::route_key(&key);
+            let step: RouteStep<T> = get_obj_from_data(&val)?;
+            match routes.last_mut() {
+                Some(r) if r.route_id == route_id => {
+                    r.steps += 1;
+                    r.time += step.time;
+                }
+                _ => {
+                    let (dest, references) = refs
+                        .get(&route_id)
+                        .map_or((None, 0), |&(d, n)| (Some(d), n));
+                    routes.push(StoredRoute {
+                        route_id,
+                        dest,
+                        steps: 1,
+                        time: step.time,
+                        references,
+                    });
+                }
+            }
+        }
+        if dest.is_some() {
+            routes.retain(|r| r.dest == dest);
+        }
+        Ok(routes)
+    }
+
+    /// Removes the stored routes that no observation set refers to anymore, after first removing
+    /// every observation set for the given destination, if any.
+    ///
+    /// Returns the number of observation sets and routes removed.
+    pub fn prune(&self, dest: Option<RouteSpotId<T>>) -> Result<(usize, usize)> {
+        let db = self.db.db();
+        let mut removed_keys = 0;
+        if let Some(dest) = dest {
+            let mut iter_opts = ReadOptions::default();
+            iter_opts.set_iterate_range(PrefixRange(serialize_data(dest)));
+            let mut batch = WriteBatchWithTransaction::<false>::default();
+            for el in db.iterator_cf_opt(self.trie_cf(), iter_opts, IteratorMode::Start) {
+                let (key, _) = el?;
+                batch.delete_cf(self.trie_cf(), key);
+                removed_keys += 1;
+            }
+            db.write(batch)?;
+        }
+
+        let refs = self.referenced_routes()?;
+        let mut batch = WriteBatchWithTransaction::<false>::default();
+        let mut removed_routes = 0;
+        let mut last_id = None;
+        for el in db.iterator_cf(self.route_cf(), IteratorMode::Start) {
+            let (key, _) = el?;
+            let (route_id, _) = Self::route_key(&key);
+            if last_id != Some(route_id) && !refs.contains_key(&route_id) {
+                // Keys sort by route id, then by step.
+                batch.delete_range_cf(
+                    self.route_cf(),
+                    serialize_data((route_id, 0)),
+                    serialize_data((route_id + 1, 0)),
+                );
+                removed_routes += 1;
+            }
+            last_id = Some(route_id);
+        }
+        db.write(batch)?;
+        if removed_keys > 0 || removed_routes > 0 {
+            db.compact_range_cf(self.route_cf(), None::<&[u8]>, None::<&[u8]>);
+            db.compact_range_cf(self.trie_cf(), None::<&[u8]>, None::<&[u8]>);
+        }
+        Ok((removed_keys, removed_routes))
+    }
+
     pub fn insert_route<W>(
         &self,
         startctx: &T,
@@ -1552,10 +2085,280 @@ where
     }
 }
 
+/// A solve trie entry: the suffix of a stored solution from the step at `index`.
+///
+/// Ordered by the time the suffix takes, so that merging keeps the fastest suffix per observation set.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StoredSuffix {
+    pub remaining: u32,
+    pub solution_id: usize,
+    pub index: usize,
+}
+
+/// Persists the solution-suffix solve trie, so that later runs can load it without having to find
+/// those solutions again.
+///
+/// The solution column family maps each solution's history to its elapsed time and id, and the
+/// trie column family maps the observations at each step to the fastest stored suffix. Opening with
+/// a different build discards all stored solutions, though they can be kept as text to replay.
+pub struct SolutionDb<T>
+where
+    T: Ctx,
+{
+    db: MatcherRocksDb<T, StoredSuffix>,
+    next_solution_id: AtomicUsize,
+}
+
+impl<T> SolutionDb<T>
+where
+    T: Ctx,
+{
+    pub fn open<P>(p: P) -> anyhow::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        Ok(Self::open_keeping_stale(p, false)?.0)
+    }
+
+    /// Opens the db like `open`. If the stored solutions are from a different build and
+    /// `keep_stale` is set, also returns their routes as text, so they can be replayed against
+    /// this build.
+    pub fn open_keeping_stale<P>(p: P, keep_stale: bool) -> anyhow::Result<(Self, Vec<String>)>
+    where
+        P: AsRef<Path>,
+    {
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.create_missing_column_families(true);
+        let mut trie_opts = Options::default();
+        trie_opts.set_merge_operator_associative("min", min_merge);
+
+        let path = p.as_ref();
+        let descriptors = || {
+            vec![
+                ColumnFamilyDescriptor::new(SOLUTION, Options::default()),
+                ColumnFamilyDescriptor::new(TRIE, trie_opts.clone()),
+            ]
+        };
+        let mut db = DB::open_cf_descriptors(&opts, path, descriptors())?;
+        let mut stale = Vec::new();
+        if let Err(e) = check_metadata(
+            &db,
+            SOLUTIONS_NAME,
+            &[SOLUTION, TRIE],
+            DbMetadata::current::<T>(),
+        ) {
+            if !e.is::<VersionMismatch>() {
+                return Err(e);
+            }
+            log::warn!("{}; discarding stored solutions", e);
+            if keep_stale {
+                for el in db.iterator_cf(db.cf_handle(SOLUTION).unwrap(), IteratorMode::Start) {
+                    let (key, _) = el?;
+                    // Ids may not even deserialize in this version.
+                    match get_obj_from_data::<Vec<HistoryAlias<T>>>(&key) {
+                        Ok(history) => stale.push(history_str::<T, _>(history.into_iter())),
+                        Err(e) => log::debug!("Skipping unreadable stored solution: {}", e),
+                    }
+                }
+                log::info!("Kept {} stale solutions to replay", stale.len());
+            }
+            drop(db);
+            DB::destroy(&opts, path)?;
+            db = DB::open_cf_descriptors(&opts, path, descriptors())?;
+            check_metadata(
+                &db,
+                SOLUTIONS_NAME,
+                &[SOLUTION, TRIE],
+                DbMetadata::current::<T>(),
+            )?;
+        }
+
+        let sdb = SolutionDb {
+            db: MatcherRocksDb::from_db_cf(db, TRIE),
+            next_solution_id: 0.into(),
+        };
+        let next_id = sdb
+            .solutions_by_id()?
+            .iter()
+            .map(|(id, _)| id + 1)
+            .max()
+            .unwrap_or(0);
+        sdb.next_solution_id.store(next_id, Ordering::Release);
+        Ok((sdb, stale))
+    }
+
+    fn solution_cf(&self) -> &ColumnFamily {
+        self.db.db().cf_handle(SOLUTION).unwrap()
+    }
+
+    pub fn len(&self) -> usize {
+        self.db
+            .db()
+            .property_int_value_cf(self.solution_cf(), "rocksdb.estimate-num-keys")
+            .unwrap()
+            .unwrap_or(0) as usize
+    }
+
+    /// Stores the solution along with the observations of its states in the solve trie.
+    /// Solutions already stored are skipped, and solutions that don't replay from `startctx` are errors.
+    pub fn insert<W>(&self, startctx: &T, world: &W, solution: &Solution<T>) -> Result<()>
+    where
+        W: World,
+        T: Ctx<World = W>,
+        W::Location: Location<Context = T>,
+        W::Exit: Exit<Context = T, Currency = <W::Location as Accessible>::Currency>,
+    {
+        let key = serialize_data(&solution.history);
+        if self
+            .db
+            .db()
+            .get_pinned_cf(self.solution_cf(), &key)?
+            .is_some()
+        {
+            return Ok(());
+        }
+        let mut replay = ContextWrapper::new(startctx.clone());
+        let mut times = Vec::with_capacity(solution.history.len());
+        for &step in &solution.history {
+            times.push(replay.elapsed());
+            replay.try_replay(world, step).map_err(|e| {
+                Error::msg(format!("Solution failed to replay at \"{}\": {}", step, e))
+            })?;
+        }
+        let solution_id = self.next_solution_id.fetch_add(1, Ordering::AcqRel);
+
+        let mut batch = WriteBatchWithTransaction::<false>::default();
+        batch.put_cf(
+            self.solution_cf(),
+            key,
+            serialize_data((solution.elapsed, solution_id)),
+        );
+        let prefix = Vec::new();
+        for_each_solution_observation(startctx, world, solution, 0, |observations, index| {
+            let suffix = StoredSuffix {
+                remaining: solution.elapsed.saturating_sub(times[index]),
+                solution_id,
+                index,
+            };
+            self.db
+                .insert_batch(&mut batch, observations, suffix, &prefix);
+        });
+        self.db.db().write(batch)?;
+        Ok(())
+    }
+
+    /// Returns the stored solutions with their ids.
+    pub fn solutions_by_id(&self) -> Result<Vec<(usize, Arc<Solution<T>>)>> {
+        let mut vec = Vec::new();
+        for el in self
+            .db
+            .db()
+            .iterator_cf(self.solution_cf(), IteratorMode::Start)
+        {
+            let (key, val) = el?;
+            let (elapsed, id): (u32, usize) = get_obj_from_data(val.as_ref())?;
+            vec.push((
+                id,
+                Arc::new(Solution {
+                    elapsed,
+                    history: get_obj_from_data(key.as_ref())?,
+                }),
+            ));
+        }
+        Ok(vec)
+    }
+
+    pub fn solutions(&self) -> Result<Vec<Arc<Solution<T>>>> {
+        Ok(self
+            .solutions_by_id()?
+            .into_iter()
+            .map(|(_, solution)| solution)
+            .collect())
+    }
+
+    /// Calls `f` with the observations and stored suffix of every solve trie entry.
+    pub fn for_each_trie_entry<F>(&self, mut f: F) -> Result<()>
+    where
+        F: FnMut(Vec<T::PropertyObservation>, StoredSuffix),
+    {
+        let trie_cf = self.db.db().cf_handle(TRIE).unwrap();
+        for el in self.db.db().iterator_cf(trie_cf, IteratorMode::Start) {
+            let (key, val) = el?;
+            let mut de = Deserializer::from_read_ref(key.as_ref());
+            let mut observations = Vec::new();
+            while let Ok(obs) = Deserialize::deserialize(&mut de) {
+                observations.push(obs);
+            }
+            f(observations, get_obj_from_data(val.as_ref())?);
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::StateData;
+    use super::{check_metadata, verify_metadata, DbMetadata, StateData, VersionMismatch};
     use crate::context::History;
+    use rocksdb::{Options, DB};
+    use tempfile::TempDir;
+
+    fn metadata(version: &str, ctx_size: usize) -> DbMetadata {
+        DbMetadata {
+            version: version.to_owned(),
+            ctx_size,
+            key_size: 0,
+        }
+    }
+
+    fn mismatch(res: anyhow::Result<()>) -> VersionMismatch {
+        res.expect_err("db should be refused")
+            .downcast::<VersionMismatch>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_check_metadata() {
+        let dir = TempDir::new().unwrap();
+        let db = DB::open_default(dir.path()).unwrap();
+        let current = metadata("1", 64);
+        // A new db is stamped, then accepted by the same build.
+        assert!(!verify_metadata(&db, "test", &[], current.clone()).unwrap());
+        check_metadata(&db, "test", &[], current.clone()).unwrap();
+        assert!(verify_metadata(&db, "test", &[], current.clone()).unwrap());
+        check_metadata(&db, "test", &[], current.clone()).unwrap();
+
+        let e = mismatch(check_metadata(&db, "test", &[], metadata("2", 64)));
+        assert_eq!(e.stored, Some(current.clone()));
+        assert_eq!(e.current, metadata("2", 64));
+        let e = mismatch(check_metadata(&db, "test", &[], metadata("1", 72)));
+        assert_eq!(e.stored, Some(current));
+        assert!(e.to_string().contains("context size 72"), "{}", e);
+    }
+
+    #[test]
+    fn test_unstamped_db_refused() {
+        let dir = TempDir::new().unwrap();
+        let db = DB::open_default(dir.path()).unwrap();
+        db.put(b"state", b"data").unwrap();
+        let e = mismatch(check_metadata(&db, "test", &[], metadata("1", 64)));
+        assert_eq!(e.stored, None);
+        assert!(e.to_string().contains("no build metadata"), "{}", e);
+
+        // Data in any of the given column families counts.
+        let dir = TempDir::new().unwrap();
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.create_missing_column_families(true);
+        let db = DB::open_cf(&opts, dir.path(), ["best"]).unwrap();
+        db.put_cf(db.cf_handle("best").unwrap(), b"state", b"data")
+            .unwrap();
+        assert!(!verify_metadata(&db, "test", &[], metadata("1", 64)).unwrap());
+        assert_eq!(
+            mismatch(check_metadata(&db, "test", &["best"], metadata("1", 64))).stored,
+            None
+        );
+    }
 
     type GenericStateData = StateData<u8, u8, u8, u8, u8, u8>;
 
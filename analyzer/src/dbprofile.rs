@@ -0,0 +1,367 @@
+//! RocksDB tuning for the queue, state and route dbs.
+//!
+//! A profile starts from a preset (`laptop`, `workstation` or `server`) and can override any
+//! option, either in a `db` section of the settings file or in a separate profile file:
+//!
+//! ```yaml
+//! db:
+//!   profile: laptop
+//!   state_cache: 1G
+//! ```
+
+use crate::settings::*;
+use humansize::{SizeFormatter, BINARY};
+use std::fmt::Display;
+use std::path::Path;
+use yaml_rust::{Yaml, YamlLoader};
+
+const KB: usize = 1 << 10;
+const MB: usize = 1 << 20;
+const GB: usize = 1 << 30;
+
+pub const PRESETS: [&str; 3] = ["laptop", "workstation", "server"];
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DbProfile {
+    /// The preset these options started from.
+    pub preset: &'static str,
+    pub write_buffer_size: usize,
+    pub max_write_buffers: i32,
+    pub target_file_size: usize,
+    /// Threads for flushes and compactions, or 0 for half the logical cores (between 2 and 32).
+    pub parallelism: i32,
+    pub max_background_jobs: i32,
+    pub low_priority_threads: i32,
+    pub max_open_files: i32,
+    pub block_size: usize,
+    pub ribbon_filter_bits: f64,
+    pub queue_cache: usize,
+    pub state_cache: usize,
+    pub route_cache: usize,
+}
+
+impl Default for DbProfile {
+    fn default() -> Self {
+        Self::workstation()
+    }
+}
+
+impl Display for DbProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "profile {}: write buffers {} x{}, target file size {}, parallelism {}, \
+            background jobs {} ({} low priority), max open files {}, block size {}, \
+            ribbon filter {} bits, caches: queue {}, states {}, routes {}",
+            self.preset,
+            SizeFormatter::new(self.write_buffer_size, BINARY),
+            self.max_write_buffers,
+            SizeFormatter::new(self.target_file_size, BINARY),
+            self.parallelism(),
+            self.max_background_jobs,
+            self.low_priority_threads,
+            self.max_open_files,
+            SizeFormatter::new(self.block_size, BINARY),
+            self.ribbon_filter_bits,
+            SizeFormatter::new(self.queue_cache, BINARY),
+            SizeFormatter::new(self.state_cache, BINARY),
+            SizeFormatter::new(self.route_cache, BINARY),
+        )
+    }
+}
+
+impl DbProfile {
+    /// About 1.5 GiB across all the dbs, for machines with 16 GiB or less.
+    pub fn laptop() -> Self {
+        DbProfile {
+            preset: "laptop",
+            write_buffer_size: 64 * MB,
+            max_write_buffers: 2,
+            target_file_size: 64 * MB,
+            parallelism: 2,
+            max_background_jobs: 4,
+            low_priority_threads: 2,
+            max_open_files: 512,
+            block_size: 16 * KB,
+            ribbon_filter_bits: 9.9,
+            queue_cache: 256 * MB,
+            state_cache: 512 * MB,
+            route_cache: 256 * MB,
+        }
+    }
+
+    /// About 12 GiB across all the dbs.
+    pub fn workstation() -> Self {
+        DbProfile {
+            preset: "workstation",
+            write_buffer_size: 256 * MB,
+            max_write_buffers: 4,
+            target_file_size: 128 * MB,
+            parallelism: 0,
+            max_background_jobs: 8,
+            low_priority_threads: 6,
+            max_open_files: 1024,
+            block_size: 16 * KB,
+            ribbon_filter_bits: 9.9,
+            queue_cache: 2 * GB,
+            state_cache: 5 * GB,
+            route_cache: 2 * GB,
+        }
+    }
+
+    /// About 35 GiB across all the dbs, for machines with 64 GiB or more.
+    pub fn server() -> Self {
+        DbProfile {
+            preset: "server",
+            write_buffer_size: 512 * MB,
+            max_write_buffers: 6,
+            target_file_size: 256 * MB,
+            parallelism: 0,
+            max_background_jobs: 16,
+            low_priority_threads: 12,
+            max_open_files: 4096,
+            block_size: 32 * KB,
+            ribbon_filter_bits: 9.9,
+            queue_cache: 6 * GB,
+            state_cache: 16 * GB,
+            route_cache: 4 * GB,
+        }
+    }
+
+    pub fn preset(name: &str) -> Result<Self, String> {
+        match name {
+            "laptop" => Ok(Self::laptop()),
+            "workstation" => Ok(Self::workstation()),
+            "server" => Ok(Self::server()),
+            _ => Err(format!(
+                "Unrecognized db profile {:?}, expected one of: {}",
+                name,
+                PRESETS.join(", ")
+            )),
+        }
+    }
+
+    /// Reads a profile from a `db` section: the preset named by `profile` (default workstation),
+    /// with any other keys overriding its options.
+    pub fn from_yaml(yaml: &Yaml) -> Result<Self, String> {
+        let hash = yaml
+            .as_hash()
+            .ok_or_else(|| format!("db section should be a key-value map: {:?}", yaml))?;
+        let mut profile = match hash.get(&Yaml::String(String::from("profile"))) {
+            Some(Yaml::String(name)) => Self::preset(name)?,
+            Some(v) => return Err(format!("db profile should be a preset name: {:?}", v)),
+            None => Self::default(),
+        };
+        let mut errs = Vec::new();
+        for (key, val) in hash {
+            let res = match key.as_str() {
+                Some("profile") => Ok(()),
+                Some("write_buffer_size") => {
+                    parse_size(key, val).map(|v| profile.write_buffer_size = v)
+                }
+                Some("max_write_buffers") => {
+                    parse_int(key, val).map(|v| profile.max_write_buffers = v)
+                }
+                Some("target_file_size") => {
+                    parse_size(key, val).map(|v| profile.target_file_size = v)
+                }
+                Some("parallelism") => parse_int(key, val).map(|v| profile.parallelism = v),
+                Some("max_background_jobs") => {
+                    parse_int(key, val).map(|v| profile.max_background_jobs = v)
+                }
+                Some("low_priority_threads") => {
+                    parse_int(key, val).map(|v| profile.low_priority_threads = v)
+                }
+                Some("max_open_files") => parse_int(key, val).map(|v| profile.max_open_files = v),
+                Some("block_size") => parse_size(key, val).map(|v| profile.block_size = v),
+                Some("ribbon_filter_bits") => match val {
+                    Yaml::Integer(i) => Ok(*i as f64),
+                    Yaml::Real(_) => val
+                        .as_f64()
+                        .ok_or_else(|| format!("Value for '{:?}' is not a number: {:?}", key, val)),
+                    _ => Err(format!("Value for '{:?}' is not a number: {:?}", key, val)),
+                }
+                .map(|v| profile.ribbon_filter_bits = v),
+                Some("queue_cache") => parse_size(key, val).map(|v| profile.queue_cache = v),
+                Some("state_cache") => parse_size(key, val).map(|v| profile.state_cache = v),
+                Some("route_cache") => parse_size(key, val).map(|v| profile.route_cache = v),
+                _ => Err(format!("Unrecognized db option: {:?}", key)),
+            };
+            if let Err(e) = res {
+                errs.push(e);
+            }
+        }
+        if errs.is_empty() {
+            Ok(profile)
+        } else {
+            Err(errs.join("\n"))
+        }
+    }
+
+    /// Reads the `db` section of a yaml file. If the file has no `db` section, reads the whole
+    /// file as a profile if `whole_file` is set, and otherwise returns None.
+    pub fn from_file<P: AsRef<Path>>(path: P, whole_file: bool) -> Result<Option<Self>, String> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Couldn't read {:?}: {}", path, e))?;
+        let docs = YamlLoader::load_from_str(&text)
+            .map_err(|e| format!("Couldn't parse {:?}: {}", path, e))?;
+        let Some(yaml) = docs.first() else {
+            return Ok(None);
+        };
+        match &yaml["db"] {
+            Yaml::BadValue if whole_file => Self::from_yaml(yaml).map(Some),
+            Yaml::BadValue => Ok(None),
+            db => Self::from_yaml(db).map(Some),
+        }
+    }
+
+    /// Resolves the profile from `--db-profile`, which is a preset name or profile file,
+    /// then from the `db` section of the settings file, and otherwise uses the default.
+    pub fn resolve(
+        profile_arg: Option<&str>,
+        settings_file: Option<&Path>,
+    ) -> Result<Self, String> {
+        if let Some(arg) = profile_arg {
+            if PRESETS.contains(&arg) {
+                return Self::preset(arg);
+            }
+            return Ok(Self::from_file(arg, true)?.unwrap_or_default());
+        }
+        if let Some(settings) = settings_file {
+            if let Some(profile) = Self::from_file(settings, false)? {
+                return Ok(profile);
+            }
+        }
+        Ok(Self::default())
+    }
+
+    /// Returns the number of threads for flushes and compactions.
+    pub fn parallelism(&self) -> i32 {
+        if self.parallelism > 0 {
+            self.parallelism
+        } else {
+            // use half the logical cores, clamp between 2 and 32
+            std::cmp::max(2, std::cmp::min(num_cpus::get() / 2, 32))
+                .try_into()
+                .unwrap()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn load(text: &str) -> Yaml {
+        YamlLoader::load_from_str(text).unwrap().remove(0)
+    }
+
+    fn write_temp(name: &str, text: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("{}-{}.yaml", name, std::process::id()));
+        std::fs::write(&path, text).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_from_yaml_presets() {
+        assert_eq!(
+            DbProfile::from_yaml(&load("profile: laptop")),
+            Ok(DbProfile::laptop())
+        );
+        assert_eq!(
+            DbProfile::from_yaml(&load("profile: server")),
+            Ok(DbProfile::server())
+        );
+        // Any map without a profile starts from the default.
+        assert_eq!(
+            DbProfile::from_yaml(&load("{}")),
+            Ok(DbProfile::workstation())
+        );
+        assert!(DbProfile::from_yaml(&load("profile: phone")).is_err());
+        assert!(DbProfile::from_yaml(&load("profile: 3")).is_err());
+        assert!(DbProfile::from_yaml(&load("- laptop")).is_err());
+    }
+
+    #[test]
+    fn test_from_yaml_overrides() {
+        let profile = DbProfile::from_yaml(&load(
+            "profile: laptop\n\
+             state_cache: 1G\n\
+             block_size: 4096\n\
+             max_open_files: 100\n\
+             ribbon_filter_bits: 12",
+        ))
+        .unwrap();
+        assert_eq!(
+            profile,
+            DbProfile {
+                state_cache: 1 << 30,
+                block_size: 4096,
+                max_open_files: 100,
+                ribbon_filter_bits: 12.0,
+                ..DbProfile::laptop()
+            }
+        );
+        let profile = DbProfile::from_yaml(&load("ribbon_filter_bits: 7.5")).unwrap();
+        assert_eq!(profile.ribbon_filter_bits, 7.5);
+        assert_eq!(profile.preset, "workstation");
+    }
+
+    #[test]
+    fn test_from_yaml_errors() {
+        // Every bad key is reported, not just the first.
+        let err = DbProfile::from_yaml(&load(
+            "profile: laptop\n\
+             state_cahce: 1G\n\
+             queue_cache: lots\n\
+             parallelism: 2",
+        ))
+        .unwrap_err();
+        assert!(err.contains("state_cahce"), "{}", err);
+        assert!(err.contains("lots"), "{}", err);
+        assert!(!err.contains("parallelism"), "{}", err);
+        assert!(DbProfile::from_yaml(&load("ribbon_filter_bits: high")).is_err());
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(DbProfile::resolve(None, None), Ok(DbProfile::default()));
+        assert_eq!(
+            DbProfile::resolve(Some("server"), None),
+            Ok(DbProfile::server())
+        );
+
+        let settings = write_temp("dbprofile-settings", "db:\n  profile: laptop\n");
+        let plain = write_temp("dbprofile-plain", "some_setting: true\n");
+        let file = write_temp("dbprofile-file", "profile: server\nblock_size: 8K\n");
+        let from_file = DbProfile {
+            block_size: 8 << 10,
+            ..DbProfile::server()
+        };
+
+        assert_eq!(
+            DbProfile::resolve(None, Some(settings.as_path())),
+            Ok(DbProfile::laptop())
+        );
+        // A settings file without a db section isn't a profile.
+        assert_eq!(
+            DbProfile::resolve(None, Some(plain.as_path())),
+            Ok(DbProfile::default())
+        );
+        // A profile file may be the db section alone, and the argument wins over settings.
+        assert_eq!(
+            DbProfile::resolve(file.to_str(), Some(settings.as_path())),
+            Ok(from_file)
+        );
+        assert_eq!(
+            DbProfile::resolve(Some("workstation"), Some(settings.as_path())),
+            Ok(DbProfile::workstation())
+        );
+        assert!(DbProfile::resolve(Some("no-such-profile.yaml"), None).is_err());
+
+        for p in [settings, plain, file] {
+            let _ = std::fs::remove_file(p);
+        }
+    }
+}
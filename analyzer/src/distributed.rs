@@ -0,0 +1,196 @@
+//! Coordinator/worker protocol for spreading a search across processes or machines.
+//!
+//! The coordinator is a normal search that also listens for workers. It owns the dbs and the
+//! solutions, and workers only need the same build of the game. A worker repeatedly sends the
+//! children of its last batch of states and asks for another batch. Every reply carries the
+//! coordinator's current max time and best solution time, so each worker picks up updates on its
+//! next request. The coordinator prunes states and checks them against the solve trie before
+//! handing them out, so workers only single step them.
+//!
+//! Messages are MessagePack, each preceded by its length as a big-endian u32.
+
+use crate::context::*;
+use crate::db::DbMetadata;
+use crate::search::single_step;
+use crate::storage::{get_obj_from_data, serialize_data};
+use crate::world::*;
+use anyhow::{anyhow, Result};
+use rayon::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::io::{ErrorKind, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+/// The largest message accepted, to catch corrupt or mismatched streams early.
+const MAX_MESSAGE_SIZE: usize = 1 << 30;
+/// How long a worker waits to ask again when the coordinator has no states for it.
+const IDLE_WAIT: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Request<C> {
+    /// The first message from a worker, identifying its build, and the number of states it wants.
+    Hello { metadata: DbMetadata, pull: usize },
+    /// Each state of the last batch with its children, and the number of states wanted next.
+    Work {
+        results: Vec<(C, Vec<C>)>,
+        pull: usize,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Reply<C> {
+    /// The worker's build doesn't match the coordinator's.
+    Rejected(String),
+    /// States to process, which may be empty if the coordinator has none to spare right now.
+    Batch {
+        states: Vec<C>,
+        max_time: u32,
+        best: Option<u32>,
+    },
+    /// The search is over.
+    Finished,
+}
+
+pub type WorkerRequest<T> = Request<ContextWrapper<T>>;
+pub type CoordinatorReply<T> = Reply<ContextWrapper<T>>;
+
+pub fn write_message<M: Serialize>(stream: &mut impl Write, msg: &M) -> Result<()> {
+    let data = serialize_data(msg);
+    stream.write_all(&u32::try_from(data.len())?.to_be_bytes())?;
+    stream.write_all(&data)?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Reads one message, or returns None if the stream closed before the start of a message.
+pub fn read_message<M: DeserializeOwned>(stream: &mut impl Read) -> Result<Option<M>> {
+    let mut len = [0; 4];
+    match stream.read_exact(&mut len) {
+        Ok(()) => (),
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    }
+    let len = u32::from_be_bytes(len) as usize;
+    if len > MAX_MESSAGE_SIZE {
+        return Err(anyhow!("Message of {} bytes is too large", len));
+    }
+    let mut data = vec![0; len];
+    stream.read_exact(&mut data)?;
+    Ok(Some(get_obj_from_data(&data)?))
+}
+
+/// Connects to a coordinator and processes batches of `batch_size` states from it until the search
+/// finishes. Returns the number of states processed.
+pub fn run_worker<W, T, L, A>(world: &W, coordinator: A, batch_size: usize) -> Result<usize>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    A: ToSocketAddrs,
+{
+    let mut stream = TcpStream::connect(coordinator)?;
+    stream.set_nodelay(true)?;
+    write_message(
+        &mut stream,
+        &WorkerRequest::<T>::Hello {
+            metadata: DbMetadata::current::<T>(),
+            pull: batch_size,
+        },
+    )?;
+
+    let mut processed = 0;
+    let mut best = None;
+    loop {
+        let reply: CoordinatorReply<T> = read_message(&mut stream)?
+            .ok_or_else(|| anyhow!("Coordinator closed the connection"))?;
+        let (states, max_time) = match reply {
+            Reply::Rejected(e) => return Err(anyhow!("Coordinator rejected this worker: {}", e)),
+            Reply::Finished => {
+                log::info!("Search finished after {} states", processed);
+                return Ok(processed);
+            }
+            Reply::Batch {
+                states,
+                max_time,
+                best: new_best,
+            } => {
+                if new_best != best {
+                    if let Some(b) = new_best {
+                        log::info!("Best solution is now {}ms", b);
+                    }
+                    best = new_best;
+                }
+                (states, max_time)
+            }
+        };
+        if states.is_empty() {
+            std::thread::sleep(IDLE_WAIT);
+        }
+        processed += states.len();
+        let results = states
+            .into_par_iter()
+            .map(|ctx| {
+                let children = if ctx.get().count_visits() >= W::NUM_CANON_LOCATIONS {
+                    // Nothing left to visit, and the coordinator handles winning states.
+                    Vec::new()
+                } else {
+                    single_step(world, ctx.clone(), max_time)
+                };
+                (ctx, children)
+            })
+            .collect();
+        write_message(
+            &mut stream,
+            &WorkerRequest::<T>::Work {
+                results,
+                pull: batch_size,
+            },
+        )?;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::net::TcpListener;
+
+    #[test]
+    fn test_message_roundtrip() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let sender = std::thread::spawn(move || {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            write_message(
+                &mut stream,
+                &Reply::Batch {
+                    states: vec![1u32, 2, 3],
+                    max_time: 456,
+                    best: Some(789),
+                },
+            )
+            .unwrap();
+            write_message(&mut stream, &Reply::<u32>::Finished).unwrap();
+        });
+
+        let (mut stream, _) = listener.accept().unwrap();
+        sender.join().unwrap();
+        match read_message::<Reply<u32>>(&mut stream).unwrap() {
+            Some(Reply::Batch {
+                states,
+                max_time,
+                best,
+            }) => {
+                assert_eq!(states, vec![1, 2, 3]);
+                assert_eq!(max_time, 456);
+                assert_eq!(best, Some(789));
+            }
+            r => panic!("Unexpected message: {:?}", r),
+        }
+        assert!(matches!(
+            read_message::<Reply<u32>>(&mut stream).unwrap(),
+            Some(Reply::Finished)
+        ));
+        assert!(read_message::<Reply<u32>>(&mut stream).unwrap().is_none());
+    }
+}
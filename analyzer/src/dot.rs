@@ -0,0 +1,189 @@
+//! Graphviz output of the world graph as connected at runtime.
+
+use crate::access::accessible_spots;
+use crate::context::*;
+use crate::world::*;
+use crate::{new_hashset, CommonHasher};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+const EXIT_COLOR: &str = "blue";
+const LOCAL_COLOR: &str = "gray50";
+const CONDENSED_COLOR: &str = "darkgreen";
+const WARP_COLOR: &str = "red";
+
+fn node_name<S: Id>(spot: S) -> String {
+    format!("{:?}", spot)
+}
+
+/// Splits a spot's display name ("Region > Area > Spot") into its parts.
+pub(crate) fn spot_parts<S: Id>(spot: S) -> (String, String, String) {
+    let name = spot.to_string();
+    let mut parts = name.splitn(3, " > ");
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(r), Some(a), Some(s)) => (r.to_owned(), a.to_owned(), s.to_owned()),
+        _ => (String::new(), String::new(), name.clone()),
+    }
+}
+
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Renders the world graph as DOT, with spots clustered by region and area.
+///
+/// Exits, local movements, condensed edges and warps are drawn in different colors. Spots and exits
+/// are filtered by `should_draw_spot` and `should_draw_edge`. Warp destinations are evaluated in the
+/// given state. If `reachable_only` is set, only spots reachable from the given state are drawn,
+/// along with the edges usable from them.
+pub fn world_to_dot<W, T>(world: &W, ctx: &ContextWrapper<T>, reachable_only: bool) -> String
+where
+    W: World,
+    T: Ctx<World = W>,
+    W::Location: Location<Context = T>,
+{
+    let reachable: Option<HashMap<_, _, CommonHasher>> = if reachable_only {
+        Some(accessible_spots(world, ctx.clone(), u32::MAX, true))
+    } else {
+        None
+    };
+    let drawn = |spot: <W::Exit as Exit>::SpotId| {
+        spot != Default::default()
+            && world.should_draw_spot(spot)
+            && reachable.as_ref().map_or(true, |r| r.contains_key(&spot))
+    };
+    // The state to evaluate an edge from the given spot in.
+    let state_at = |spot: <W::Exit as Exit>::SpotId| reachable.as_ref().and_then(|r| r.get(&spot));
+
+    let mut clusters: BTreeMap<String, BTreeMap<String, Vec<_>>> = BTreeMap::new();
+    for &spot in world.get_all_spots() {
+        if drawn(spot) {
+            let (region, area, _) = spot_parts(spot);
+            clusters
+                .entry(region)
+                .or_default()
+                .entry(area)
+                .or_default()
+                .push(spot);
+        }
+    }
+
+    let mut out = String::from("digraph world {\n    compound = true;\n    node [shape=box];\n");
+    for (ri, (region, areas)) in clusters.iter().enumerate() {
+        writeln!(
+            out,
+            "    subgraph cluster_{} {{\n        label = \"{}\";",
+            ri,
+            dot_escape(region)
+        )
+        .unwrap();
+        for (ai, (area, spots)) in areas.iter().enumerate() {
+            writeln!(
+                out,
+                "        subgraph cluster_{}_{} {{\n            label = \"{}\";",
+                ri,
+                ai,
+                dot_escape(area)
+            )
+            .unwrap();
+            for &spot in spots {
+                writeln!(
+                    out,
+                    "            {} [label = \"{}\"];",
+                    node_name(spot),
+                    dot_escape(&spot_parts(spot).2)
+                )
+                .unwrap();
+            }
+            out.push_str("        }\n");
+        }
+        out.push_str("    }\n");
+    }
+
+    let mut local_seen = new_hashset();
+    for &spot in world.get_all_spots() {
+        if !drawn(spot) {
+            continue;
+        }
+        let state = state_at(spot);
+        for exit in world.get_spot_exits(spot) {
+            if !world.should_draw_edge(exit.id())
+                || !drawn(exit.dest())
+                || state.is_some_and(|c| !exit.can_access(c.get(), world))
+            {
+                continue;
+            }
+            writeln!(
+                out,
+                "    {} -> {} [color = {}, tooltip = \"{}\"];",
+                node_name(spot),
+                node_name(exit.dest()),
+                EXIT_COLOR,
+                dot_escape(&exit.id().to_string())
+            )
+            .unwrap();
+        }
+        for &dest in world.get_area_spots(spot) {
+            if dest == spot || !drawn(dest) || !world.are_spots_connected(spot, dest) {
+                continue;
+            }
+            if let Some(c) = state {
+                let movement_state = c.get().get_movement_state(world);
+                if c.get().local_travel_time(movement_state, dest) == u32::MAX {
+                    continue;
+                }
+            }
+            local_seen.insert((spot, dest));
+            writeln!(
+                out,
+                "    {} -> {} [color = {}];",
+                node_name(spot),
+                node_name(dest),
+                LOCAL_COLOR
+            )
+            .unwrap();
+        }
+        for ce in world.get_condensed_edges_from(spot) {
+            if !drawn(ce.dst) || local_seen.contains(&(spot, ce.dst)) {
+                continue;
+            }
+            if let Some(c) = state {
+                let movement_state = c.get().get_movement_state(world);
+                if !ce.can_access(world, c.get(), movement_state) {
+                    continue;
+                }
+            }
+            writeln!(
+                out,
+                "    {} -> {} [color = {}, style = dashed];",
+                node_name(spot),
+                node_name(ce.dst),
+                CONDENSED_COLOR
+            )
+            .unwrap();
+        }
+    }
+
+    for warp in world.get_warps() {
+        if reachable.is_some() && !warp.can_access(ctx.get(), world) {
+            continue;
+        }
+        let dest = warp.dest(ctx.get(), world);
+        if !drawn(dest) {
+            continue;
+        }
+        writeln!(
+            out,
+            "    warp_{:?} [label = \"{}\", shape = diamond, color = {}];\n    warp_{:?} -> {} [color = {}];",
+            warp.id(),
+            dot_escape(&warp.id().to_string()),
+            WARP_COLOR,
+            warp.id(),
+            node_name(dest),
+            WARP_COLOR
+        )
+        .unwrap();
+    }
+    out.push_str("}\n");
+    out
+}
@@ -0,0 +1,207 @@
+//! Encodings for the states stored as keys and values in the dbs.
+//!
+//! States are always serialized with MessagePack first. The zstd dictionary encoding also
+//! compresses them with a dictionary trained on sample states, which removes most of the
+//! framing and the values shared between states.
+
+use crate::context::*;
+use crate::search::single_step;
+use crate::storage::{deserialize_state, serialize_state};
+use crate::util::SplitMix64;
+use crate::world::*;
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use zstd::bulk::{Compressor, Decompressor};
+use zstd::zstd_safe::CParameter;
+
+/// The max size of a trained dictionary.
+const DICT_SIZE: usize = 16 * 1024;
+/// The number of sample states to train a dictionary on.
+pub const DICT_SAMPLES: usize = 8_192;
+/// The max number of steps in each random walk when sampling states.
+const SAMPLE_WALK_STEPS: usize = 256;
+const ZSTD_LEVEL: i32 = 3;
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, ValueEnum)]
+pub enum StateEncoding {
+    /// MessagePack
+    #[default]
+    Rmp,
+    /// MessagePack compressed with a zstd dictionary trained on sample states
+    ZstdDict,
+}
+
+/// The encoding used by a db, as recorded in the db.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EncodingRecord {
+    pub encoding: StateEncoding,
+    /// The trained dictionary, if the encoding uses one.
+    pub dictionary: Vec<u8>,
+}
+
+/// Encodes and decodes states with a given encoding.
+pub struct StateCodec {
+    record: EncodingRecord,
+    // zstd contexts can't be shared between threads, so each call takes one from a pool.
+    compressors: Mutex<Vec<Compressor<'static>>>,
+    decompressors: Mutex<Vec<Decompressor<'static>>>,
+}
+
+impl StateCodec {
+    pub fn from_record(record: EncodingRecord) -> Self {
+        Self {
+            record,
+            compressors: Mutex::new(Vec::new()),
+            decompressors: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn rmp() -> Self {
+        Self::from_record(EncodingRecord {
+            encoding: StateEncoding::Rmp,
+            dictionary: Vec::new(),
+        })
+    }
+
+    /// Creates a zstd dictionary codec trained on the given states.
+    pub fn train<T: Ctx>(samples: &[T]) -> Result<Self> {
+        let encoded: Vec<_> = samples.iter().map(serialize_state).collect();
+        let dictionary = zstd::dict::from_samples(&encoded, DICT_SIZE)?;
+        log::info!(
+            "Trained a {} byte state dictionary on {} samples",
+            dictionary.len(),
+            samples.len()
+        );
+        Ok(Self::from_record(EncodingRecord {
+            encoding: StateEncoding::ZstdDict,
+            dictionary,
+        }))
+    }
+
+    pub fn encoding(&self) -> StateEncoding {
+        self.record.encoding
+    }
+
+    pub fn record(&self) -> &EncodingRecord {
+        &self.record
+    }
+
+    fn take_compressor(&self) -> Result<Compressor<'static>> {
+        if let Some(c) = self.compressors.lock().unwrap().pop() {
+            return Ok(c);
+        }
+        let mut c = Compressor::with_dictionary(ZSTD_LEVEL, &self.record.dictionary)?;
+        // Every state in a db uses the same dictionary, so there's no need to store its id.
+        c.set_parameter(CParameter::DictIdFlag(false))?;
+        Ok(c)
+    }
+
+    fn take_decompressor(&self) -> Result<Decompressor<'static>> {
+        if let Some(d) = self.decompressors.lock().unwrap().pop() {
+            return Ok(d);
+        }
+        Ok(Decompressor::with_dictionary(&self.record.dictionary)?)
+    }
+
+    pub fn encode<T: Ctx>(&self, el: &T) -> Result<Vec<u8>> {
+        let raw = serialize_state(el);
+        match self.record.encoding {
+            StateEncoding::Rmp => Ok(raw),
+            StateEncoding::ZstdDict => {
+                let mut c = self.take_compressor()?;
+                let buf = c.compress(&raw);
+                self.compressors.lock().unwrap().push(c);
+                Ok(buf?)
+            }
+        }
+    }
+
+    pub fn decode<T: Ctx>(&self, buf: &[u8]) -> Result<T> {
+        match self.record.encoding {
+            StateEncoding::Rmp => deserialize_state(buf),
+            StateEncoding::ZstdDict => {
+                let mut d = self.take_decompressor()?;
+                // Frames record their size, so this is only an upper limit.
+                let raw = d.decompress(buf, 16 * std::mem::size_of::<T>() + 1024);
+                self.decompressors.lock().unwrap().push(d);
+                deserialize_state(&raw?)
+            }
+        }
+    }
+}
+
+/// Collects up to `count` states by seeded random walks from the given states,
+/// e.g. for training a dictionary.
+pub fn sample_states<W, T, L>(world: &W, starts: &[T], count: usize, seed: u64) -> Vec<T>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+{
+    let mut rng = SplitMix64::new(seed);
+    let mut samples = Vec::with_capacity(count);
+    // Each walk adds at least its start state.
+    for start in starts.iter().cycle().take(count) {
+        if samples.len() >= count {
+            break;
+        }
+        let mut ctx = ContextWrapper::new(start.clone());
+        samples.push(start.clone());
+        for _ in 0..SAMPLE_WALK_STEPS {
+            if samples.len() >= count || world.won(ctx.get()) {
+                break;
+            }
+            let mut next = single_step(world, ctx, u32::MAX);
+            if next.is_empty() {
+                break;
+            }
+            ctx = next.swap_remove(rng.below(next.len()));
+            samples.push(ctx.get().clone());
+        }
+    }
+    samples
+}
+
+/// Returns the codec for the encoding a db recorded, which its states can only be read with,
+/// warning if it isn't the requested encoding.
+pub fn resolve_codec(stored: EncodingRecord, requested: StateEncoding) -> StateCodec {
+    if stored.encoding != requested {
+        log::warn!(
+            "State db was written with {:?} encoding, using that instead of {:?}",
+            stored.encoding,
+            requested
+        );
+    }
+    match stored.encoding {
+        StateEncoding::Rmp => StateCodec::rmp(),
+        StateEncoding::ZstdDict => StateCodec::from_record(stored),
+    }
+}
+
+/// Creates a codec for the given encoding, training a dictionary on states sampled from the given states if needed.
+///
+/// If the state db already recorded an encoding (see `read_db_encoding`), its states can only be
+/// read with that encoding, so it's used instead, and no dictionary is trained.
+pub fn make_codec<W, T, L>(
+    world: &W,
+    starts: &[T],
+    encoding: StateEncoding,
+    stored: Option<EncodingRecord>,
+) -> Result<StateCodec>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+{
+    if let Some(record) = stored {
+        return Ok(resolve_codec(record, encoding));
+    }
+    match encoding {
+        StateEncoding::Rmp => Ok(StateCodec::rmp()),
+        StateEncoding::ZstdDict => {
+            StateCodec::train(&sample_states(world, starts, DICT_SAMPLES, 0))
+        }
+    }
+}
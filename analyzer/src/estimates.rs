@@ -1,3 +1,4 @@
+use crate::backward::BackwardReachability;
 use crate::context::*;
 use crate::steiner::approx::ApproxSteiner;
 use crate::steiner::graph::*;
@@ -17,15 +18,16 @@ pub const UNREASONABLE_TIME: u32 = 1 << 30;
 // What we basically need is a helper that contains the necessary cache elements
 // for scoring, that the DB can fall back to. Probably better than bloating the
 // DB struct and functionality.
-pub struct ContextScorer<'w, W, S, LI, CI, EI, A> {
+pub struct ContextScorer<'w, W: World, S, LI, CI, EI, A> {
     // the cache is a map from start point and remaining locations to u64
     // but we also hold the Algo which contains precalculations for generating
     world: &'w W,
     algo: A,
 
-    known_costs: Mutex<LruCache<(S, Vec<LI>, Vec<Edge<EI>>), u64, CommonHasher>>,
+    known_costs: Mutex<LruCache<(S, Option<S>, Vec<LI>, Vec<Edge<EI>>), u64, CommonHasher>>,
     required_locations: Vec<LI>,
     canon_locations: FxHashSet<CI>,
+    backward: BackwardReachability<W>,
 
     estimates: AtomicUsize,
     cached_estimates: AtomicUsize,
@@ -48,11 +50,18 @@ where
     fn new<T>(world: &'w W, startctx: &T, cache_size: usize) -> Self
     where
         T: Ctx<World = W>,
+        W::Location: Location<Context = T>,
     {
         let required_locations: Vec<_> = world
             .required_items()
             .into_iter()
             .flat_map(|(item, _)| world.get_item_locations(item))
+            .chain(
+                world
+                    .goal()
+                    .into_iter()
+                    .flat_map(|goal| goal.locations.iter().copied()),
+            )
             .collect();
         let canon_locations: FxHashSet<_> = required_locations
             .iter()
@@ -67,6 +76,7 @@ where
             )),
             required_locations,
             canon_locations,
+            backward: BackwardReachability::new(world, startctx),
             estimates: 0.into(),
             cached_estimates: 0.into(),
         }
@@ -80,6 +90,19 @@ where
         self.cached_estimates.load(Ordering::Acquire)
     }
 
+    /// A cheap lower bound on the time to win from the state, from backward reachability.
+    pub fn lower_bound_remaining_time<T>(&self, ctx: &T) -> u32
+    where
+        T: Ctx<World = W>,
+        W::Location: Location<Context = T>,
+    {
+        self.backward.lower_bound(self.world, ctx)
+    }
+
+    pub fn backward(&self) -> &BackwardReachability<W> {
+        &self.backward
+    }
+
     pub fn estimate_remaining_time<T>(&self, ctx: &T) -> u64
     where
         T: Ctx<World = W>,
@@ -116,7 +139,14 @@ where
                 .into_iter()
                 .flat_map(|(v, _)| v)
                 .filter(|&loc_id| !ctx.visited(loc_id))
+                .chain(
+                    self.world
+                        .goal()
+                        .into_iter()
+                        .flat_map(|goal| goal.locations_needed(ctx)),
+                )
                 .collect(),
+            self.world.goal().and_then(|goal| goal.spot),
             subsets,
         )
     }
@@ -158,21 +188,24 @@ where
     }
 
     /// Returns the estimate amount of time to get the specified locations from
-    /// the current state. Does not check whether these locations are todo.
+    /// the current state, and to reach the spot if given. Does not check whether
+    /// these locations are todo.
     pub fn estimate_time_to_get<T>(
         &self,
         ctx: &T,
         required: Vec<<W::Location as Location>::LocId>,
+        spot: Option<<W::Exit as Exit>::SpotId>,
         subsets: Vec<(HashSet<<W::Location as Location>::LocId, CommonHasher>, i16)>,
     ) -> u64
     where
         T: Ctx<World = W>,
         W::Location: Location<Context = T>,
     {
-        if required.is_empty() || self.world.won(ctx) {
+        let pos = ctx.position();
+        let spot = spot.filter(|&spot| spot != pos);
+        if (required.is_empty() && spot.is_none()) || self.world.won(ctx) {
             return 0;
         }
-        let pos = ctx.position();
         let extra_edges: Vec<_> = self
             .world
             .get_warps()
@@ -205,7 +238,7 @@ where
             }))
             .collect();
 
-        let key: (_, Vec<_>, Vec<_>) = (pos, required, extra_edges);
+        let key: (_, _, Vec<_>, Vec<_>) = (pos, spot, required, extra_edges);
         let mut locked_map = self.known_costs.lock().unwrap();
         if let Some(&c) = locked_map.get(&key) {
             drop(locked_map);
@@ -214,9 +247,10 @@ where
         } else {
             drop(locked_map);
             let nodes = key
-                .1
+                .2
                 .iter()
-                .map(|loc_id| loc_to_graph_node(self.world, *loc_id));
+                .map(|loc_id| loc_to_graph_node(self.world, *loc_id))
+                .chain(key.1.map(spot_to_graph_node::<W>));
             let node_subsets = subsets.iter().map(|(set, ct)| {
                 (
                     set.iter()
@@ -229,7 +263,7 @@ where
                 spot_to_graph_node::<W>(ctx.position()),
                 nodes.collect(),
                 node_subsets.collect(),
-                &key.2,
+                &key.3,
             ) {
                 // Extra warp cost is number of "branches" times min_warp_time
                 // Number of branches is number of edges minus number of unique starting nodes
@@ -279,6 +313,7 @@ where
     pub fn shortest_paths<T>(world: &'w W, startctx: &T, cache_size: usize) -> Self
     where
         T: Ctx<World = W>,
+        W::Location: Location<Context = T>,
     {
         let now = Instant::now();
         let sp = Self::new(world, startctx, cache_size);
@@ -0,0 +1,245 @@
+//! Counterfactual explanations of why a location can't be accessed.
+
+use crate::access::{accessible_spots, find_unused_links};
+use crate::context::*;
+use crate::world::*;
+use std::collections::BTreeSet;
+
+/// A set of additional items that makes the target accessible.
+pub enum ItemFix<I> {
+    /// No smaller set of items works (within the searched size).
+    Smallest(Vec<I>),
+    /// No item can be removed from this set, but a smaller set may exist.
+    Reduced(Vec<I>),
+    /// The target isn't accessible even with every remaining item.
+    NotFound,
+}
+
+pub struct Explanation<T: Ctx> {
+    pub loc_id: <<T::World as World>::Location as Location>::LocId,
+    pub spot: <<T::World as World>::Exit as Exit>::SpotId,
+    pub visited: bool,
+    /// The fastest state found at the target's spot, if reachable.
+    pub at_spot: Option<ContextWrapper<T>>,
+    pub accessible: bool,
+    /// The explanation of the target's rule at the state at its spot, if that's why it's inaccessible.
+    pub rule: Option<String>,
+    /// The unusable edges from reachable spots, if the target's spot can't be reached.
+    pub unused_links: Option<String>,
+    pub fix: Option<ItemFix<T::ItemId>>,
+}
+
+impl<T: Ctx> Explanation<T> {
+    pub fn summary(&self) -> String {
+        if self.visited {
+            return format!("{} is already visited", self.loc_id);
+        }
+        let mut lines = Vec::new();
+        match &self.at_spot {
+            Some(c) if self.accessible => lines.push(format!(
+                "{} is accessible: reached {} at {}ms",
+                self.loc_id,
+                self.spot,
+                c.elapsed()
+            )),
+            Some(c) => lines.push(format!(
+                "{} is inaccessible: reached {} at {}ms but its rule fails:\n{}",
+                self.loc_id,
+                self.spot,
+                c.elapsed(),
+                self.rule.as_deref().unwrap_or_default()
+            )),
+            None => lines.push(format!(
+                "{} is inaccessible: {} is unreachable. Unusable links:\n{}",
+                self.loc_id,
+                self.spot,
+                self.unused_links.as_deref().unwrap_or_default()
+            )),
+        }
+        match &self.fix {
+            Some(ItemFix::Smallest(items)) => lines.push(format!(
+                "Smallest set of additional items that makes it accessible: {}",
+                items_str(items)
+            )),
+            Some(ItemFix::Reduced(items)) => lines.push(format!(
+                "Additional items that make it accessible (none can be removed): {}",
+                items_str(items)
+            )),
+            Some(ItemFix::NotFound) => lines.push(String::from(
+                "Not accessible even with every item from unvisited locations",
+            )),
+            None => (),
+        }
+        lines.join("\n")
+    }
+}
+
+fn items_str<I: std::fmt::Display>(items: &[I]) -> String {
+    items
+        .iter()
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Returns the fastest state at the location's spot, and whether the location is accessible there.
+fn reach_location<W, T>(
+    world: &W,
+    ctx: &ContextWrapper<T>,
+    loc_id: <W::Location as Location>::LocId,
+    max_time: u32,
+) -> (Option<ContextWrapper<T>>, bool)
+where
+    W: World,
+    T: Ctx<World = W>,
+    W::Location: Location<Context = T>,
+{
+    let spot = world.get_location_spot(loc_id);
+    let mut spot_map = accessible_spots(world, ctx.clone(), max_time, true);
+    match spot_map.remove(&spot) {
+        Some(c) => {
+            let can = world.get_location(loc_id).can_access(c.get(), world);
+            (Some(c), can)
+        }
+        None => (None, false),
+    }
+}
+
+fn with_items<W, T>(world: &W, ctx: &ContextWrapper<T>, items: &[T::ItemId]) -> ContextWrapper<T>
+where
+    W: World,
+    T: Ctx<World = W>,
+    W::Location: Location<Context = T>,
+{
+    let mut ctx = ctx.clone();
+    for item in items {
+        ctx.get_mut().collect(*item, world);
+    }
+    ctx
+}
+
+/// Calls `f` on each subset of `items` of size `k`, in order, stopping if `f` returns true.
+fn for_each_subset<I: Copy>(
+    items: &[I],
+    k: usize,
+    chosen: &mut Vec<I>,
+    f: &mut impl FnMut(&[I]) -> bool,
+) -> bool {
+    if chosen.len() == k {
+        return f(chosen);
+    }
+    for (i, item) in items.iter().enumerate() {
+        chosen.push(*item);
+        let stop = for_each_subset(&items[i + 1..], k, chosen, f);
+        chosen.pop();
+        if stop {
+            return true;
+        }
+    }
+    false
+}
+
+/// Searches for the cheapest set of additional items that makes the location accessible.
+///
+/// Tries every set of up to `max_items` items from unvisited locations, preferring the fewest items
+/// and then the fastest access. Failing that, adds every item and removes them one at a time.
+fn find_item_fix<W, T>(
+    world: &W,
+    ctx: &ContextWrapper<T>,
+    loc_id: <W::Location as Location>::LocId,
+    max_time: u32,
+    max_items: usize,
+) -> ItemFix<T::ItemId>
+where
+    W: World,
+    T: Ctx<World = W>,
+    W::Location: Location<Context = T>,
+{
+    let candidates: Vec<T::ItemId> = world
+        .get_all_locations()
+        .iter()
+        .filter(|loc| ctx.get().todo(loc) && loc.item() != T::ItemId::default())
+        .map(|loc| loc.item())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    for k in 1..=max_items {
+        let mut best: Option<(u32, Vec<T::ItemId>)> = None;
+        for_each_subset(&candidates, k, &mut Vec::new(), &mut |items| {
+            if let (Some(c), true) =
+                reach_location(world, &with_items(world, ctx, items), loc_id, max_time)
+            {
+                if best.as_ref().map_or(true, |(t, _)| c.elapsed() < *t) {
+                    best = Some((c.elapsed(), items.to_vec()));
+                }
+            }
+            false
+        });
+        if let Some((_, items)) = best {
+            return ItemFix::Smallest(items);
+        }
+    }
+
+    let mut items = candidates;
+    if !reach_location(world, &with_items(world, ctx, &items), loc_id, max_time).1 {
+        return ItemFix::NotFound;
+    }
+    let mut i = 0;
+    while i < items.len() {
+        let mut fewer = items.clone();
+        fewer.remove(i);
+        if reach_location(world, &with_items(world, ctx, &fewer), loc_id, max_time).1 {
+            items = fewer;
+        } else {
+            i += 1;
+        }
+    }
+    ItemFix::Reduced(items)
+}
+
+/// Explains why the given location can't be accessed from the given state, and what would fix it.
+pub fn explain_location<W, T>(
+    world: &W,
+    ctx: ContextWrapper<T>,
+    loc_id: <W::Location as Location>::LocId,
+    max_time: u32,
+    max_items: usize,
+) -> Explanation<T>
+where
+    W: World,
+    T: Ctx<World = W>,
+    W::Location: Location<Context = T>,
+{
+    let spot = world.get_location_spot(loc_id);
+    let mut explanation = Explanation {
+        loc_id,
+        spot,
+        visited: ctx.get().visited(loc_id),
+        at_spot: None,
+        accessible: false,
+        rule: None,
+        unused_links: None,
+        fix: None,
+    };
+    if explanation.visited {
+        return explanation;
+    }
+
+    let spot_map = accessible_spots(world, ctx.clone(), max_time, true);
+    if let Some(c) = spot_map.get(&spot) {
+        let loc = world.get_location(loc_id);
+        explanation.accessible = loc.can_access(c.get(), world);
+        if !explanation.accessible {
+            explanation.rule = Some(loc.explain(c.get(), world));
+        }
+        explanation.at_spot = Some(c.clone());
+    } else {
+        explanation.unused_links = Some(find_unused_links(world, &spot_map));
+    }
+
+    if !explanation.accessible {
+        explanation.fix = Some(find_item_fix(world, &ctx, loc_id, max_time, max_items));
+    }
+    explanation
+}
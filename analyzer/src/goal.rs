@@ -0,0 +1,206 @@
+//! Objectives set at runtime in the settings file, in place of the victory rules.
+//!
+//! Every part given must be met to win: each item collected at least the given number of times,
+//! each listed location and every location in each listed region visited, each context value
+//! equal to the one given, and the position at the spot. For example, to collect 3 Gold Skulltula
+//! Tokens, clear the Deku Tree and finish at a particular spot at night:
+//!
+//! ```yaml
+//! goal:
+//!   items:
+//!     Gold_Skulltula_Token: 3
+//!   regions: [Deku Tree]
+//!   context:
+//!     tod: Night
+//!   spot: KF > Kokiri Village > Training Center
+//! ```
+
+use crate::context::*;
+use crate::settings::*;
+use crate::world::*;
+use std::fmt::Display;
+use yaml_rust::Yaml;
+
+type SpotId<W> = <<W as World>::Exit as Exit>::SpotId;
+type LocId<W> = <<W as World>::Location as Location>::LocId;
+type ItemId<W> = <<<W as World>::Location as Accessible>::Context as Ctx>::ItemId;
+type RegionId<W> = <<<W as World>::Location as Accessible>::Context as Ctx>::RegionId;
+type Context<W> = <<W as World>::Location as Accessible>::Context;
+type Expectation<W> = <Context<W> as Ctx>::Expectation;
+
+#[derive(Clone, Debug)]
+pub struct Goal<W: World + ?Sized> {
+    pub items: Vec<(ItemId<W>, i16)>,
+    pub locations: Vec<LocId<W>>,
+    pub context: Vec<Expectation<W>>,
+    pub spot: Option<SpotId<W>>,
+}
+
+impl<W: World + ?Sized> Display for Goal<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts: Vec<String> = self
+            .items
+            .iter()
+            .map(|(item, ct)| format!("{} x{}", item, ct))
+            .collect();
+        if !self.locations.is_empty() {
+            parts.push(format!("{} locations", self.locations.len()));
+        }
+        if !self.context.is_empty() {
+            parts.push(format!("{} context values", self.context.len()));
+        }
+        if let Some(spot) = self.spot {
+            parts.push(format!("reach {}", spot));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+/// Calls `f` on the value, or on each element if the value is a list.
+fn for_each_value<F>(val: &Yaml, mut f: F) -> Result<(), String>
+where
+    F: FnMut(&Yaml) -> Result<(), String>,
+{
+    match val {
+        Yaml::Array(vec) => vec.iter().try_for_each(f),
+        _ => f(val),
+    }
+}
+
+/// Returns the ids of every location in the region.
+fn region_locations<W: World>(world: &W, region: RegionId<W>) -> Vec<LocId<W>> {
+    world
+        .get_all_locations()
+        .iter()
+        .map(|loc| loc.id())
+        .filter(|&loc_id| W::get_spot_region(world.get_location_spot(loc_id)) == region)
+        .collect()
+}
+
+impl<W: World> Goal<W> {
+    /// Parses a goal from a map with any of `items`, `regions`, `locations`, `context` and `spot`.
+    ///
+    /// `items` is a map of item to count, or a list of items to collect once each.
+    /// `context` is a map of context values, given as in unit test expectations.
+    pub fn from_yaml(world: &W, yaml: &Yaml) -> Result<Self, String> {
+        let hash = yaml
+            .as_hash()
+            .ok_or_else(|| format!("goal should be a key-value map: {:?}", yaml))?;
+        let mut goal = Goal {
+            items: Vec::new(),
+            locations: Vec::new(),
+            context: Vec::new(),
+            spot: None,
+        };
+        let mut errs = Vec::new();
+        for (key, val) in hash {
+            let res = match key.as_str() {
+                Some("items") => match val.as_hash() {
+                    Some(items) => items.iter().try_for_each(|(item, ct)| {
+                        let ct = parse_int(item, ct)?;
+                        goal.items.push((parse_str_into(key, item)?, ct));
+                        Ok(())
+                    }),
+                    None => for_each_value(val, |item| {
+                        goal.items.push((parse_str_into(key, item)?, 1));
+                        Ok(())
+                    }),
+                },
+                Some("regions") => for_each_value(val, |region| {
+                    let region = parse_str_into(key, region)?;
+                    goal.locations.extend(region_locations(world, region));
+                    Ok(())
+                }),
+                Some("locations") => for_each_value(val, |loc| {
+                    goal.locations.push(parse_str_into(key, loc)?);
+                    Ok(())
+                }),
+                Some("context") => match val.as_hash() {
+                    Some(map) => map.iter().try_for_each(|(ckey, cval)| {
+                        let ckey = ckey.as_str().ok_or_else(|| {
+                            format!("goal context key must be string: {:?}", ckey)
+                        })?;
+                        goal.context
+                            .push(<Context<W> as Ctx>::parse_expect_context(ckey, cval)?);
+                        Ok(())
+                    }),
+                    None => Err(format!("goal context should be a key-value map: {:?}", val)),
+                },
+                Some("spot") => parse_str_into(key, val).map(|spot| goal.spot = Some(spot)),
+                _ => Err(format!("Unrecognized goal key: {:?}", key)),
+            };
+            if let Err(e) = res {
+                errs.push(e);
+            }
+        }
+        if !errs.is_empty() {
+            return Err(errs.join("\n"));
+        }
+        if goal.items.is_empty()
+            && goal.locations.is_empty()
+            && goal.context.is_empty()
+            && goal.spot.is_none()
+        {
+            return Err(String::from("goal has no objectives"));
+        }
+        goal.items.sort_unstable();
+        goal.items.dedup_by(|a, b| {
+            // Keep the larger count of duplicate items.
+            a.0 == b.0 && {
+                b.1 = std::cmp::max(a.1, b.1);
+                true
+            }
+        });
+        goal.locations.sort_unstable();
+        goal.locations.dedup();
+        Ok(goal)
+    }
+
+    /// Whether the state meets every part of the goal.
+    pub fn met<T>(&self, ctx: &T) -> bool
+    where
+        T: Ctx<World = W>,
+        W::Location: Location<Context = T>,
+    {
+        self.met_except_spot(ctx) && self.spot.map_or(true, |spot| ctx.position() == spot)
+    }
+
+    /// Whether the state meets every part of the goal other than being at its spot.
+    pub fn met_except_spot<T>(&self, ctx: &T) -> bool
+    where
+        T: Ctx<World = W>,
+        W::Location: Location<Context = T>,
+    {
+        self.items.iter().all(|&(item, ct)| ctx.count(item) >= ct)
+            && self.locations.iter().all(|&loc_id| ctx.visited(loc_id))
+            && (self.context.is_empty() || ctx.assert_expectations(&self.context).is_ok())
+    }
+
+    /// Returns the items still to collect, with the number still needed.
+    pub fn items_needed<T>(&self, ctx: &T) -> Vec<(ItemId<W>, i16)>
+    where
+        T: Ctx<World = W>,
+        W::Location: Location<Context = T>,
+    {
+        self.items
+            .iter()
+            .filter_map(|&(item, ct)| {
+                let have = ctx.count(item);
+                (have < ct).then_some((item, ct - have))
+            })
+            .collect()
+    }
+
+    /// Returns the goal's locations still to visit.
+    pub fn locations_needed<T>(&self, ctx: &T) -> Vec<LocId<W>>
+    where
+        T: Ctx<World = W>,
+        W::Location: Location<Context = T>,
+    {
+        self.locations
+            .iter()
+            .copied()
+            .filter(|&loc_id| !ctx.visited(loc_id))
+            .collect()
+    }
+}
@@ -1,5 +1,6 @@
 use crate::access::*;
 use crate::context::*;
+use crate::estimates::ContextScorer;
 use crate::new_hashset;
 use crate::world::*;
 use std::collections::HashSet;
@@ -149,6 +150,14 @@ where
         if ctx.elapsed() > max_time {
             return Err(ctx);
         }
+        if let Some(spot) = goal_spot_to_reach(world, ctx.get()) {
+            // No location leads to the spot, so finish by walking there.
+            let shortest_paths = ContextScorer::shortest_paths_tree_only(world, ctx.get());
+            return match move_to(world, ctx.clone(), spot, &shortest_paths) {
+                Ok(c) if world.won(c.get()) && c.elapsed() <= max_time => Ok(c),
+                _ => Err(ctx),
+            };
+        }
         match first_spot_with_locations_after_actions(world, ctx, max_depth, max_time) {
             Ok(c) => {
                 ctx = c;
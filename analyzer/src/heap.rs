@@ -2,7 +2,9 @@ extern crate plotlib;
 
 use crate::bucket::*;
 use crate::context::*;
-use crate::db::{HeapDB, HeapMetric};
+use crate::db::{check_db_dir, HeapDB};
+use crate::dbprofile::DbProfile;
+use crate::encoding::StateCodec;
 use crate::estimates::ContextScorer;
 #[cfg(feature = "mysql")]
 use crate::models::MySQLDB;
@@ -10,12 +12,12 @@ use crate::scoring::{
     BestTimes, EstimatedTimeMetric, EstimatorWrapper, ScoreMetric, TimeSinceAndElapsed,
 };
 use crate::steiner::*;
-use crate::storage::serialize_state;
 use crate::storage::ContextDB;
 use crate::timing::{TimingMutex, TimingMutexGuard, WallTimeStats};
 use crate::world::*;
 use anyhow::{anyhow, Result};
 use bucket_queue::{Bucket, BucketQueue, Queue};
+use humansize::{SizeFormatter, BINARY};
 use log;
 use plotlib::page::Page;
 use plotlib::repr::{Histogram, HistogramBins, Plot};
@@ -33,36 +35,181 @@ pub(crate) struct HeapElement<T: Ctx> {
     pub(crate) el: ContextWrapper<T>,
 }
 
-#[allow(unused)]
-pub(self) type TimeSinceRocksDb<'w, W, T> = HeapDB<'w, W, T, 16, TimeSinceAndElapsed<'w, W>>;
-#[allow(unused)]
-pub(self) type ElapsedTimeRocksDb<'w, W, T> = HeapDB<'w, W, T, 12, EstimatedTimeMetric<'w, W>>;
+pub(crate) type TimeSinceRocksDb<'w, W, T> = HeapDB<'w, W, T, 16, TimeSinceAndElapsed<'w, W>>;
+pub(crate) type ElapsedTimeRocksDb<'w, W, T> = HeapDB<'w, W, T, 12, EstimatedTimeMetric<'w, W>>;
 
-#[allow(unused)]
 #[cfg(feature = "mysql")]
-pub(self) type TimeSinceMySQLDB<'w, W, T> = MySQLDB<'w, W, T, 16, TimeSinceAndElapsed<'w, W>>;
-#[allow(unused)]
+pub(crate) type TimeSinceMySQLDB<'w, W, T> = MySQLDB<'w, W, T, 16, TimeSinceAndElapsed<'w, W>>;
 #[cfg(feature = "mysql")]
-pub(self) type ElapsedTimeMySQLDB<'w, W, T> = MySQLDB<'w, W, T, 12, EstimatedTimeMetric<'w, W>>;
+pub(crate) type ElapsedTimeMySQLDB<'w, W, T> = MySQLDB<'w, W, T, 12, EstimatedTimeMetric<'w, W>>;
+
+/// Bytes per queued element beyond its entry in the segment's `DoublePriorityQueue`,
+/// which is an `IndexMap` bucket of the hash, state and score (see `queue_element_size`).
+/// The map's index table holds a `usize` and a control byte per element at up to 7/8 load,
+/// and the queue keeps two `usize` heap positions per element, for about 3.3 `usize`s.
+const QUEUE_ELEMENT_OVERHEAD: usize = 4 * std::mem::size_of::<usize>();
+/// The fewest elements the queue holds, however small the memory budget.
+const QUEUE_MIN_CAPACITY: usize = 65_536;
+
+/// Returns the approximate bytes of memory each state takes in the queue. The queue holds
+/// bare states, not `ContextWrapper`s, since the db keeps the history and times.
+fn queue_element_size<T, P>() -> usize {
+    // An IndexMap bucket is the hash, key and value, laid out like this tuple.
+    std::mem::size_of::<(usize, T, P)>() + QUEUE_ELEMENT_OVERHEAD
+}
+
+/// Returns how many elements to evict from each segment, aiming for `min_evictions` overall
+/// (rounding may leave a few short). Each segment keeps a share of what's kept:
+/// half by its current size, and half by its share of the recent pops.
+fn eviction_counts(sizes: &[usize], recent: &[usize], min_evictions: usize) -> Vec<usize> {
+    let len: usize = sizes.iter().sum();
+    if len == 0 {
+        return vec![0; sizes.len()];
+    }
+    let keep = len.saturating_sub(min_evictions);
+    let total_recent: usize = recent.iter().sum();
+    sizes
+        .iter()
+        .zip(recent)
+        .map(|(&size, &pops)| {
+            let target = if total_recent == 0 {
+                keep * size / len
+            } else {
+                (keep * size / len + keep * pops / total_recent) / 2
+            };
+            size.saturating_sub(target)
+        })
+        .collect()
+}
 
-// These types have to be changed to affect the score type.
-pub(crate) type MetricType<'w, W> = TimeSinceAndElapsed<'w, W>;
+/// Returns how many elements to retrieve for the given segment and up, out of `free`,
+/// in proportion to the recent pops of those segments, clamped to `min..=max`.
+fn restore_share(recent: &[usize], segment: usize, free: usize, min: usize, max: usize) -> usize {
+    let total: usize = recent.iter().sum();
+    let from: usize = recent[segment..].iter().sum();
+    let amount = if total == 0 {
+        free / recent.len()
+    } else {
+        free * from / total
+    };
+    std::cmp::max(min, std::cmp::min(max, amount))
+}
+
+#[derive(Default)]
+struct SegmentStats {
+    pops: AtomicUsize,
+    /// Pops since the last eviction plus half the previous value, tracking the recent pop rate.
+    recent_pops: AtomicUsize,
+    spills: AtomicUsize,
+    retrievals: AtomicUsize,
+}
+
+/// A db that can back the queue.
+pub trait QueueDb<'w, W, T, const KS: usize, SM>: ContextDB<'w, W, T, KS, SM> + Sized
+where
+    W: World + 'w,
+    T: Ctx<World = W>,
+    SM: ScoreMetric<'w, W, T, KS> + 'w,
+{
+    fn open_queue_db(
+        db_path: &Path,
+        initial_max_time: u32,
+        metric: SM,
+        delete_dbs: bool,
+        codec: StateCodec,
+        dominance: bool,
+        profile: &DbProfile,
+    ) -> Result<Self>;
+
+    /// Checks the metadata of an existing db without opening it for writing,
+    /// returning a `VersionMismatch` error if it was written by a different build.
+    fn check_existing(db_path: &Path) -> Result<()>;
+}
+
+impl<'w, W, T, L, E, const KS: usize, SM> QueueDb<'w, W, T, KS, SM> for HeapDB<'w, W, T, KS, SM>
+where
+    W: World<Location = L, Exit = E> + 'w,
+    T: Ctx<World = W>,
+    L: Location<Context = T, Currency = E::Currency>,
+    E: Exit<Context = T>,
+    W::Warp: Warp<Context = T, SpotId = E::SpotId, Currency = E::Currency>,
+    SM: ScoreMetric<'w, W, T, KS> + 'w,
+{
+    fn open_queue_db(
+        db_path: &Path,
+        initial_max_time: u32,
+        metric: SM,
+        delete_dbs: bool,
+        codec: StateCodec,
+        dominance: bool,
+        profile: &DbProfile,
+    ) -> Result<Self> {
+        HeapDB::open(
+            db_path,
+            initial_max_time,
+            metric,
+            delete_dbs,
+            codec,
+            dominance,
+            profile,
+        )
+    }
+
+    fn check_existing(db_path: &Path) -> Result<()> {
+        check_db_dir::<T, _>(db_path, KS)
+    }
+}
 
-#[cfg(not(feature = "mysql"))]
-pub(self) type DbType<'w, W, T> = TimeSinceRocksDb<'w, W, T>;
 #[cfg(feature = "mysql")]
-pub(self) type DbType<'w, W, T> = TimeSinceMySQLDB<'w, W, T>;
-// Automatic from DbType
-pub(self) type Score<'w, W, T> = <DbType<'w, W, T> as HeapMetric>::Score;
-pub struct DbBackedQueue<'w, W, T>
+impl<'w, W, T, L, E, const KS: usize, SM> QueueDb<'w, W, T, KS, SM> for MySQLDB<'w, W, T, KS, SM>
+where
+    W: World<Location = L, Exit = E> + 'w,
+    T: Ctx<World = W>,
+    L: Location<Context = T, Currency = E::Currency>,
+    E: Exit<Context = T>,
+    W::Warp: Warp<Context = T, SpotId = E::SpotId, Currency = E::Currency>,
+    SM: ScoreMetric<'w, W, T, KS> + 'w,
+{
+    fn open_queue_db(
+        _db_path: &Path,
+        _initial_max_time: u32,
+        metric: SM,
+        delete_dbs: bool,
+        codec: StateCodec,
+        dominance: bool,
+        _profile: &DbProfile,
+    ) -> Result<Self> {
+        if codec.encoding() != crate::encoding::StateEncoding::Rmp {
+            log::warn!("MySQL db only supports the rmp state encoding");
+        }
+        if dominance {
+            log::warn!("MySQL db doesn't support dominance pruning");
+        }
+        let db = MySQLDB::connect(metric);
+        db.check_metadata(delete_dbs)?;
+        Ok(db)
+    }
+
+    fn check_existing(_db_path: &Path) -> Result<()> {
+        crate::models::check_mysql_metadata::<T>(
+            &mut crate::models::establish_connection(),
+            KS,
+            false,
+        )
+    }
+}
+
+pub struct DbBackedQueue<'w, W, T, const KS: usize, SM, DB>
 where
     T: Ctx<World = W>,
     W: World,
     W::Location: Location<Context = T>,
+    SM: ScoreMetric<'w, W, T, KS> + 'w,
+    DB: QueueDb<'w, W, T, KS, SM>,
 {
     // TODO: Make the bucket element just T.
-    queue: TimingMutex<BucketQueue<Segment<T, Score<'w, W, T>>>>,
-    db: DbType<'w, W, T>,
+    queue: TimingMutex<BucketQueue<Segment<T, SM::Score>>>,
+    db: DB,
     capacity: usize,
     iskips: AtomicUsize,
     pskips: AtomicUsize,
@@ -76,6 +223,7 @@ where
     retrieving: AtomicBool,
     // Locked by queue!
     processed_counts: Vec<AtomicUsize>,
+    segment_stats: Vec<SegmentStats>,
     world: &'w W,
 
     // timers
@@ -90,49 +238,69 @@ where
     extend_timer: WallTimeStats,
 }
 
-impl<'w, W, T> DbBackedQueue<'w, W, T>
+impl<'w, W, T, const KS: usize, SM, DB> DbBackedQueue<'w, W, T, KS, SM, DB>
 where
     W: World,
     T: Ctx<World = W>,
     W::Location: Location<Context = T>,
+    SM: ScoreMetric<'w, W, T, KS> + 'w,
+    DB: QueueDb<'w, W, T, KS, SM>,
 {
+    /// Opens the db and creates a queue that holds as many states in memory
+    /// as fit in `max_memory` bytes.
     pub fn new<P>(
         db_path: P,
         world: &'w W,
-        metric: MetricType<'w, W>,
+        metric: SM,
         initial_max_time: u32,
-        max_capacity: usize,
-        min_evictions: usize,
-        max_evictions: usize,
-        min_reshuffle: usize,
-        max_reshuffle: usize,
+        max_memory: usize,
         delete_dbs: bool,
-    ) -> Result<DbBackedQueue<'w, W, T>>
+        codec: StateCodec,
+        dominance: bool,
+        profile: &DbProfile,
+    ) -> Result<DbBackedQueue<'w, W, T, KS, SM, DB>>
     where
         P: AsRef<Path>,
     {
-        #[cfg(not(feature = "mysql"))]
-        let db = HeapDB::open(db_path, initial_max_time, metric, delete_dbs)?;
-        #[cfg(feature = "mysql")]
-        let db = MySQLDB::connect(metric);
+        let db = DB::open_queue_db(
+            db_path.as_ref(),
+            initial_max_time,
+            metric,
+            delete_dbs,
+            codec,
+            dominance,
+            profile,
+        )?;
         let max_possible_progress = W::NUM_CANON_LOCATIONS;
         let mut processed_counts = Vec::new();
         processed_counts.resize_with(max_possible_progress + 1, || 0.into());
+        let mut segment_stats = Vec::new();
+        segment_stats.resize_with(max_possible_progress + 1, SegmentStats::default);
+
+        let element_size = queue_element_size::<T, SM::Score>();
+        let capacity = std::cmp::max(max_memory / element_size, QUEUE_MIN_CAPACITY);
+        log::info!(
+            "Queue holds up to {} states of {} bytes each in {}",
+            capacity,
+            element_size,
+            SizeFormatter::new(max_memory, BINARY)
+        );
         let q = DbBackedQueue {
             queue: TimingMutex::new(BucketQueue::new()),
             db,
-            capacity: max_capacity,
+            capacity,
             iskips: 0.into(),
             pskips: 0.into(),
             dup_pskips: 0.into(),
-            min_evictions,
-            max_evictions,
-            min_reshuffle,
-            max_reshuffle,
+            min_evictions: capacity / 8,
+            max_evictions: capacity / 4,
+            min_reshuffle: capacity / 512,
+            max_reshuffle: capacity / 128,
             evictions: 0.into(),
             retrievals: 0.into(),
             retrieving: false.into(),
             processed_counts,
+            segment_stats,
             world,
             relock_timer: Default::default(),
             push_timer: Default::default(),
@@ -147,7 +315,7 @@ where
         Ok(q)
     }
 
-    pub fn db(&self) -> &DbType<'w, W, T> {
+    pub fn db(&self) -> &DB {
         &self.db
     }
 
@@ -196,7 +364,7 @@ where
         self.db.preserved_bests()
     }
 
-    pub fn heap_bests(&self) -> Vec<Option<Score<'w, W, T>>> {
+    pub fn heap_bests(&self) -> Vec<Option<SM::Score>> {
         let queue = self.queue.lock().unwrap();
         queue.peek_all_buckets_min()
     }
@@ -269,7 +437,7 @@ where
                 let (ctx, &p_max) = queue
                     .peek_segment_max(progress)
                     .ok_or(anyhow!("queue at capacity with no elements"))?;
-                let raw = serialize_state(ctx);
+                let raw = self.db.encode_state(ctx)?;
                 let p_max = p_max.clone();
 
                 // Release the lock while we inspect the result.
@@ -278,6 +446,9 @@ where
                 let BestTimes { elapsed, .. } = self.db.get_best_times_raw(&raw)?;
                 if score > p_max || (score == p_max && el.elapsed() >= elapsed) {
                     // Lower priority (or equal but later), evict the new item immediately
+                    self.segment_stats[progress]
+                        .spills
+                        .fetch_add(1, Ordering::Release);
                     self.db.evict(std::iter::once((el.into_inner(), score)))?;
                 } else {
                     // New item is better, evict some old_items.
@@ -291,7 +462,7 @@ where
 
                     // Recheck eviction criteria after reobtaining lock
                     if queue.len() == self.capacity {
-                        evicted = Some(Self::evict_internal(&mut queue, evictions));
+                        evicted = Some(self.evict_internal(&mut queue, evictions));
                     }
                     queue.push(el.into_inner(), progress, score);
                 }
@@ -310,8 +481,13 @@ where
         Ok(())
     }
 
-    fn evict_to_db(&self, ev: Vec<(T, Score<'w, W, T>)>, category: &str) -> Result<()> {
+    fn evict_to_db(&self, ev: Vec<(T, SM::Score)>, category: &str) -> Result<()> {
         let start = Instant::now();
+        for (el, _) in &ev {
+            self.segment_stats[el.count_visits()]
+                .spills
+                .fetch_add(1, Ordering::Release);
+        }
         self.db.evict(ev)?;
         self.evictions.fetch_add(1, Ordering::Release);
         log::debug!("{}:evict to db took {:?}", category, start.elapsed());
@@ -320,24 +496,81 @@ where
         Ok(())
     }
 
+    /// Returns popped elements that were never processed (e.g. by a lost remote worker)
+    /// to the queue via the db.
+    pub fn requeue<I>(&self, iter: I) -> Result<()>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut ev = Vec::new();
+        for el in iter {
+            if !self.db.was_processed(&el)? {
+                let score = self.db.lookup_score(&el)?;
+                ev.push((el, score));
+            }
+        }
+        if ev.is_empty() {
+            Ok(())
+        } else {
+            self.evict_to_db(ev, "requeue")
+        }
+    }
+
     /// Removes elements from the max end of each segment in the queue until we reach
-    /// the minimum desired evictions.
+    /// the minimum desired evictions. Segments popped more often recently keep more elements.
     fn evict_internal(
-        queue: &mut TimingMutexGuard<BucketQueue<Segment<T, Score<'w, W, T>>>>,
+        &self,
+        queue: &mut TimingMutexGuard<BucketQueue<Segment<T, SM::Score>>>,
         min_evictions: usize,
-    ) -> Vec<(T, Score<'w, W, T>)> {
-        let evicted = queue.pop_max_proportionally(min_evictions);
+    ) -> Vec<(T, SM::Score)> {
+        let sizes = queue.bucket_sizes();
+        let len: usize = sizes.iter().sum();
+        if len == 0 {
+            return Vec::new();
+        }
+        let recent: Vec<usize> = self.segment_stats[..sizes.len()]
+            .iter()
+            .map(|st| st.recent_pops.load(Ordering::Acquire))
+            .collect();
+        let counts = eviction_counts(&sizes, &recent, min_evictions);
+        let mut evicted = queue.pop_max_by_segment(&counts);
+        if evicted.len() < min_evictions {
+            evicted.extend(queue.pop_max_proportionally(min_evictions - evicted.len()));
+        }
         queue.shrink_to_fit();
+        for st in &self.segment_stats {
+            let _ = st
+                .recent_pops
+                .fetch_update(Ordering::AcqRel, Ordering::Acquire, |p| Some(p / 2));
+        }
         evicted
     }
 
+    /// Returns how many elements to retrieve from the db for the given segment and up,
+    /// in proportion to how often those segments were popped recently.
+    fn restore_amount(&self, segment: usize, free: usize, min: usize, max: usize) -> usize {
+        let recent: Vec<usize> = self
+            .segment_stats
+            .iter()
+            .map(|st| st.recent_pops.load(Ordering::Acquire))
+            .collect();
+        restore_share(&recent, segment, free, min, max)
+    }
+
+    fn count_pop(&self, progress: usize) {
+        self.processed_counts[progress].fetch_add(1, Ordering::Release);
+        let st = &self.segment_stats[progress];
+        st.pops.fetch_add(1, Ordering::Release);
+        st.recent_pops.fetch_add(1, Ordering::Release);
+    }
+
     /// Retrieves up to the given number of elements for the given segment from the db.
     fn retrieve(
         &self,
         segment: usize,
         num: usize,
         score_limit: u32,
-    ) -> Result<Vec<(T, usize, Score<'w, W, T>)>> {
+    ) -> Result<Vec<(T, usize, SM::Score)>> {
         log::debug!(
             "Beginning retrieve of {} entries from segment {} and up, we have {} total in the db",
             num,
@@ -353,6 +586,11 @@ where
                 (el, progress, score)
             })
             .collect();
+        for (_, progress, _) in &res {
+            self.segment_stats[*progress]
+                .retrievals
+                .fetch_add(1, Ordering::Release);
+        }
 
         Ok(res)
     }
@@ -366,7 +604,7 @@ where
                 // Only when we go a decent bit over
                 if !self.db.is_empty()
                     && db_best < u32::MAX
-                    && MetricType::<'w, W>::score_primary(min_score) > db_best * 11 / 10
+                    && SM::score_primary(min_score) > db_best * 11 / 10
                 {
                     queue = self.maybe_reshuffle(
                         progress,
@@ -401,7 +639,7 @@ where
                     queue = self.queue.track_lock(&self.pop_timer).unwrap();
                     continue;
                 }
-                self.processed_counts[progress].fetch_add(1, Ordering::Release);
+                self.count_pop(progress);
                 return Ok(Some(ContextWrapper::with_times(
                     ctx,
                     elapsed,
@@ -417,7 +655,7 @@ where
                     let ctx = self.db.pop(0)?;
                     if let Some(c) = &ctx {
                         let progress = c.get().count_visits();
-                        self.processed_counts[progress].fetch_add(1, Ordering::Release);
+                        self.count_pop(progress);
                     }
                     return Ok(ctx);
                 }
@@ -431,8 +669,8 @@ where
         progress: usize,
         min_to_restore: usize,
         max_to_restore: usize,
-        queue: TimingMutexGuard<'a, BucketQueue<Segment<T, Score<'w, W, T>>>>,
-    ) -> Result<TimingMutexGuard<'a, BucketQueue<Segment<T, Score<'w, W, T>>>>> {
+        queue: TimingMutexGuard<'a, BucketQueue<Segment<T, SM::Score>>>,
+    ) -> Result<TimingMutexGuard<'a, BucketQueue<Segment<T, SM::Score>>>> {
         if !self.retrieving.fetch_or(true, Ordering::AcqRel) {
             let r = self.maybe_reshuffle_locked(progress, min_to_restore, max_to_restore, queue);
             self.retrieving.store(false, Ordering::Release);
@@ -447,24 +685,20 @@ where
         progress: usize,
         min_to_restore: usize,
         max_to_restore: usize,
-        mut queue: TimingMutexGuard<'a, BucketQueue<Segment<T, Score<'w, W, T>>>>,
-    ) -> Result<TimingMutexGuard<'a, BucketQueue<Segment<T, Score<'w, W, T>>>>> {
+        mut queue: TimingMutexGuard<'a, BucketQueue<Segment<T, SM::Score>>>,
+    ) -> Result<TimingMutexGuard<'a, BucketQueue<Segment<T, SM::Score>>>> {
         let start = Instant::now();
-        let num_buckets = queue.approx_num_buckets();
         // Get a decent amount to refill
-        let num_to_restore = std::cmp::max(
+        let num_to_restore = self.restore_amount(
+            progress,
+            self.capacity - queue.len(),
             min_to_restore,
-            std::cmp::min(
-                max_to_restore,
-                (self.capacity - queue.len()) / (num_buckets + 1),
-            ),
+            max_to_restore,
         );
         let len = queue.len();
         let score_limit = if let Some((lower, upper)) = queue.peek_segment_priority_range(progress)
         {
-            (MetricType::<'w, W>::score_primary(*lower)
-                + MetricType::<'w, W>::score_primary(*upper))
-                / 2
+            (SM::score_primary(*lower) + SM::score_primary(*upper)) / 2
         } else {
             self.max_time()
         };
@@ -473,7 +707,7 @@ where
         }
         if self.capacity - len < num_to_restore {
             // evict at least twice that much.
-            let evicted = Self::evict_internal(
+            let evicted = self.evict_internal(
                 &mut queue,
                 std::cmp::max(self.min_evictions, 2 * num_to_restore),
             );
@@ -498,13 +732,10 @@ where
 
     fn maybe_fetch_for_empty_buckets<'a>(
         &'a self,
-        mut queue: TimingMutexGuard<'a, BucketQueue<Segment<T, Score<'w, W, T>>>>,
-    ) -> Result<TimingMutexGuard<'a, BucketQueue<Segment<T, Score<'w, W, T>>>>> {
+        mut queue: TimingMutexGuard<'a, BucketQueue<Segment<T, SM::Score>>>,
+    ) -> Result<TimingMutexGuard<'a, BucketQueue<Segment<T, SM::Score>>>> {
         // Runs over all the buckets
-        let Some(min_score) = queue
-            .peek_min()
-            .map(|p| MetricType::<'w, W>::score_primary(*p.1))
-        else {
+        let Some(min_score) = queue.peek_min().map(|p| SM::score_primary(*p.1)) else {
             return Ok(queue);
         };
         // threshold is 1/8 the difference between min score and a reasonable upper bound,
@@ -552,15 +783,14 @@ where
     fn do_retrieve_and_insert<'a>(
         &'a self,
         segment: usize,
-        mut queue: TimingMutexGuard<'a, BucketQueue<Segment<T, Score<'w, W, T>>>>,
-    ) -> Result<TimingMutexGuard<'a, BucketQueue<Segment<T, Score<'w, W, T>>>>> {
+        mut queue: TimingMutexGuard<'a, BucketQueue<Segment<T, SM::Score>>>,
+    ) -> Result<TimingMutexGuard<'a, BucketQueue<Segment<T, SM::Score>>>> {
         let start = Instant::now();
-        let num_to_restore = std::cmp::max(
+        let num_to_restore = self.restore_amount(
+            segment,
+            self.capacity - queue.len(),
             self.min_reshuffle,
-            std::cmp::min(
-                self.max_reshuffle,
-                (self.capacity - queue.len()) / W::NUM_CANON_LOCATIONS,
-            ),
+            self.max_reshuffle,
         );
         drop(queue);
         let res = self.retrieve(segment, num_to_restore, self.max_time())?;
@@ -575,9 +805,7 @@ where
 
     fn pop_special<F>(&self, n: usize, pop_func: F) -> Result<Vec<ContextWrapper<T>>>
     where
-        F: Fn(
-            &mut TimingMutexGuard<BucketQueue<Segment<T, Score<'w, W, T>>>>,
-        ) -> Option<(T, Score<'w, W, T>)>,
+        F: Fn(&mut TimingMutexGuard<BucketQueue<Segment<T, SM::Score>>>) -> Option<(T, SM::Score)>,
     {
         let mut vec = Vec::new();
         let mut queue = self.queue.track_lock(&self.pop_special_timer).unwrap();
@@ -607,7 +835,7 @@ where
                     continue;
                 }
                 let progress = ctx.count_visits();
-                self.processed_counts[progress].fetch_add(1, Ordering::Release);
+                self.count_pop(progress);
 
                 vec.push(ContextWrapper::with_times(ctx, elapsed, time_since_visit));
                 if vec.len() == n {
@@ -624,7 +852,7 @@ where
                     self.retrieving.store(false, Ordering::Release);
                 } else if let Some(ctx) = self.db.pop(0)? {
                     let progress = ctx.get().count_visits();
-                    self.processed_counts[progress].fetch_add(1, Ordering::Release);
+                    self.count_pop(progress);
                     vec.push(ctx);
                 } else {
                     return Ok(vec);
@@ -638,8 +866,8 @@ where
     where
         F: Fn(
             usize,
-            &mut TimingMutexGuard<BucketQueue<Segment<T, Score<'w, W, T>>>>,
-        ) -> Vec<(T, Score<'w, W, T>)>,
+            &mut TimingMutexGuard<BucketQueue<Segment<T, SM::Score>>>,
+        ) -> Vec<(T, SM::Score)>,
     {
         let mut vec = Vec::new();
         let mut queue = self
@@ -675,7 +903,7 @@ where
                         continue;
                     }
                     let progress = ctx.count_visits();
-                    self.processed_counts[progress].fetch_add(1, Ordering::Release);
+                    self.count_pop(progress);
 
                     vec.push(ContextWrapper::with_times(ctx, elapsed, time_since_visit));
                 }
@@ -696,7 +924,7 @@ where
                     self.retrieving.store(false, Ordering::Release);
                 } else if let Some(ctx) = self.db.pop(0)? {
                     let progress = ctx.get().count_visits();
-                    self.processed_counts[progress].fetch_add(1, Ordering::Release);
+                    self.count_pop(progress);
                     vec.push(ctx);
                 } else {
                     return Ok(vec);
@@ -776,7 +1004,7 @@ where
                             continue;
                         }
                         let progress = ctx.count_visits();
-                        self.processed_counts[progress].fetch_add(1, Ordering::Release);
+                        self.count_pop(progress);
                         vec.push(ContextWrapper::with_times(ctx, elapsed, time_since_visit));
                         let start = Instant::now();
                         queue = self.queue.track_lock(&self.pop_local_min_timer).unwrap();
@@ -852,7 +1080,7 @@ where
                             }
 
                             let progress = ctx.count_visits();
-                            self.processed_counts[progress].fetch_add(1, Ordering::Release);
+                            self.count_pop(progress);
                             vec.push(ContextWrapper::with_times(ctx, elapsed, time_since_visit));
                             let start = Instant::now();
                             queue = self.queue.track_lock(&self.pop_round_robin_timer).unwrap();
@@ -895,7 +1123,7 @@ where
                                     continue;
                                 }
                                 let progress = ctx.count_visits();
-                                self.processed_counts[progress].fetch_add(1, Ordering::Release);
+                                self.count_pop(progress);
                                 vec.push(ContextWrapper::with_times(
                                     ctx,
                                     elapsed,
@@ -930,7 +1158,7 @@ where
                         self.retrieving.store(false, Ordering::Release);
                     } else if let Some(ctx) = self.db.pop(0)? {
                         let progress = ctx.get().count_visits();
-                        self.processed_counts[progress].fetch_add(1, Ordering::Release);
+                        self.count_pop(progress);
                         return Ok(vec![ctx]);
                     }
                 }
@@ -1029,14 +1257,14 @@ where
         &self,
         mut vec: Vec<ContextWrapper<T>>,
         prev: &T,
-    ) -> Result<Vec<(T, usize, Score<'w, W, T>)>> {
+    ) -> Result<Vec<(T, usize, SM::Score)>> {
         let mut iskips = 0;
         let keeps = self.db.record_processed(prev, &mut vec)?;
         debug_assert!(vec.len() == keeps.len());
         // TODO: Is it inefficient to deconstruct the wrapper here,
         // and then reconstruct the wrapper for the one element we keep,
         // or can we extract it first somehow?
-        let vec: Vec<(T, usize, Score<'w, W, T>)> = vec
+        let vec: Vec<(T, usize, SM::Score)> = vec
             .into_iter()
             .zip(keeps.into_iter())
             .filter_map(|(el, keep)| {
@@ -1062,7 +1290,7 @@ where
         Ok(vec)
     }
 
-    fn internal_extend(&self, vec: Vec<(T, usize, Score<'w, W, T>)>) -> Result<()>
+    fn internal_extend(&self, vec: Vec<(T, usize, SM::Score)>) -> Result<()>
     where
         T: Ctx<World = W>,
     {
@@ -1073,7 +1301,7 @@ where
             start = Instant::now();
             let len = queue.len();
             if len + vec.len() > self.capacity {
-                evicted = Some(Self::evict_internal(
+                evicted = Some(self.evict_internal(
                     &mut queue,
                     std::cmp::min(
                         std::cmp::max(len + vec.len() - self.capacity, self.min_evictions),
@@ -1122,6 +1350,27 @@ where
         )
     }
 
+    /// Returns the pops, spills to the db and retrievals from the db of each segment.
+    pub fn segment_stats(&self) -> String {
+        self.segment_stats
+            .iter()
+            .enumerate()
+            .filter(|(_, st)| {
+                st.pops.load(Ordering::Acquire) > 0 || st.spills.load(Ordering::Acquire) > 0
+            })
+            .map(|(progress, st)| {
+                format!(
+                    "{}: pop={} spill={} retr={}",
+                    progress,
+                    st.pops.load(Ordering::Acquire),
+                    st.spills.load(Ordering::Acquire),
+                    st.retrievals.load(Ordering::Acquire),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
     pub fn print_queue_histogram(&self) {
         let queue = self.queue.lock().unwrap();
         if queue.is_empty() {
@@ -1132,7 +1381,7 @@ where
         let mut prog_score = Vec::new();
         let mut prog_estimates = Vec::new();
         for (progress, _, score) in queue.iter() {
-            let primary = MetricType::<'w, W>::score_primary(*score) as f64;
+            let primary = SM::score_primary(*score) as f64;
             let total_estimate = self.db.metric().total_estimate_from_score(*score) as f64;
             let progress = progress as f64;
             progresses.push(progress);
@@ -1177,7 +1426,7 @@ where
         );
 
         // We can avoid an extra graph if the score is just the total estimate only
-        if std::mem::size_of::<Score<'w, W, T>>() > std::mem::size_of::<u32>() {
+        if std::mem::size_of::<SM::Score>() > std::mem::size_of::<u32>() {
             let p = Plot::new(prog_estimates)
                 .point_style(PointStyle::new().marker(PointMarker::Circle));
             let v = ContinuousView::new()
@@ -1203,5 +1452,56 @@ where
             "States checked since last time:\n{}",
             Page::single(&v).dimensions(90, 10).to_text().unwrap(),
         );
+        println!("Queue segments: {}", self.segment_stats());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_eviction_counts_by_size() {
+        // Without recent pops, every segment keeps the same fraction.
+        assert_eq!(
+            eviction_counts(&[100, 300, 0], &[0, 0, 0], 200),
+            vec![50, 150, 0]
+        );
+        assert_eq!(eviction_counts(&[0, 0], &[0, 0], 10), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_eviction_counts_by_recent_pops() {
+        // Keeping 200: 50/150 by size and 200/0 by pops average to 125/75,
+        // and a segment asked to keep more than it has keeps everything.
+        assert_eq!(eviction_counts(&[100, 300], &[10, 0], 200), vec![0, 225]);
+        // 5/195 by size and 0/200 by pops average to 2/197.
+        assert_eq!(eviction_counts(&[10, 390], &[0, 10], 200), vec![8, 193]);
+    }
+
+    #[test]
+    fn test_restore_share() {
+        // Without recent pops, an even share per segment.
+        assert_eq!(restore_share(&[0, 0, 0, 0], 1, 400, 0, 1000), 100);
+        // Otherwise, the share of pops at the segment and up.
+        assert_eq!(restore_share(&[30, 10, 0, 0], 1, 400, 0, 1000), 100);
+        assert_eq!(restore_share(&[30, 10, 0, 0], 0, 400, 0, 1000), 400);
+        assert_eq!(restore_share(&[30, 10, 0, 0], 2, 400, 0, 1000), 0);
+        // Clamped to the bounds.
+        assert_eq!(restore_share(&[30, 10, 0, 0], 2, 400, 25, 1000), 25);
+        assert_eq!(restore_share(&[30, 10, 0, 0], 0, 400, 0, 300), 300);
+    }
+
+    #[test]
+    fn test_queue_element_size() {
+        // The hash and score are padded up to the alignment of the state.
+        assert_eq!(
+            queue_element_size::<u64, u32>(),
+            24 + QUEUE_ELEMENT_OVERHEAD
+        );
+        assert_eq!(
+            queue_element_size::<[u8; 100], u32>(),
+            112 + QUEUE_ELEMENT_OVERHEAD
+        );
     }
 }
@@ -15,18 +15,27 @@ extern crate yaml_rust;
 
 mod a_star;
 pub mod access;
+pub mod anneal;
+pub mod backward;
 pub mod bucket;
 pub mod cli;
 pub mod condense;
 pub mod context;
 pub mod db;
+pub mod dbprofile;
 pub mod direct;
+pub mod distributed;
+pub mod dot;
+pub mod encoding;
 pub mod estimates;
+pub mod explain;
+pub mod goal;
 pub mod greedy;
 pub mod heap;
 pub mod matchertrie;
 pub mod minimize;
 pub mod observer;
+pub mod passes;
 pub mod priority;
 #[cfg(not(target_env = "msvc"))]
 pub mod prof;
@@ -34,10 +43,14 @@ pub mod route;
 pub mod scoring;
 pub mod search;
 pub mod settings;
+pub mod softlock;
 pub mod solutions;
+pub mod soundness;
 pub mod steiner;
 pub mod storage;
+pub mod svg;
 pub mod timing;
+pub mod util;
 pub mod world;
 
 #[cfg(feature = "mysql")]
@@ -1,17 +1,24 @@
+use crate::a_star::*;
 use crate::access::*;
 use crate::context::*;
 use crate::direct::DirectPaths;
 use crate::matchertrie::MatcherTrie;
 use crate::new_hashmap;
 use crate::observer::{short_observations, record_observations, TrieMatcher};
+use crate::priority::LimitedPriorityQueue;
 use crate::solutions::{Solution, SolutionSuffix};
+use crate::steiner::graph::ExternalNodeId;
 use crate::steiner::{EdgeId, NodeId, ShortestPaths};
 use crate::world::*;
 use crate::CommonHasher;
+use rayon::prelude::*;
 use std::collections::{HashMap, VecDeque};
 use std::ops::RangeInclusive;
 use std::sync::Arc;
 
+/// Sizes of collection windows that `mutate_segment_windows` can solve exactly.
+pub const SEGMENT_WINDOW_SIZES: RangeInclusive<usize> = 3..=5;
+
 /// Attempts to create better solutions by removing sections of the route
 /// based on observations.
 pub fn pinpoint_minimize<W, T, L, TM>(
@@ -149,7 +156,7 @@ where
     vec
 }
 
-trait RangeAndStepTuple<T: Ctx> {
+pub(crate) trait RangeAndStepTuple<T: Ctx> {
     fn range(&self) -> &RangeInclusive<usize>;
     fn step(&self) -> &HistoryAlias<T>;
 }
@@ -172,7 +179,7 @@ impl<T: Ctx> RangeAndStepTuple<T> for (RangeInclusive<usize>, HistoryAlias<T>, u
     }
 }
 
-fn rediscover_routes<'a, W, T, L, RT>(
+pub(crate) fn rediscover_routes<'a, W, T, L, RT>(
     world: &W,
     mut rreplay: ContextWrapper<T>,
     iter: impl Iterator<Item = &'a RT>,
@@ -680,6 +687,308 @@ where
     }
 }
 
+/// An action-counted state that also tracks which window steps it has performed.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct CtxWithWindow<T: Ctx> {
+    inner: CtxWithActionCounter<T>,
+    // bitmask of the window steps already performed
+    done: usize,
+}
+
+impl<T> CtxWrapper<T> for CtxWithWindow<T>
+where
+    T: Ctx,
+{
+    fn ctx(&self) -> &ContextWrapper<T> {
+        self.inner.ctx()
+    }
+    fn copy_update(&self, newctx: ContextWrapper<T>) -> Self {
+        CtxWithWindow {
+            inner: self.inner.copy_update(newctx),
+            done: self.done,
+        }
+    }
+    fn new_incr(&self, newctx: ContextWrapper<T>) -> Self {
+        CtxWithWindow {
+            inner: self.inner.new_incr(newctx),
+            done: self.done,
+        }
+    }
+    fn can_continue(&self, max_depth: usize) -> bool {
+        self.inner.can_continue(max_depth)
+    }
+    fn unique_spot(&self) -> (<<<T as Ctx>::World as World>::Exit as Exit>::SpotId, usize) {
+        self.inner.unique_spot()
+    }
+}
+
+/// Searches exhaustively (up to max_states) for the fastest way to perform all of the given
+/// collection steps, in any order, ending at the given spot.
+fn solve_window<W, T, L>(
+    world: &W,
+    ctx: ContextWrapper<T>,
+    steps: &[HistoryAlias<T>],
+    end_spot: <W::Exit as Exit>::SpotId,
+    max_time: u32,
+    max_depth: usize,
+    max_states: usize,
+    shortest_paths: &ShortestPaths<NodeId<W>, EdgeId<W>>,
+) -> Option<ContextWrapper<T>>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+{
+    let all_done = (1 << steps.len()) - 1;
+    let step_spots: Vec<_> = steps
+        .iter()
+        .map(|step| match step {
+            History::G(_, loc_id) | History::V(_, loc_id, _) => world.get_location_spot(*loc_id),
+            History::A(act_id) => world.get_action_spot(*act_id),
+            _ => Default::default(),
+        })
+        .collect();
+    let step_times: Vec<_> = steps
+        .iter()
+        .map(|step| match step {
+            History::G(_, loc_id) | History::V(_, loc_id, _) => {
+                world.get_location(*loc_id).base_time()
+            }
+            History::A(act_id) => world.get_action(*act_id).base_time(),
+            _ => 0,
+        })
+        .collect();
+
+    let goal = ExternalNodeId::Spot(end_spot);
+    let score_func = |el: &CtxWithWindow<T>| -> Option<u32> {
+        let ctx = el.ctx();
+        let mut scores = vec![shortest_paths
+            .min_distance(ExternalNodeId::Spot(ctx.get().position()), goal)
+            .map(|u| u as u32)];
+        // We need to take into account contextual warps which aren't otherwise part
+        // of a normal shortest paths graph.
+        for warp in world.get_warps() {
+            scores.push(
+                shortest_paths
+                    .min_distance(ExternalNodeId::Spot(warp.dest(ctx.get(), world)), goal)
+                    .map(|u| warp.time(ctx.get(), world) + u as u32),
+            );
+        }
+        // Every remaining step still has to be performed.
+        let remaining: u32 = step_times
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| el.done & (1 << i) == 0)
+            .map(|(_, t)| *t)
+            .sum();
+        scores
+            .into_iter()
+            .flatten()
+            .min()
+            .map(|u| u + remaining + ctx.elapsed())
+    };
+    let key_func = |el: &CtxWithWindow<T>| (el.ctx().get().clone(), el.done);
+
+    let mut spot_heap =
+        LimitedPriorityQueue::with_capacity_and_limit(std::cmp::min(1_024, max_states), max_states);
+    let item = CtxWithWindow {
+        inner: CtxWithActionCounter {
+            el: ctx,
+            counter: 0,
+        },
+        done: 0,
+    };
+    if let Some(score) = score_func(&item) {
+        let unique_key = key_func(&item);
+        spot_heap.push(item, unique_key, score);
+    }
+
+    while let Some((el, _)) = spot_heap.pop() {
+        let ctx = el.ctx();
+        if el.done == all_done && ctx.get().position() == end_spot {
+            return Some(el.inner.el);
+        }
+        if spot_heap.is_expired() {
+            return None;
+        }
+
+        for (i, step) in steps.iter().enumerate() {
+            if el.done & (1 << i) == 0 && ctx.can_replay(world, *step) {
+                let mut newctx = ctx.clone();
+                newctx.replay(world, *step);
+                if newctx.elapsed() <= max_time {
+                    let item = CtxWithWindow {
+                        inner: CtxWithActionCounter {
+                            el: newctx,
+                            counter: el.inner.counter,
+                        },
+                        done: el.done | (1 << i),
+                    };
+                    if let Some(score) = score_func(&item) {
+                        let unique_key = key_func(&item);
+                        spot_heap.push(item, unique_key, score);
+                    }
+                }
+            }
+        }
+
+        let pos = ctx.get().position();
+        let allow_local = W::same_area(pos, end_spot)
+            || step_spots
+                .iter()
+                .enumerate()
+                .any(|(i, s)| el.done & (1 << i) == 0 && W::same_area(pos, *s));
+        expand_astar(
+            world,
+            &el,
+            max_time,
+            &mut spot_heap,
+            &score_func,
+            &key_func,
+            allow_local,
+            el.can_continue(max_depth),
+        );
+        if el.can_continue(max_depth) {
+            expand_actions_astar(world, &el, max_time, &mut spot_heap, &score_func, &key_func);
+        }
+    }
+    None
+}
+
+/// Replays the solution and returns the state just before each of the given collection ranges.
+pub(crate) fn collection_checkpoints<W, T, L>(
+    world: &W,
+    startctx: &T,
+    solution: &Solution<T>,
+    collection_hist: &[(RangeInclusive<usize>, HistoryAlias<T>)],
+) -> Vec<ContextWrapper<T>>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+{
+    let mut replay = ContextWrapper::new(startctx.clone());
+    let mut vec = Vec::with_capacity(collection_hist.len());
+    for (range, _) in collection_hist {
+        vec.push(replay.clone());
+        assert!(
+            replay.maybe_replay_all(world, &solution.history[range.clone()]),
+            "Could not replay base solution history range {:?}",
+            range,
+        );
+    }
+    vec
+}
+
+/// Attempts to improve short windows of consecutive collection steps by solving them exactly.
+///
+/// For each window of `window` collection steps, the state before the window and the spot at the end
+/// of the window are fixed, and a bounded A* search finds the fastest way to perform the same collections
+/// in any order. An improvement is spliced in front of the rest of the route, which is replayed as-is
+/// where possible and rediscovered otherwise.
+///
+/// Windows are solved independently in parallel, and the fastest resulting route is returned.
+pub fn mutate_segment_windows<W, T, L>(
+    world: &W,
+    startctx: &T,
+    max_time: u32,
+    window: usize,
+    max_depth: usize,
+    max_states: usize,
+    solution: Arc<Solution<T>>,
+    shortest_paths: &ShortestPaths<NodeId<W>, EdgeId<W>>,
+    direct_paths: &(impl DirectPaths<W, T> + Sync),
+) -> Option<ContextWrapper<T>>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+{
+    assert!(
+        window > 1 && window < usize::BITS as usize,
+        "Invalid segment window size: {}",
+        window
+    );
+    // Restrict max time to being strictly less than the given solution, since we'll only return if we improve anyway.
+    let max_time = std::cmp::min(max_time, solution.elapsed.saturating_sub(1));
+    // [(history range inclusive of the collection step, history step)]
+    let collection_hist: Vec<_> =
+        collection_history_with_range_info::<T, _>(solution.history.iter().copied()).collect();
+    if collection_hist.len() < window {
+        return None;
+    }
+    let checkpoints = collection_checkpoints(world, startctx, &solution, &collection_hist);
+
+    (0..=collection_hist.len() - window)
+        .into_par_iter()
+        .filter_map(|coll_ai| {
+            let replay = &checkpoints[coll_ai];
+            let start = *collection_hist[coll_ai].0.start();
+            let end = *collection_hist[coll_ai + window - 1].0.end();
+            let mut orig_window = replay.clone();
+            assert!(
+                orig_window.maybe_replay_all(world, &solution.history[start..=end]),
+                "Could not replay base solution history range {}..={}",
+                start,
+                end,
+            );
+            let steps: Vec<_> = collection_hist[coll_ai..coll_ai + window]
+                .iter()
+                .map(|(_, step)| *step)
+                .collect();
+
+            let solved = solve_window(
+                world,
+                replay.clone(),
+                &steps,
+                orig_window.get().position(),
+                orig_window.elapsed().saturating_sub(1),
+                max_depth,
+                max_states,
+                shortest_paths,
+            )?;
+            log::debug!(
+                "Solved window of {} collections at #{} in {}ms (was {}ms)",
+                window,
+                coll_ai,
+                solved.elapsed() - replay.elapsed(),
+                orig_window.elapsed() - replay.elapsed(),
+            );
+            match solved
+                .clone()
+                .try_replay_all(world, solution.history[end + 1..].iter())
+            {
+                Ok(spliced) if spliced.elapsed() <= max_time && world.won(spliced.get()) => {
+                    Some(spliced)
+                }
+                _ => {
+                    let mut spliced = rediscover_routes(
+                        world,
+                        solved,
+                        collection_hist[coll_ai + window..].iter(),
+                        max_time,
+                        max_depth,
+                        max_states,
+                        &solution.history,
+                        shortest_paths,
+                        direct_paths,
+                    )?;
+                    // The steps after the last collection (e.g. moving to the victory spot)
+                    // aren't part of any collection range, so replay them before checking.
+                    let tail_start = collection_hist.last().map_or(0, |(r, _)| *r.end() + 1);
+                    (spliced.maybe_replay_all(world, &solution.history[tail_start..])
+                        && spliced.elapsed() <= max_time
+                        && world.won(spliced.get()))
+                    .then_some(spliced)
+                }
+            }
+        })
+        .min_by_key(|spliced| spliced.elapsed())
+}
+
 /// Use a matcher trie to minimize a solution
 pub fn trie_minimize<W, T, L, TM>(
     world: &W,
@@ -1,5 +1,5 @@
 use crate::context::{ContextWrapper, Ctx, HistoryAlias, Wrapper};
-use crate::db::HeapMetric;
+use crate::db::{DbMetadata, HeapMetric, VersionMismatch};
 use crate::estimates::UNREASONABLE_TIME;
 use crate::schema::db_states::dsl::*;
 use crate::scoring::{BestTimes, EstimatorWrapper, ScoreMetric};
@@ -28,6 +28,7 @@ const INVALID_ESTIMATE: u32 = crate::estimates::UNREASONABLE_TIME + 3;
 const TEST_DATABASE_URL: &'static str = "mysql://logic_graph@localhost/logic_graph__unittest";
 const EVICT_CHUNK_SIZE: usize = 10;
 const RETRIEVE_CHUNK_SIZE: usize = 10;
+const STATES_METADATA: &str = "states";
 
 define_sql_function!(
     #[sql_name = "IF"]
@@ -55,6 +56,54 @@ pub fn establish_connection() -> MysqlConnection {
         .unwrap_or_else(|_| panic!("Error connecting to {}", database_url))
 }
 
+/// Checks the metadata of the states table, returning a `VersionMismatch` error if it was written by
+/// a different build or its states were scored by a metric with a different key size. A table with states but no metadata was written before the table was stamped,
+/// so it's treated as a different build. If `stamp` is set, an empty table is stamped with the
+/// current build's metadata.
+pub fn check_mysql_metadata<T: Ctx>(
+    conn: &mut MysqlConnection,
+    key_size: usize,
+    stamp: bool,
+) -> Result<()> {
+    use crate::schema::db_metadata;
+    let current = DbMetadata::for_states::<T>(key_size);
+    let stored: Option<Vec<u8>> = db_metadata::table
+        .filter(db_metadata::name.eq(STATES_METADATA))
+        .select(db_metadata::data)
+        .first(conn)
+        .optional()?;
+    let stored = match stored {
+        Some(bytes) => Some(get_obj_from_data::<DbMetadata>(&bytes)?),
+        None => {
+            let has_states = db_states
+                .select(raw_state)
+                .first::<Vec<u8>>(conn)
+                .optional()?
+                .is_some();
+            if !has_states {
+                if stamp {
+                    diesel::insert_into(db_metadata::table)
+                        .values((
+                            db_metadata::name.eq(STATES_METADATA),
+                            db_metadata::data.eq(serialize_data(&current)),
+                        ))
+                        .execute(conn)?;
+                }
+                return Ok(());
+            }
+            None
+        }
+    };
+    if stored.as_ref() != Some(&current) {
+        return Err(anyhow::Error::new(VersionMismatch {
+            db: String::from("mysql states"),
+            stored,
+            current,
+        }));
+    }
+    Ok(())
+}
+
 #[derive(Debug, Default, Queryable, Selectable, Insertable, Eq, PartialEq, Ord, PartialOrd)]
 #[diesel(table_name = crate::schema::db_states, check_for_backend(Mysql))]
 pub struct DBState {
@@ -450,7 +499,7 @@ where
         for (prog, score) in db_states
             .filter(queries::available(self.max_time()))
             .group_by(progress)
-            // Ideally this would be changeable to the current MetricType primary
+            // Ideally this would be changeable to the current metric's primary
             // (we can't do min on a tuple) but writing a type is hard. TODO: features?
             .select((progress, min(time_since_visit)))
             .get_results::<(u32, Option<u32>)>(&mut self.pool_connection())
@@ -744,6 +793,18 @@ where
         }
     }
 
+    /// Stamps the states table with the current build's metadata if it's empty, or returns a
+    /// `VersionMismatch` error if it was written by a different build. If `delete_first` is set,
+    /// clears the table first.
+    pub fn check_metadata(&self, delete_first: bool) -> Result<()> {
+        let mut conn = self.pool_connection();
+        if delete_first {
+            diesel::delete(db_states).execute(&mut conn)?;
+            diesel::delete(crate::schema::db_metadata::table).execute(&mut conn)?;
+        }
+        check_mysql_metadata::<T>(&mut conn, KS, true)
+    }
+
     /// Opens a DB connection and starts a test transaction to it, ensuring that no changes are made during the test.
     pub fn with_test_connection(metric: SM) -> Self {
         use diesel_migrations::*;
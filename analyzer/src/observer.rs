@@ -1,5 +1,7 @@
-use crate::context::{history_to_full_series, Ctx, History, HistoryAlias};
+use crate::context::{history_to_full_series, ContextWrapper, Ctx, History, HistoryAlias};
+use crate::db::SolutionDb;
 use crate::matchertrie::*;
+use crate::new_hashmap;
 use crate::solutions::{Solution, SolutionSuffix};
 use crate::world::*;
 use std::fmt::Debug;
@@ -54,22 +56,19 @@ where
 {
 }
 
-// This is here to allow benchmarking without a SolutionCollector.
-/// Records a full solution's observations into the solve trie.
-///
-/// Every state that has visited at least |min_relevant| locations is recorded in the trie,
-/// except for the winning state.
-pub fn record_observations<W, T, TM>(
+/// Walks a full solution backwards, calling `f` with the observations of each state that has visited
+/// at least |min_relevant| locations, except for the winning state, along with the index of the step
+/// taken from that state.
+pub(crate) fn for_each_solution_observation<W, T>(
     startctx: &T,
     world: &W,
-    solution: Arc<Solution<T>>,
+    solution: &Solution<T>,
     min_relevant: usize,
-    solve_trie: &MatcherTrie<TM, SolutionSuffix<T>>,
+    mut f: impl FnMut(Vec<T::PropertyObservation>, usize),
 ) where
     W: World,
     T: Ctx<World = W>,
     W::Location: Location<Context = T>,
-    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
 {
     let full_history = history_to_full_series(startctx, world, solution.history.iter().copied());
     // The history entries are the steps "in between" the states in full_history, so we should have
@@ -114,11 +113,69 @@ pub fn record_observations<W, T, TM>(
         // 2. Apply the observations in reverse order.
         solve.apply_observations();
 
-        // 3. Insert the new observation list.
-        solve_trie.insert(solve.to_vec(state), SolutionSuffix(solution.clone(), idx));
+        // 3. Hand off the new observation list.
+        f(solve.to_vec(state), idx);
     }
 }
 
+// This is here to allow benchmarking without a SolutionCollector.
+/// Records a full solution's observations into the solve trie.
+///
+/// Every state that has visited at least |min_relevant| locations is recorded in the trie,
+/// except for the winning state.
+pub fn record_observations<W, T, TM>(
+    startctx: &T,
+    world: &W,
+    solution: Arc<Solution<T>>,
+    min_relevant: usize,
+    solve_trie: &MatcherTrie<TM, SolutionSuffix<T>>,
+) where
+    W: World,
+    T: Ctx<World = W>,
+    W::Location: Location<Context = T>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+{
+    for_each_solution_observation(startctx, world, &solution, min_relevant, |obs, idx| {
+        solve_trie.insert(obs, SolutionSuffix(solution.clone(), idx))
+    });
+}
+
+/// Loads the stored solve trie into the given trie, for every stored solution that still wins
+/// from the given start state.
+///
+/// Stored solutions that no longer replay (e.g. from different settings) are skipped, along with
+/// their observations. Returns the number of solutions loaded.
+pub fn record_stored_observations<W, T, TM>(
+    startctx: &T,
+    world: &W,
+    solution_db: &SolutionDb<T>,
+    solve_trie: &MatcherTrie<TM, SolutionSuffix<T>>,
+) -> anyhow::Result<usize>
+where
+    W: World,
+    T: Ctx<World = W>,
+    W::Location: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = <W::Location as Accessible>::Currency>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+{
+    let mut solutions = new_hashmap();
+    for (id, solution) in solution_db.solutions_by_id()? {
+        match ContextWrapper::new(startctx.clone()).try_replay_all(world, solution.history.iter()) {
+            Ok(ctx) if world.won(ctx.get()) => {
+                solutions.insert(id, solution);
+            }
+            Ok(_) => log::debug!("Skipping stored solution that no longer wins"),
+            Err(e) => log::debug!("Skipping stored solution: {}", e),
+        }
+    }
+    solution_db.for_each_trie_entry(|observations, suffix| {
+        if let Some(solution) = solutions.get(&suffix.solution_id) {
+            solve_trie.insert(observations, SolutionSuffix(solution.clone(), suffix.index));
+        }
+    })?;
+    Ok(solutions.len())
+}
+
 /// Records a non-winning step sequence into the given trie.
 ///
 /// This does not need to start from nothing, but the solution provided must be applicable from the starting state.
@@ -0,0 +1,620 @@
+//! Minimize passes, and a pipeline that runs them in a configurable order.
+
+use crate::context::*;
+use crate::direct::DirectPaths;
+use crate::matchertrie::MatcherTrie;
+use crate::minimize::*;
+use crate::observer::{record_observations, TrieMatcher};
+use crate::settings::parse_int;
+use crate::solutions::{Solution, SolutionSuffix};
+use crate::steiner::{EdgeId, NodeId, ShortestPaths};
+use crate::world::*;
+use rayon::prelude::*;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use yaml_rust::{Yaml, YamlLoader};
+
+/// Everything a minimize pass may use besides the solution itself.
+pub struct PassEnv<'a, W, T, TM, DP>
+where
+    W: World,
+    T: Ctx<World = W>,
+{
+    pub world: &'a W,
+    pub startctx: &'a T,
+    pub max_depth: usize,
+    pub max_states: usize,
+    pub shortest_paths: &'a ShortestPaths<NodeId<W>, EdgeId<W>>,
+    pub direct_paths: &'a DP,
+    pub trie: &'a MatcherTrie<TM, SolutionSuffix<T>>,
+}
+
+pub trait MinimizePass<W, T, TM, DP>: Sync
+where
+    W: World,
+    T: Ctx<World = W>,
+{
+    fn name(&self) -> String;
+
+    /// Runs the pass once over the solution, returning a faster winning route if one was found.
+    fn run(
+        &self,
+        env: &PassEnv<W, T, TM, DP>,
+        solution: Arc<Solution<T>>,
+    ) -> Option<ContextWrapper<T>>;
+}
+
+pub struct TriePass;
+pub struct GreedyCollectionsPass;
+pub struct SpotRevisitsPass;
+pub struct CanonLocationsPass;
+pub struct CollectionReorderPass;
+pub struct SegmentWindowsPass(pub usize);
+
+/// Runs independent passes concurrently on the same solution and keeps the fastest result.
+pub struct ParallelPass<W, T, TM, DP>(pub Vec<Box<dyn MinimizePass<W, T, TM, DP>>>)
+where
+    W: World,
+    T: Ctx<World = W>;
+
+impl<W, T, L, TM, DP> MinimizePass<W, T, TM, DP> for TriePass
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    DP: DirectPaths<W, T> + Sync,
+{
+    fn name(&self) -> String {
+        String::from("trie")
+    }
+
+    fn run(
+        &self,
+        env: &PassEnv<W, T, TM, DP>,
+        solution: Arc<Solution<T>>,
+    ) -> Option<ContextWrapper<T>> {
+        trie_minimize(env.world, env.startctx, solution, env.trie)
+    }
+}
+
+impl<W, T, L, TM, DP> MinimizePass<W, T, TM, DP> for GreedyCollectionsPass
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    DP: DirectPaths<W, T> + Sync,
+{
+    fn name(&self) -> String {
+        String::from("greedy_collections")
+    }
+
+    fn run(
+        &self,
+        env: &PassEnv<W, T, TM, DP>,
+        solution: Arc<Solution<T>>,
+    ) -> Option<ContextWrapper<T>> {
+        mutate_greedy_collections(
+            env.world,
+            env.startctx,
+            solution.elapsed,
+            env.max_depth,
+            env.max_states,
+            solution,
+            env.shortest_paths,
+            env.direct_paths,
+        )
+    }
+}
+
+impl<W, T, L, TM, DP> MinimizePass<W, T, TM, DP> for SpotRevisitsPass
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    DP: DirectPaths<W, T> + Sync,
+{
+    fn name(&self) -> String {
+        String::from("spot_revisits")
+    }
+
+    /// Swaps segments between revisits of the same spot, records each winning mutation in the
+    /// trie, then minimizes the fastest mutation with the trie. The mutations themselves are only
+    /// used as observations, so only a trie improvement is returned.
+    fn run(
+        &self,
+        env: &PassEnv<W, T, TM, DP>,
+        solution: Arc<Solution<T>>,
+    ) -> Option<ContextWrapper<T>> {
+        let mut mutations = mutate_spot_revisits(env.world, env.startctx, solution);
+        let old_len = mutations.len();
+        mutations.retain(|c| env.world.won(c.get()));
+        let min = mutations.iter().min_by_key(|c| c.elapsed())?.to_solution();
+        log::info!(
+            "Route swapping got {} solutions (best={}ms) and {} partials",
+            mutations.len(),
+            min.elapsed,
+            old_len - mutations.len()
+        );
+        mutations.par_iter().for_each(|m| {
+            record_observations(env.startctx, env.world, m.to_solution(), 0, env.trie)
+        });
+        trie_minimize(env.world, env.startctx, min, env.trie)
+    }
+}
+
+impl<W, T, L, TM, DP> MinimizePass<W, T, TM, DP> for CanonLocationsPass
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    DP: DirectPaths<W, T> + Sync,
+{
+    fn name(&self) -> String {
+        String::from("canon_locations")
+    }
+
+    fn run(
+        &self,
+        env: &PassEnv<W, T, TM, DP>,
+        solution: Arc<Solution<T>>,
+    ) -> Option<ContextWrapper<T>> {
+        mutate_canon_locations(
+            env.world,
+            env.startctx,
+            solution.elapsed,
+            env.max_depth,
+            env.max_states,
+            solution,
+            env.shortest_paths,
+            env.direct_paths,
+            |_| {},
+        )
+    }
+}
+
+impl<W, T, L, TM, DP> MinimizePass<W, T, TM, DP> for CollectionReorderPass
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    DP: DirectPaths<W, T> + Sync,
+{
+    fn name(&self) -> String {
+        String::from("reorder_collections")
+    }
+
+    fn run(
+        &self,
+        env: &PassEnv<W, T, TM, DP>,
+        solution: Arc<Solution<T>>,
+    ) -> Option<ContextWrapper<T>> {
+        mutate_collection_steps(
+            env.world,
+            env.startctx,
+            solution.elapsed,
+            env.max_depth,
+            env.max_states,
+            solution,
+            env.shortest_paths,
+            env.direct_paths,
+        )
+    }
+}
+
+impl<W, T, L, TM, DP> MinimizePass<W, T, TM, DP> for SegmentWindowsPass
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    DP: DirectPaths<W, T> + Sync,
+{
+    fn name(&self) -> String {
+        format!("segment_windows_{}", self.0)
+    }
+
+    fn run(
+        &self,
+        env: &PassEnv<W, T, TM, DP>,
+        solution: Arc<Solution<T>>,
+    ) -> Option<ContextWrapper<T>> {
+        mutate_segment_windows(
+            env.world,
+            env.startctx,
+            solution.elapsed,
+            self.0,
+            env.max_depth,
+            env.max_states,
+            solution,
+            env.shortest_paths,
+            env.direct_paths,
+        )
+    }
+}
+
+impl<W, T, L, TM, DP> MinimizePass<W, T, TM, DP> for ParallelPass<W, T, TM, DP>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    DP: DirectPaths<W, T> + Sync,
+{
+    fn name(&self) -> String {
+        self.0
+            .iter()
+            .map(|p| p.name())
+            .collect::<Vec<_>>()
+            .join("+")
+    }
+
+    fn run(
+        &self,
+        env: &PassEnv<W, T, TM, DP>,
+        solution: Arc<Solution<T>>,
+    ) -> Option<ContextWrapper<T>> {
+        self.0
+            .par_iter()
+            .filter_map(|p| p.run(env, solution.clone()))
+            .min_by_key(|c| c.elapsed())
+    }
+}
+
+/// Names accepted by `pass_by_name`. Segment windows also accept a size suffix, e.g. `segment_windows_4`.
+pub const PASS_NAMES: &[&str] = &[
+    "trie",
+    "greedy_collections",
+    "spot_revisits",
+    "canon_locations",
+    "reorder_collections",
+    "segment_windows",
+];
+
+/// The registry of minimize passes.
+pub fn pass_by_name<W, T, L, TM, DP>(
+    name: &str,
+) -> Result<Box<dyn MinimizePass<W, T, TM, DP>>, String>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    DP: DirectPaths<W, T> + Sync,
+{
+    match name {
+        "trie" => Ok(Box::new(TriePass)),
+        "greedy_collections" => Ok(Box::new(GreedyCollectionsPass)),
+        "spot_revisits" => Ok(Box::new(SpotRevisitsPass)),
+        "canon_locations" => Ok(Box::new(CanonLocationsPass)),
+        "reorder_collections" => Ok(Box::new(CollectionReorderPass)),
+        _ => {
+            if let Some(size) = name.strip_prefix("segment_windows_") {
+                let size = size
+                    .parse()
+                    .map_err(|e| format!("Bad segment window size in '{}': {:?}", name, e))?;
+                if SEGMENT_WINDOW_SIZES.contains(&size) {
+                    return Ok(Box::new(SegmentWindowsPass(size)));
+                }
+                Err(format!(
+                    "Segment window size {} must be in {:?}",
+                    size, SEGMENT_WINDOW_SIZES
+                ))
+            } else {
+                Err(format!(
+                    "Unrecognized minimize pass '{}', expected one of: {}",
+                    name,
+                    PASS_NAMES.join(", ")
+                ))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Repeat {
+    Times(usize),
+    UntilStable,
+}
+
+pub struct PassStep<W, T, TM, DP>
+where
+    W: World,
+    T: Ctx<World = W>,
+{
+    pub pass: Box<dyn MinimizePass<W, T, TM, DP>>,
+    pub repeat: Repeat,
+}
+
+fn passes_named<W, T, L, TM, DP>(
+    name: &str,
+) -> Result<Vec<Box<dyn MinimizePass<W, T, TM, DP>>>, String>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    DP: DirectPaths<W, T> + Sync,
+{
+    if name == "segment_windows" {
+        Ok(SEGMENT_WINDOW_SIZES
+            .map(|size| Box::new(SegmentWindowsPass(size)) as Box<dyn MinimizePass<W, T, TM, DP>>)
+            .collect())
+    } else {
+        Ok(vec![pass_by_name(name)?])
+    }
+}
+
+fn step<W, T, L, TM, DP>(name: &str, repeat: Repeat) -> Result<Vec<PassStep<W, T, TM, DP>>, String>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    DP: DirectPaths<W, T> + Sync,
+{
+    Ok(passes_named(name)?
+        .into_iter()
+        .map(|pass| PassStep { pass, repeat })
+        .collect())
+}
+
+/// A step running the named passes concurrently on the same solution.
+fn parallel_step<W, T, L, TM, DP>(
+    names: &[&str],
+    repeat: Repeat,
+) -> Result<PassStep<W, T, TM, DP>, String>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    DP: DirectPaths<W, T> + Sync,
+{
+    let mut passes = Vec::new();
+    for name in names {
+        passes.extend(passes_named(name)?);
+    }
+    Ok(PassStep {
+        pass: Box::new(ParallelPass(passes)),
+        repeat,
+    })
+}
+
+/// The default pipeline, used when no passes are configured.
+pub fn default_pipeline<W, T, L, TM, DP>() -> Vec<PassStep<W, T, TM, DP>>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    DP: DirectPaths<W, T> + Sync,
+{
+    let mut vec: Vec<PassStep<W, T, TM, DP>> = Vec::new();
+    vec.push(PassStep {
+        pass: Box::new(TriePass),
+        repeat: Repeat::Times(1),
+    });
+    // Both start from the trie-minimized route and don't depend on each other.
+    vec.push(PassStep {
+        pass: Box::new(ParallelPass(vec![
+            Box::new(GreedyCollectionsPass),
+            Box::new(SpotRevisitsPass),
+        ])),
+        repeat: Repeat::Times(1),
+    });
+    vec.push(PassStep {
+        pass: Box::new(CanonLocationsPass),
+        repeat: Repeat::UntilStable,
+    });
+    vec.push(PassStep {
+        pass: Box::new(CollectionReorderPass),
+        repeat: Repeat::UntilStable,
+    });
+    vec.extend(SEGMENT_WINDOW_SIZES.map(|size| PassStep {
+        pass: Box::new(SegmentWindowsPass(size)),
+        repeat: Repeat::UntilStable,
+    }));
+    vec.push(PassStep {
+        pass: Box::new(TriePass),
+        repeat: Repeat::Times(1),
+    });
+    vec
+}
+
+/// Parses a pipeline from the `passes` section of yaml of the form:
+///
+/// ```yaml
+/// passes:
+///   - trie
+///   - parallel: [greedy_collections, spot_revisits]
+///   - name: reorder_collections
+///     repeat: until_stable
+///   - name: segment_windows_4
+///     repeat: 2
+/// ```
+///
+/// Passes listed under `parallel` run concurrently on the same route, and the fastest result is kept.
+pub fn parse_pipeline<W, T, L, TM, DP>(yaml: &Yaml) -> Result<Vec<PassStep<W, T, TM, DP>>, String>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    DP: DirectPaths<W, T> + Sync,
+{
+    let Some(list) = yaml["passes"].as_vec() else {
+        return Err(format!("Expected a list of 'passes' but got: {:?}", yaml));
+    };
+    let name_key = Yaml::String(String::from("name"));
+    let parallel_key = Yaml::String(String::from("parallel"));
+    let repeat_key = Yaml::String(String::from("repeat"));
+    let mut vec = Vec::new();
+    let mut errs = Vec::new();
+    for el in list {
+        let res = match el {
+            Yaml::String(s) => step(s, Repeat::Times(1)),
+            Yaml::Hash(h) => {
+                let repeat = match h.get(&repeat_key) {
+                    None => Repeat::Times(1),
+                    Some(Yaml::String(s)) if s == "until_stable" => Repeat::UntilStable,
+                    Some(val) => match parse_int(&repeat_key, val) {
+                        Ok(n) => Repeat::Times(n),
+                        Err(e) => {
+                            errs.push(e);
+                            continue;
+                        }
+                    },
+                };
+                if let Some(name) = h.get(&name_key).and_then(|n| n.as_str()) {
+                    step(name, repeat)
+                } else if let Some(names) = h.get(&parallel_key).and_then(|n| n.as_vec()) {
+                    let names: Option<Vec<_>> = names.iter().map(|n| n.as_str()).collect();
+                    match names {
+                        Some(names) => parallel_step(&names, repeat).map(|s| vec![s]),
+                        None => Err(format!("Parallel passes should be names: {:?}", el)),
+                    }
+                } else {
+                    Err(format!("Pass is missing a name: {:?}", el))
+                }
+            }
+            _ => Err(format!("Expected a pass name or map but got: {:?}", el)),
+        };
+        match res {
+            Ok(steps) => vec.extend(steps),
+            Err(e) => errs.push(e),
+        }
+    }
+    if errs.is_empty() {
+        Ok(vec)
+    } else {
+        Err(errs.join("\n"))
+    }
+}
+
+/// Reads the `passes` section of a yaml file. If the file has no `passes` section, returns an error
+/// if `required` is set, and otherwise returns None.
+pub fn read_pipeline<W, T, L, TM, DP, P>(
+    path: &P,
+    required: bool,
+) -> Result<Option<Vec<PassStep<W, T, TM, DP>>>, String>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    DP: DirectPaths<W, T> + Sync,
+    P: AsRef<Path> + std::fmt::Debug,
+{
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("Couldn't read from file {:?}: {:?}", path, e))?;
+    let yaml = YamlLoader::load_from_str(&text).map_err(|e| format!("YAML parse error: {}", e))?;
+    let yaml = yaml.first().unwrap_or(&Yaml::Null);
+    if !required && yaml["passes"].is_badvalue() {
+        return Ok(None);
+    }
+    parse_pipeline(yaml).map(Some)
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PassReport {
+    pub name: String,
+    pub runs: usize,
+    pub improvements: usize,
+    pub saved: u32,
+    pub duration: Duration,
+}
+
+/// Runs each pass in order (repeating as configured), feeding each improvement into the following passes
+/// and recording its observations in the trie.
+///
+/// Returns the best route found, if any, and a report per pass step.
+pub fn run_pipeline<W, T, L, TM, DP>(
+    env: &PassEnv<W, T, TM, DP>,
+    pipeline: &[PassStep<W, T, TM, DP>],
+    mut solution: Arc<Solution<T>>,
+) -> (Option<ContextWrapper<T>>, Vec<PassReport>)
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+    TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    DP: DirectPaths<W, T> + Sync,
+{
+    let mut best = None;
+    let mut reports = Vec::with_capacity(pipeline.len());
+    for step in pipeline {
+        let mut report = PassReport {
+            name: step.pass.name(),
+            ..Default::default()
+        };
+        let start = Instant::now();
+        loop {
+            if let Repeat::Times(n) = step.repeat {
+                if report.runs >= n {
+                    break;
+                }
+            }
+            report.runs += 1;
+            let Some(better) = step.pass.run(env, solution.clone()) else {
+                break;
+            };
+            if better.elapsed() >= solution.elapsed {
+                break;
+            }
+            println!(
+                "Improved route via {} from {}ms to {}ms",
+                report.name,
+                solution.elapsed,
+                better.elapsed()
+            );
+            report.improvements += 1;
+            report.saved += solution.elapsed - better.elapsed();
+            solution = better.to_solution();
+            record_observations(env.startctx, env.world, solution.clone(), 0, env.trie);
+            best = Some(better);
+        }
+        report.duration = start.elapsed();
+        reports.push(report);
+    }
+    (best, reports)
+}
+
+pub fn format_reports(reports: &[PassReport]) -> String {
+    let width = reports.iter().map(|r| r.name.len()).max().unwrap_or(0);
+    reports
+        .iter()
+        .map(|r| {
+            format!(
+                "{:width$}  runs={:<3} improvements={:<3} saved={}ms in {:?}",
+                r.name,
+                r.runs,
+                r.improvements,
+                r.saved,
+                r.duration,
+                width = width
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -7,7 +7,9 @@ use crate::steiner::graph::*;
 use crate::steiner::*;
 use crate::world::*;
 use lazy_static::lazy_static;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use similar::TextDiff;
 use std::hash::Hash;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -102,6 +104,17 @@ pub(crate) fn find_route_in_solution_string(solution: &str) -> &str {
     solution
 }
 
+/// Splits a solutions file, as written by the search, into its solutions in file order.
+pub(crate) fn solutions_from_string(text: &str) -> Vec<&str> {
+    let mut starts: Vec<usize> = text
+        .match_indices("Solution #")
+        .map(|(i, _)| i)
+        .filter(|&i| i == 0 || text[..i].ends_with('\n'))
+        .collect();
+    starts.push(text.len());
+    starts.windows(2).map(|w| &text[w[0]..w[1]]).collect()
+}
+
 /// Parses the history into a list.
 pub(crate) fn hist_from_string<T>(route: &str) -> Result<Vec<HistoryAlias<T>>, String>
 where
@@ -274,6 +287,287 @@ where
     }
 }
 
+/// A change made to a route line while migrating it to the current world.
+pub enum RouteChange {
+    /// The line didn't parse, and its name was replaced by the most similar name of the same kind.
+    Renamed {
+        line: usize,
+        old: String,
+        new: String,
+        similarity: f32,
+    },
+    /// The step failed to replay, and was replaced by moving to where the step is now.
+    /// `new` holds one replacement line per line.
+    Repathed {
+        line: usize,
+        old: String,
+        new: String,
+        error: String,
+    },
+    /// The line couldn't be parsed or replayed, and was removed.
+    Dropped {
+        line: usize,
+        old: String,
+        error: String,
+    },
+}
+
+impl std::fmt::Display for RouteChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RouteChange::Renamed {
+                line,
+                old,
+                new,
+                similarity,
+            } => write!(
+                f,
+                "line {}: renamed (similarity {:.2})\n  - {}\n  + {}",
+                line, similarity, old, new
+            ),
+            RouteChange::Repathed {
+                line,
+                old,
+                new,
+                error,
+            } => {
+                write!(f, "line {}: re-pathed\n  - {}", line, old)?;
+                for l in new.lines() {
+                    write!(f, "\n  + {}", l)?;
+                }
+                write!(f, "\n  {}", error)
+            }
+            RouteChange::Dropped { line, old, error } => {
+                write!(f, "line {}: dropped\n  - {}\n  {}", line, old, error)
+            }
+        }
+    }
+}
+
+pub struct MigratedRoute<T: Ctx> {
+    /// The repaired route text, keeping comments and unchanged lines as they were.
+    pub route: String,
+    pub changes: Vec<RouteChange>,
+    /// The state at the end of the repaired route.
+    pub ctx: ContextWrapper<T>,
+}
+
+/// Names of each kind of world element that may be referenced in a route line.
+struct WorldNames {
+    locations: Vec<String>,
+    exits: Vec<String>,
+    actions: Vec<String>,
+    spots: Vec<String>,
+}
+
+impl WorldNames {
+    fn new<W: World>(world: &W) -> Self {
+        let spots = world.get_all_spots();
+        WorldNames {
+            locations: world
+                .get_all_locations()
+                .iter()
+                .map(|loc| loc.id().to_string())
+                .collect(),
+            exits: spots
+                .iter()
+                .flat_map(|sp| world.get_spot_exits(*sp))
+                .map(|ex| ex.id().to_string())
+                .collect(),
+            actions: world
+                .get_global_actions()
+                .iter()
+                .chain(spots.iter().flat_map(|sp| world.get_spot_actions(*sp)))
+                .map(|act| act.id().to_string())
+                .collect(),
+            spots: spots.iter().map(|sp| sp.to_string()).collect(),
+        }
+    }
+}
+
+static MIN_NAME_SIMILARITY: f32 = 0.6;
+
+/// Replaces the name in a route line that failed to parse with the most similar name of the same kind,
+/// returning the parsed step, the new line, and the similarity of the names.
+fn rename_line<T: Ctx>(line: &str, names: &WorldNames) -> Option<(HistoryAlias<T>, String, f32)> {
+    lazy_static! {
+        // Each captures the name referenced in a kind of route line, as parsed by History::from_str.
+        static ref LOC_NAME: Regex = Regex::new(
+            r"^(?:\* )?(?:[Cc]ollect \w+ from |[Vv]isit |Take hybrid exit )(?P<name>[^=,]*?)(?: ==> [^=]*|, collecting \w+)?$").unwrap();
+        static ref EXIT_NAME: Regex = Regex::new(
+            r"^(?:[Mm]ove(?:\.\.\.)? to |Take exit )(?P<name>[^=]* ==> [^=]*)$").unwrap();
+        static ref ACTION_NAME: Regex = Regex::new(
+            r"^(?:! )?(?:[Dd]o|[Aa]ctivate) (?P<name>.*)$").unwrap();
+        static ref SPOT_NAME: Regex = Regex::new(
+            r"^(?:[Mm]ove(?:\.\.\.)? to )?(?P<name>[^=]*)$").unwrap();
+    }
+    let (m, candidates) = [
+        (&*LOC_NAME, &names.locations),
+        (&*EXIT_NAME, &names.exits),
+        (&*ACTION_NAME, &names.actions),
+        (&*SPOT_NAME, &names.spots),
+    ]
+    .into_iter()
+    .find_map(|(re, candidates)| {
+        re.captures(line)
+            .and_then(|cap| cap.name("name"))
+            .map(|m| (m, candidates))
+    })?;
+    let name = m.as_str().trim_end();
+    let (best, similarity) = candidates
+        .iter()
+        .map(|c| (c, TextDiff::from_chars(name, c.as_str()).ratio()))
+        .filter(|(_, r)| *r >= MIN_NAME_SIMILARITY)
+        .max_by(|a, b| a.1.total_cmp(&b.1))?;
+    let end = m.start() + name.len();
+    let new_line = format!("{}{}{}", &line[..m.start()], best, &line[end..]);
+    let step = History::from_str(&new_line).ok()?;
+    Some((step, new_line, similarity))
+}
+
+/// Replaces a step that failed to replay with moves to where the step is now, returning the
+/// new state and the lines replacing the step.
+///
+/// Exits and warps are replaced by moving to their destination. Collections are re-pathed to
+/// the location's current spot and collected there, and hybrid collections then move on to
+/// their recorded destination.
+fn repath_step<W, T>(
+    world: &W,
+    ctx: &ContextWrapper<T>,
+    h: HistoryAlias<T>,
+    shortest_paths: &ShortestPaths<NodeId<W>, EdgeId<W>>,
+) -> Option<(ContextWrapper<T>, Vec<String>)>
+where
+    W: World,
+    T: Ctx<World = W>,
+    W::Location: Location<Context = T>,
+{
+    match h {
+        History::E(exit_id) => {
+            let dest = world.get_exit(exit_id).dest();
+            let next = move_to(world, ctx.clone(), dest, shortest_paths).ok()?;
+            Some((next, vec![format!("Move... to {}", dest)]))
+        }
+        History::W(_, dest) => {
+            let next = move_to(world, ctx.clone(), dest, shortest_paths).ok()?;
+            Some((next, vec![format!("Move... to {}", dest)]))
+        }
+        History::G(item, loc_id) | History::V(item, loc_id, ..) => {
+            let spot_id = world.get_location_spot(loc_id);
+            let mut next = move_to(world, ctx.clone(), spot_id, shortest_paths).ok()?;
+            let item = if item == Default::default() {
+                world.get_location(loc_id).item()
+            } else {
+                item
+            };
+            let collect = History::G(item, loc_id);
+            next.try_replay(world, collect).ok()?;
+            let mut lines = vec![
+                format!("Move... to {}", spot_id),
+                collect.to_string().trim_start().to_owned(),
+            ];
+            if let History::V(.., dest) = h {
+                if let Ok(moved) = move_to(world, next.clone(), dest, shortest_paths) {
+                    next = moved;
+                    lines.push(format!("Move... to {}", dest));
+                }
+            }
+            Some((next, lines))
+        }
+        _ => None,
+    }
+}
+
+/// Replays a route written against an older version of the world, repairing it where possible.
+///
+/// Lines that no longer parse have their names replaced by the most similar name of the same kind.
+/// Steps that no longer replay are re-pathed with moves to where the step is now, where possible.
+/// Anything else that fails is dropped from the route. Lines that need no change are kept
+/// exactly as written, and changes are reported with their line numbers in `route`.
+pub fn migrate_route<W, T>(
+    world: &W,
+    startctx: &T,
+    route: &str,
+    shortest_paths: &ShortestPaths<NodeId<W>, EdgeId<W>>,
+) -> MigratedRoute<T>
+where
+    W: World,
+    T: Ctx<World = W>,
+    W::Location: Location<Context = T>,
+{
+    let names = WorldNames::new(world);
+    let mut ctx = ContextWrapper::new(startctx.clone());
+    let mut lines = Vec::new();
+    let mut changes = Vec::new();
+    let body = find_route_in_solution_string(route);
+    // Skip past any solution header, which ends in a newline.
+    let first_line = route[..route.len() - body.len()].lines().count() + 1;
+
+    for (i, raw) in body.lines().enumerate() {
+        let line_num = first_line + i;
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            lines.push(raw.to_owned());
+            continue;
+        }
+        let indent = &raw[..raw.len() - raw.trim_start().len()];
+        let parsed: Result<HistoryAlias<T>, String> = History::from_str(line);
+        let (h, text) = match parsed {
+            Ok(h) => (h, raw.to_owned()),
+            Err(error) => match rename_line::<T>(line, &names) {
+                Some((h, new, similarity)) => {
+                    changes.push(RouteChange::Renamed {
+                        line: line_num,
+                        old: line.to_owned(),
+                        new: new.clone(),
+                        similarity,
+                    });
+                    (h, format!("{}{}", indent, new))
+                }
+                None => {
+                    changes.push(RouteChange::Dropped {
+                        line: line_num,
+                        old: line.to_owned(),
+                        error,
+                    });
+                    continue;
+                }
+            },
+        };
+
+        let error = match step_from_route(ctx.clone(), line_num, h, world, shortest_paths) {
+            Ok(next) => {
+                ctx = next;
+                lines.push(text);
+                continue;
+            }
+            Err(e) => e,
+        };
+        if let Some((next, new_lines)) = repath_step(world, &ctx, h, shortest_paths) {
+            changes.push(RouteChange::Repathed {
+                line: line_num,
+                old: text.trim().to_owned(),
+                new: new_lines.join("\n"),
+                error,
+            });
+            ctx = next;
+            lines.extend(new_lines.into_iter().map(|l| format!("{}{}", indent, l)));
+            continue;
+        }
+        changes.push(RouteChange::Dropped {
+            line: line_num,
+            old: text.trim().to_owned(),
+            error,
+        });
+    }
+
+    MigratedRoute {
+        route: lines.join("\n"),
+        changes,
+        ctx,
+    }
+}
+
 pub fn debug_route<W, T>(
     world: &W,
     startctx: &T,
@@ -432,3 +726,43 @@ pub fn import_route_to_mysql<'w, W, T, const KS: usize, SM>(
     states.sort();
     db.insert_batch(&states, conn).unwrap();
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_solutions_from_string() {
+        let text = "Solution #0-0, est. 100ms:\n\
+                    in short:\n\
+                    A\n\
+                    in full:\n\
+                    A\n\
+                    B\n\n\n\
+                    Solution #0-1, est. 120ms (+20ms):\n\
+                    in short:\n\
+                    # see Solution #0-0\n\
+                    in full:\n\
+                    C\n\n\n";
+        let sols = solutions_from_string(text);
+        assert_eq!(sols.len(), 2);
+        assert!(sols[0].starts_with("Solution #0-0"));
+        // A mention of a solution mid-line doesn't start a new one.
+        assert!(sols[1].starts_with("Solution #0-1"));
+        assert!(sols[1].contains("# see Solution #0-0"));
+        assert_eq!(sols.concat(), text);
+        assert_eq!(find_route_in_solution_string(sols[0]).trim(), "A\nB");
+        assert_eq!(find_route_in_solution_string(sols[1]).trim(), "C");
+    }
+
+    #[test]
+    fn test_solutions_from_string_edges() {
+        assert!(solutions_from_string("").is_empty());
+        assert!(solutions_from_string("no solutions yet\n").is_empty());
+        // Anything before the first solution is dropped.
+        assert_eq!(
+            solutions_from_string("header\nSolution #1-0:\nin full:\nA\n"),
+            vec!["Solution #1-0:\nin full:\nA\n"]
+        );
+    }
+}
@@ -1,5 +1,13 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    db_metadata (name) {
+        #[max_length = 32]
+        name -> Varchar,
+        data -> Blob,
+    }
+}
+
 diesel::table! {
     db_states (raw_state) {
         raw_state -> Blob,
@@ -15,3 +23,5 @@ diesel::table! {
         prev -> Nullable<Blob>,
     }
 }
+
+diesel::allow_tables_to_appear_in_same_query!(db_metadata, db_states,);
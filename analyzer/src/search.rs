@@ -1,12 +1,15 @@
 use crate::access::*;
 use crate::context::*;
-use crate::db::RouteDb;
+use crate::db::{read_db_encoding, DbMetadata, RouteDb, SolutionDb, VersionMismatch};
+use crate::dbprofile::DbProfile;
 use crate::direct::DirectPathsDb;
+use crate::distributed::{read_message, write_message, CoordinatorReply, Reply, WorkerRequest};
+use crate::encoding::{make_codec, StateEncoding};
 use crate::estimates::{ContextScorer, UNREASONABLE_TIME};
-use crate::heap::{DbBackedQueue, MetricType};
+use crate::heap::{DbBackedQueue, QueueDb};
 use crate::matchertrie::*;
 use crate::minimize::*;
-use crate::observer::{record_observations, TrieMatcher};
+use crate::observer::{record_observations, record_stored_observations, TrieMatcher};
 use crate::scoring::ScoreMetric;
 use crate::solutions::{Solution, SolutionCollector, SolutionResult, SolutionSuffix};
 use crate::storage::ContextDB;
@@ -16,7 +19,8 @@ use log;
 use rayon::prelude::*;
 use similar::TextDiff;
 use std::fmt::Debug;
-use std::io::Write;
+use std::io::ErrorKind;
+use std::net::{SocketAddr, TcpListener, TcpStream};
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
 use std::sync::{Arc, Condvar, Mutex};
@@ -27,11 +31,13 @@ static MAX_DEPTH_FOR_ONE_LOC: usize = 4;
 static MAX_GREEDY_DEPTH: usize = 9;
 static MAX_STATES_FOR_ONE_LOC: usize = 16_384;
 
-static QUEUE_CAPACITY: usize = 2_097_152;
-static QUEUE_MIN_PER_EVICTION: usize = 262_144;
-static QUEUE_MAX_PER_EVICTION: usize = 524_288;
-static QUEUE_MIN_PER_RESHUFFLE: usize = 4_096;
-static QUEUE_MAX_PER_RESHUFFLE: usize = 16_384;
+static QUEUE_MEMORY: usize = 1 << 30;
+
+/// How long to wait for a remote worker's results before giving up on it.
+static REMOTE_WORKER_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Where the search writes all its solutions, which a warm start reads back.
+pub static SOLUTIONS_FILE: &str = "data/solutions.txt";
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 enum SearchMode {
@@ -53,6 +59,7 @@ enum SearchMode {
     MutateCanonLocations,
     Unknown,
     Similar,
+    Remote,
 }
 
 fn mode_by_index(index: usize) -> SearchMode {
@@ -245,6 +252,18 @@ pub struct SearchOptions {
     pub local_max_states: usize,
     pub greedy_max_depth: usize,
     pub greedy_max_states: usize,
+    /// Whether to rebuild dbs written by a different build, rather than refusing to load them.
+    pub migrate: bool,
+    /// The encoding of states in new dbs. Existing dbs keep the encoding they were written with.
+    pub state_encoding: StateEncoding,
+    /// The address to accept remote workers on, if any.
+    pub listen: Option<SocketAddr>,
+    /// Whether to skip new states dominated by an already-seen state at the same position.
+    pub dominance: bool,
+    /// The memory in bytes for states held in the queue; the rest spill to the db.
+    pub queue_memory: usize,
+    /// RocksDB tuning for the queue, state and route dbs.
+    pub db_profile: DbProfile,
 }
 impl Default for SearchOptions {
     fn default() -> Self {
@@ -255,35 +274,75 @@ impl Default for SearchOptions {
             local_max_states: MAX_STATES_FOR_ONE_LOC,
             greedy_max_depth: MAX_GREEDY_DEPTH,
             greedy_max_states: MAX_STATES_FOR_ONE_LOC,
+            migrate: false,
+            state_encoding: StateEncoding::default(),
+            listen: None,
+            dominance: false,
+            queue_memory: QUEUE_MEMORY,
+            db_profile: DbProfile::default(),
+        }
+    }
+}
+
+fn store_solution<W, T>(
+    solution_db: &Option<SolutionDb<T>>,
+    startctx: &T,
+    world: &W,
+    solution: &Solution<T>,
+) where
+    W: World,
+    T: Ctx<World = W>,
+    W::Location: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = <W::Location as Accessible>::Currency>,
+{
+    if let Some(sdb) = solution_db {
+        if let Err(e) = sdb.insert(startctx, world, solution) {
+            log::warn!("Failed to store solution: {}", e);
         }
     }
 }
 
-pub struct Search<'a, W, T, TM>
+/// What's left to do for a popped state after the checks before single stepping it.
+enum PoppedState<T: Ctx> {
+    /// The state needs no stepping, having won, dead-ended or been pruned.
+    Finished,
+    /// The state was completed from the solve trie or moved to the goal's spot,
+    /// either of which stored its steps.
+    Handled,
+    /// The state needs single stepping.
+    Step(ContextWrapper<T>),
+}
+
+pub struct Search<'a, W, T, TM, const KS: usize, SM, DB>
 where
     W: World,
     T: Ctx<World = W> + Debug,
     W::Location: Location<Context = T>,
     TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    SM: ScoreMetric<'a, W, T, KS> + 'a,
+    DB: QueueDb<'a, W, T, KS, SM>,
 {
     world: &'a W,
     startctx: ContextWrapper<T>,
     solve_trie: Arc<MatcherTrie<TM, SolutionSuffix<T>>>,
     solutions: Arc<Mutex<SolutionCollector<T>>>,
+    solution_db: Option<SolutionDb<T>>,
     direct_paths: DirectPathsDb<W, T>,
-    queue: DbBackedQueue<'a, W, T>,
+    queue: DbBackedQueue<'a, W, T, KS, SM, DB>,
     solution_cvar: Condvar,
     options: SearchOptions,
 
     // stats
     iters: AtomicUsize,
     deadends: AtomicU32,
+    pruned: AtomicUsize,
     greedies: AtomicUsize,
     greedy_misses: AtomicUsize,
     greedy_spots_only: AtomicUsize,
     greedy_in_comm: AtomicUsize,
     greedy_out_comm: AtomicUsize,
     held: AtomicUsize,
+    remote_held: AtomicUsize,
     last_clean: AtomicUsize,
     solves_since_clean: AtomicUsize,
     last_solve: AtomicUsize,
@@ -296,33 +355,41 @@ where
     finished: AtomicBool,
 }
 
-impl<'a, W, T, TM> Search<'a, W, T, TM>
+impl<'a, W, T, TM, const KS: usize, SM, DB> Search<'a, W, T, TM, KS, SM, DB>
 where
     W: World,
     T: Ctx<World = W> + Debug,
     W::Location: Location<Context = T>,
     W::Exit: Exit<Context = T, Currency = <W::Location as Accessible>::Currency>,
     TM: TrieMatcher<SolutionSuffix<T>, Struct = T>,
+    SM: ScoreMetric<'a, W, T, KS> + 'a,
+    DB: QueueDb<'a, W, T, KS, SM>,
 {
     pub fn new<P>(
         world: &'a W,
         ctx: T,
         routes: Vec<ContextWrapper<T>>,
-        metric: MetricType<'a, W>,
+        metric: SM,
         db_path: P,
+        solution_db: Option<SolutionDb<T>>,
         options: SearchOptions,
-    ) -> Result<Search<'a, W, T, TM>, std::io::Error>
+    ) -> Result<Search<'a, W, T, TM, KS, SM, DB>, std::io::Error>
     where
         P: AsRef<Path>,
     {
         let solve_trie: Arc<MatcherTrie<TM, SolutionSuffix<T>>> = Arc::default();
         let mut solutions = SolutionCollector::<T>::new(
-            "data/solutions.txt",
+            SOLUTIONS_FILE,
             "data/previews.txt",
             "data/best.txt",
             "data/best-prev.txt",
             &ctx,
         )?;
+        if let Some(sdb) = &solution_db {
+            let count = record_stored_observations::<_, _, TM>(&ctx, world, sdb, &solve_trie)
+                .map_err(std::io::Error::other)?;
+            log::info!("Loaded {} stored solutions into the solve trie", count);
+        }
 
         let startctx = ContextWrapper::new(ctx);
 
@@ -358,6 +425,7 @@ where
             let max_time = wonctx.elapsed();
             let sol = wonctx.to_solution();
             if solutions.insert_solution(sol.clone(), world).accepted() {
+                store_solution(&solution_db, startctx.get(), world, &sol);
                 record_observations::<_, _, TM>(startctx.get(), world, sol, 1, &solve_trie);
             }
             for w in &wins {
@@ -365,6 +433,7 @@ where
 
                 // Insert the solution.
                 if solutions.insert_solution(sol.clone(), world).accepted() {
+                    store_solution(&solution_db, startctx.get(), world, &sol);
                     record_observations(startctx.get(), world, sol.clone(), 1, &solve_trie);
                 }
                 // Try trie-minimization; if successful, insert and record that solution.
@@ -376,6 +445,7 @@ where
                         .insert_solution(solution.clone(), world)
                         .accepted()
                     {
+                        store_solution(&solution_db, startctx.get(), world, &solution);
                         record_observations(
                             startctx.get(),
                             world,
@@ -398,41 +468,86 @@ where
 
         let solutions = Arc::new(Mutex::new(solutions));
 
-        let mut vpath = db_path.as_ref().to_owned();
-        vpath.push("VERSION");
-        let version_diff =
-            std::fs::exists(&vpath)? && std::fs::read_to_string(&vpath)? != W::VERSION;
-        let delete_dbs = if version_diff {
-            print!("Detected db version mismatch. Proceed to delete dbs and start over? (y/N) ");
-            std::io::stdout().flush().unwrap();
-            let mut str = String::default();
-            std::io::stdin().read_line(&mut str).unwrap();
-            assert!(str.starts_with(&['y', 'Y']), "Exiting without deleting dbs");
-            std::fs::write(&vpath, W::VERSION)?;
-            true
+        // Stored states can't be trusted across builds, but the routes provided (including any
+        // stored solutions that still replay) can be replayed to rebuild them.
+        let delete_dbs = match DB::check_existing(db_path.as_ref()) {
+            Ok(()) => false,
+            Err(e) if options.migrate && e.is::<VersionMismatch>() => {
+                log::warn!("{}; rebuilding dbs from provided routes", e);
+                true
+            }
+            Err(e) if e.is::<VersionMismatch>() => {
+                return Err(std::io::Error::other(format!(
+                    "{}; rerun with --migrate to rebuild the dbs from the provided routes",
+                    e
+                )));
+            }
+            Err(e) => return Err(std::io::Error::other(e)),
+        };
+
+        let sample_starts: Vec<T> = std::iter::once(startctx.get())
+            .chain(wins.iter().chain(others.iter()).map(|c| c.get()))
+            .cloned()
+            .collect();
+        // An existing state db keeps its encoding, so only train a dictionary for a new one.
+        let stored_encoding = if delete_dbs {
+            None
         } else {
-            false
+            read_db_encoding(db_path.as_ref()).map_err(std::io::Error::other)?
         };
+        let codec = make_codec(
+            world,
+            &sample_starts,
+            options.state_encoding,
+            stored_encoding,
+        )
+        .map_err(std::io::Error::other)?;
 
         let queue = DbBackedQueue::new(
             db_path.as_ref(),
             world,
             metric,
             initial_max_time,
-            QUEUE_CAPACITY,
-            QUEUE_MIN_PER_EVICTION,
-            QUEUE_MAX_PER_EVICTION,
-            QUEUE_MIN_PER_RESHUFFLE,
-            QUEUE_MAX_PER_RESHUFFLE,
+            options.queue_memory,
             delete_dbs,
+            codec,
+            options.dominance,
+            &options.db_profile,
         )
         .unwrap();
         queue.db().reset_all_cached_estimates();
         queue.push(startctx.clone(), None).unwrap();
         log::info!("Max time to consider is now: {}ms", queue.max_time());
+        let scorer = queue.db().scorer();
+        let start_spot = startctx.get().position();
+        log::info!(
+            "Lower bound from the start: {}ms; last required pickups: {}",
+            scorer.lower_bound_remaining_time(startctx.get()),
+            scorer
+                .backward()
+                .last_pickups(start_spot)
+                .iter()
+                .map(|&item| format!(
+                    "{} ({}ms)",
+                    item,
+                    scorer
+                        .backward()
+                        .time_to_collect(item, start_spot)
+                        .unwrap_or(u32::MAX)
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
 
-        let (ropts, rcache) = RouteDb::<T>::default_options();
-        let route_db = RouteDb::<T>::open(db_path, ropts, rcache, delete_dbs).unwrap();
+        let (ropts, rcache) = RouteDb::<T>::options(&options.db_profile);
+        // The route db discards its own routes if they were written by a different build,
+        // unless migrating, in which case it keeps those that still replay.
+        let route_db = if options.migrate {
+            RouteDb::<T>::open_migrating(db_path, ropts, rcache, startctx.get(), world)
+        } else {
+            RouteDb::<T>::open(db_path, ropts, rcache, false)
+        }
+        .map_err(std::io::Error::other)?;
         let direct_paths = DirectPathsDb::new(free_sp, route_db);
 
         let s = Search {
@@ -440,13 +555,16 @@ where
             startctx,
             solve_trie,
             solutions,
+            solution_db,
             direct_paths,
             queue,
             solution_cvar: Condvar::new(),
             options,
             iters: 0.into(),
             deadends: 0.into(),
+            pruned: 0.into(),
             held: 0.into(),
+            remote_held: 0.into(),
             greedies: 0.into(),
             greedy_misses: 0.into(),
             greedy_spots_only: 0.into(),
@@ -682,6 +800,12 @@ where
             } else {
                 log::debug!("Recording solution from {:?} mode: {}ms", mode, elapsed);
             }
+            store_solution(
+                &self.solution_db,
+                self.startctx.get(),
+                self.world,
+                &solution,
+            );
             record_observations(
                 self.startctx.get(),
                 self.world,
@@ -858,6 +982,10 @@ where
         let num_threads = rayon::current_num_threads();
         let num_workers = (num_cpus::get() * 2 + 1) / 3;
         let res = Mutex::new(Ok(()));
+        let listener = match self.options.listen {
+            Some(addr) => Some(TcpListener::bind(addr)?),
+            None => None,
+        };
 
         struct AtExit<'a> {
             flag: &'a AtomicBool,
@@ -896,8 +1024,10 @@ where
                 }
             };
 
+            // States held by remote workers may still add to the queue.
             while !self.finished.load(Ordering::Acquire)
-                && workers_done.load(Ordering::Acquire) < num_workers
+                && (workers_done.load(Ordering::Acquire) < num_workers
+                    || self.remote_held.load(Ordering::Acquire) > 0)
             {
                 let iters = self.iters.load(Ordering::Acquire);
                 let current_mode = if iters < 200_000 {
@@ -954,6 +1084,10 @@ where
                                 self.check_status_update(&start, iters, &ctx);
                                 let progress = ctx.get().count_visits();
 
+                                if self.finish_at_goal_spot(&ctx, current_mode).is_some() {
+                                    return;
+                                }
+
                                 // get remaining locations
                                 let remaining: Vec<_> = self
                                     .world
@@ -1287,6 +1421,19 @@ where
                 }
             });
 
+            if listener.is_some() {
+                scope.spawn(|_| {
+                    if let Err(e) = self.serve_remote_workers(listener.unwrap(), &start) {
+                        log::error!("Remote worker listener exiting due to error: {:?}", e);
+                        let mut r = res.lock().unwrap();
+                        if r.is_ok() {
+                            *r = Err(e);
+                            self.finished.store(true, Ordering::Release);
+                        }
+                    }
+                });
+            }
+
             if self.queue.is_empty() && self.queue.db().recovery() {
                 log::debug!("Waiting a bit for queue recovery...");
                 std::thread::sleep(Duration::from_secs(10));
@@ -1319,11 +1466,12 @@ where
         });
         let (iskips, pskips, dpskips) = self.queue.skip_stats();
         log::info!(
-            "Finished after {} rounds ({} dead-ends)\nHeap: skipped {} pushes + {}+{} pops\n\
+            "Finished after {} rounds ({} dead-ends, {} pruned)\nHeap: skipped {} pushes + {}+{} pops\n\
             {}: {}\n\
             Result: {}",
             self.iters.load(Ordering::Acquire),
             self.deadends.load(Ordering::Acquire),
+            self.pruned.load(Ordering::Acquire),
             iskips,
             pskips,
             dpskips,
@@ -1340,6 +1488,144 @@ where
         self.solutions.lock().unwrap().export()
     }
 
+    /// Accepts remote workers until the search is finished.
+    fn serve_remote_workers(&self, listener: TcpListener, start: &Mutex<Instant>) -> Result<()> {
+        log::info!("Accepting remote workers on {}", listener.local_addr()?);
+        // Nonblocking so we notice when the search finishes.
+        listener.set_nonblocking(true)?;
+        std::thread::scope(|s| {
+            while !self.finished.load(Ordering::Acquire) {
+                match listener.accept() {
+                    Ok((stream, addr)) => {
+                        log::info!("Remote worker connected from {}", addr);
+                        s.spawn(move || {
+                            if let Err(e) = self.serve_remote_worker(stream, start) {
+                                log::warn!("Remote worker {} dropped: {}", addr, e);
+                            }
+                        });
+                    }
+                    Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                        sleep(Duration::from_millis(200));
+                    }
+                    Err(e) => log::warn!("Failed to accept remote worker: {}", e),
+                }
+            }
+        });
+        Ok(())
+    }
+
+    fn serve_remote_worker(&self, mut stream: TcpStream, start: &Mutex<Instant>) -> Result<()> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(REMOTE_WORKER_TIMEOUT))?;
+        stream.set_nodelay(true)?;
+        // States sent to the worker and not yet returned, to requeue if the worker is lost.
+        let mut outstanding = Vec::new();
+        let res = self.handle_remote_requests(&mut stream, start, &mut outstanding);
+        if !outstanding.is_empty() {
+            self.remote_held
+                .fetch_sub(outstanding.len(), Ordering::Release);
+            self.queue.requeue(outstanding)?;
+        }
+        res
+    }
+
+    fn handle_remote_requests(
+        &self,
+        stream: &mut TcpStream,
+        start: &Mutex<Instant>,
+        outstanding: &mut Vec<T>,
+    ) -> Result<()> {
+        let mut greeted = false;
+        while let Some(req) = read_message::<WorkerRequest<T>>(stream)? {
+            let pull = match req {
+                WorkerRequest::Work { .. } if !greeted => {
+                    write_message(
+                        stream,
+                        &CoordinatorReply::<T>::Rejected(String::from(
+                            "worker sent results before Hello",
+                        )),
+                    )?;
+                    return Err(anyhow::anyhow!("Worker sent results before Hello"));
+                }
+                WorkerRequest::Hello { metadata, pull } => {
+                    let current = DbMetadata::current::<T>();
+                    if metadata != current {
+                        write_message(
+                            stream,
+                            &CoordinatorReply::<T>::Rejected(format!(
+                                "worker build {:?} does not match coordinator build {:?}",
+                                metadata, current
+                            )),
+                        )?;
+                        return Err(anyhow::anyhow!(
+                            "Worker build {:?} does not match coordinator build {:?}",
+                            metadata,
+                            current
+                        ));
+                    }
+                    greeted = true;
+                    pull
+                }
+                WorkerRequest::Work { results, pull } => {
+                    // The results are for the whole of the last batch.
+                    self.remote_held
+                        .fetch_sub(outstanding.len(), Ordering::Release);
+                    outstanding.clear();
+                    for (prev, children) in results {
+                        let iters = self.iters.fetch_add(1, Ordering::AcqRel) + 1;
+                        self.check_status_update(start, iters, &prev);
+                        self.extend_and_handle_solutions(children, prev.get(), SearchMode::Remote)?;
+                    }
+                    pull
+                }
+            };
+            if self.finished.load(Ordering::Acquire) {
+                write_message(stream, &CoordinatorReply::<T>::Finished)?;
+                return Ok(());
+            }
+
+            let mut states = Vec::new();
+            while states.len() < pull {
+                let Some(ctx) = self.queue.pop()? else {
+                    break;
+                };
+                if self.queue.db().was_processed(ctx.get())? {
+                    continue;
+                }
+                // Workers only single step, so everything else process_one does happens here.
+                let prev = ctx.get().clone();
+                match self.check_before_step(ctx, SearchMode::Remote) {
+                    PoppedState::Finished => {
+                        self.extend_and_handle_solutions(Vec::new(), &prev, SearchMode::Remote)?
+                    }
+                    PoppedState::Handled => (),
+                    PoppedState::Step(ctx) => {
+                        outstanding.push(prev);
+                        states.push(ctx);
+                    }
+                }
+            }
+            self.remote_held.fetch_add(states.len(), Ordering::Release);
+            let best = {
+                let sols = self.solutions.lock().unwrap();
+                if sols.is_empty() {
+                    None
+                } else {
+                    Some(sols.best())
+                }
+            };
+            write_message(
+                stream,
+                &Reply::Batch {
+                    states,
+                    max_time: self.queue.max_time(),
+                    best,
+                },
+            )?;
+        }
+        Ok(())
+    }
+
     fn process_one_greedy(
         &self,
         ctx: &ContextWrapper<T>,
@@ -1402,29 +1688,84 @@ where
 
     fn process_one(
         &self,
-        mut ctx: ContextWrapper<T>,
+        ctx: ContextWrapper<T>,
         iters: usize,
         start: &Mutex<Instant>,
     ) -> Option<Vec<ContextWrapper<T>>> {
         self.check_status_update(start, iters, &ctx);
 
+        match self.check_before_step(ctx, SearchMode::Unknown) {
+            PoppedState::Finished => Some(Vec::new()),
+            PoppedState::Handled => None,
+            PoppedState::Step(ctx) => Some(self.single_step(ctx)),
+        }
+    }
+
+    /// Runs the checks a popped state gets before it's single stepped, here or by a remote worker.
+    fn check_before_step(&self, mut ctx: ContextWrapper<T>, mode: SearchMode) -> PoppedState<T> {
+        if let Some(popped) = self.finish_at_goal_spot(&ctx, mode) {
+            return popped;
+        }
+
         if ctx.get().count_visits() >= W::NUM_CANON_LOCATIONS {
             if self.world.won(ctx.get()) {
                 ctx.set_won();
-                self.handle_solution(&mut ctx, None, SearchMode::Unknown);
+                self.handle_solution(&mut ctx, None, mode);
             } else {
                 self.deadends.fetch_add(1, Ordering::Release);
             }
-            return Some(Vec::new());
+            return PoppedState::Finished;
+        }
+
+        // The db's estimate may predate the current max time, so check the cheap bound again.
+        let bound = self
+            .queue
+            .db()
+            .scorer()
+            .lower_bound_remaining_time(ctx.get());
+        if bound == u32::MAX {
+            self.deadends.fetch_add(1, Ordering::Release);
+            return PoppedState::Finished;
+        }
+        if ctx.elapsed().saturating_add(bound) > self.queue.max_time() {
+            self.pruned.fetch_add(1, Ordering::Release);
+            return PoppedState::Finished;
         }
 
         if let Some(win) = trie_search(self.world, &ctx, UNREASONABLE_TIME, &self.solve_trie) {
             // Handles recording the solution, updating all steps, and single stepping as well.
             self.recreate_store(&ctx, win.recent_history(), SearchMode::Similar)
                 .unwrap();
-            None
+            PoppedState::Handled
         } else {
-            Some(self.single_step(ctx))
+            PoppedState::Step(ctx)
+        }
+    }
+
+    /// Moves the state to the goal's spot if it meets the rest of the goal,
+    /// since no location leads there. Returns None if the state doesn't need it.
+    fn finish_at_goal_spot(
+        &self,
+        ctx: &ContextWrapper<T>,
+        mode: SearchMode,
+    ) -> Option<PoppedState<T>> {
+        let spot = goal_spot_to_reach(self.world, ctx.get())?;
+        match move_to(
+            self.world,
+            ctx.clone(),
+            spot,
+            self.queue.db().scorer().get_algo(),
+        ) {
+            Ok(mut c) => {
+                let hist = c.remove_history().0;
+                // Handles recording the solution, as the last step reaches the spot.
+                self.recreate_store(ctx, &hist, mode).unwrap();
+                Some(PoppedState::Handled)
+            }
+            Err(_) => {
+                self.deadends.fetch_add(1, Ordering::Release);
+                Some(PoppedState::Finished)
+            }
         }
     }
 
@@ -1586,7 +1927,7 @@ where
             heap_bests
                 .into_iter()
                 .map(|n| match n {
-                    Some(score) => MetricType::<W>::score_primary(score).to_string(),
+                    Some(score) => SM::score_primary(score).to_string(),
                     None => String::from("-"),
                 })
                 .collect::<Vec<_>>()
@@ -32,3 +32,88 @@ pub fn parse_bool(key: &Yaml, val: &Yaml) -> Result<bool, String> {
         _ => Err(format!("Value for '{:?}' is not boolean: {:?}", key, val)),
     }
 }
+
+/// Parses a size in bytes with an optional binary unit, e.g. 512M, 8G or 8GiB.
+pub fn parse_size_str(s: &str) -> Result<usize, String> {
+    let s = s.trim();
+    let (num, unit) = s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()));
+    let num: usize = num
+        .parse()
+        .map_err(|e| format!("Invalid size {:?}: {}", s, e))?;
+    let shift = match unit
+        .trim()
+        .to_ascii_uppercase()
+        .trim_end_matches("IB")
+        .trim_end_matches('B')
+    {
+        "" => 0,
+        "K" => 10,
+        "M" => 20,
+        "G" => 30,
+        "T" => 40,
+        u => return Err(format!("Unrecognized size unit {:?} in {:?}", u, s)),
+    };
+    num.checked_mul(1 << shift)
+        .ok_or_else(|| format!("Size too large: {}", s))
+}
+
+pub fn parse_size(key: &Yaml, val: &Yaml) -> Result<usize, String> {
+    match val {
+        Yaml::Integer(i) => usize::try_from(*i).map_err(|e| format!("{:?}", e)),
+        Yaml::String(s) => parse_size_str(s),
+        _ => Err(format!("Value for '{:?}' is not a size: {:?}", key, val)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_size_str_units() {
+        assert_eq!(parse_size_str("4096"), Ok(4096));
+        assert_eq!(parse_size_str("1K"), Ok(1 << 10));
+        assert_eq!(parse_size_str("512M"), Ok(512 << 20));
+        assert_eq!(parse_size_str("8G"), Ok(8 << 30));
+        assert_eq!(parse_size_str("2T"), Ok(2 << 40));
+        // Case, B/iB suffixes and whitespace don't matter.
+        assert_eq!(parse_size_str("8gib"), Ok(8 << 30));
+        assert_eq!(parse_size_str("8GB"), Ok(8 << 30));
+        assert_eq!(parse_size_str(" 64 MiB "), Ok(64 << 20));
+        assert_eq!(parse_size_str("100B"), Ok(100));
+    }
+
+    #[test]
+    fn test_parse_size_str_errors() {
+        assert!(parse_size_str("").is_err());
+        assert!(parse_size_str("G").is_err());
+        assert!(parse_size_str("-1G").is_err());
+        assert!(parse_size_str("1.5G").is_err());
+        assert!(parse_size_str("8X").is_err());
+        assert!(parse_size_str("8 GB extra").is_err());
+    }
+
+    #[test]
+    fn test_parse_size_str_overflow() {
+        assert_eq!(parse_size_str(&usize::MAX.to_string()), Ok(usize::MAX));
+        assert!(parse_size_str(&format!("{}0", usize::MAX)).is_err());
+        assert!(parse_size_str(&format!("{}K", usize::MAX)).is_err());
+        assert_eq!(
+            parse_size_str(&format!("{}T", usize::MAX >> 40)),
+            Ok((usize::MAX >> 40) << 40)
+        );
+        assert!(parse_size_str(&format!("{}T", (usize::MAX >> 40) + 1)).is_err());
+    }
+
+    #[test]
+    fn test_parse_size() {
+        let key = Yaml::String(String::from("size"));
+        assert_eq!(parse_size(&key, &Yaml::Integer(4096)), Ok(4096));
+        assert_eq!(
+            parse_size(&key, &Yaml::String(String::from("2M"))),
+            Ok(2 << 20)
+        );
+        assert!(parse_size(&key, &Yaml::Integer(-1)).is_err());
+        assert!(parse_size(&key, &Yaml::Boolean(true)).is_err());
+    }
+}
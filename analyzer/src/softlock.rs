@@ -0,0 +1,150 @@
+//! Detection of reachable states from which the game can no longer be won.
+
+use crate::access::{accessible_spots, can_win, can_win_just_locations};
+use crate::context::*;
+use crate::new_hashset;
+use crate::search::{activate_actions, visit_locations};
+use crate::world::*;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+
+/// A step that leads from a winnable state to one that can no longer win.
+pub struct Softlock<T: Ctx> {
+    pub step: HistoryAlias<T>,
+    /// The winnable state the step was taken from (possibly after some movement).
+    pub before: ContextWrapper<T>,
+    /// The first unwinnable state found after taking the step.
+    pub after: ContextWrapper<T>,
+    /// Whether the game can't be won even ignoring movement.
+    /// Otherwise, only the greedy search failed to find a win, which may be a false positive.
+    pub confirmed: bool,
+    /// The number of distinct states in which taking this step lost winnability.
+    pub occurrences: usize,
+}
+
+pub struct SoftlockReport<T: Ctx> {
+    pub explored: usize,
+    pub softlocks: Vec<Softlock<T>>,
+}
+
+impl<T: Ctx> SoftlockReport<T> {
+    pub fn summary(&self) -> String {
+        let mut lines = vec![format!(
+            "Explored {} winnable states; found {} step(s) leading to softlocks ({} confirmed)",
+            self.explored,
+            self.softlocks.len(),
+            self.softlocks.iter().filter(|s| s.confirmed).count(),
+        )];
+        for s in &self.softlocks {
+            lines.push(format!(
+                "\n[{}] {} ({} state(s)), first at {}ms after:\n  {}\nChanges: {}",
+                if s.confirmed {
+                    "confirmed"
+                } else {
+                    "suspected"
+                },
+                s.step,
+                s.occurrences,
+                s.after.elapsed(),
+                history_summary::<T, _>(s.after.recent_history().iter().copied()),
+                s.after.get().diff(s.before.get()),
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Explores up to `max_states` winnable states breadth-first from the given state, and reports each step
+/// that leads from a winnable state to an unwinnable one.
+///
+/// Winnability is tested with a greedy search, so a softlock is only confirmed if the game can't be won
+/// even when ignoring movement (e.g. after spending currency needed elsewhere).
+pub fn find_softlocks<W, T, L>(
+    world: &W,
+    ctx: ContextWrapper<T>,
+    max_time: u32,
+    max_states: usize,
+) -> SoftlockReport<T>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W> + Debug,
+    L: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = L::Currency>,
+{
+    let mut softlocks: Vec<Softlock<T>> = Vec::new();
+    let mut seen = new_hashset();
+    let mut queue = VecDeque::new();
+    let mut explored = 0;
+    seen.insert(ctx.get().clone());
+    queue.push_back(ctx);
+
+    while let Some(ctx) = queue.pop_front() {
+        if explored >= max_states {
+            break;
+        }
+        explored += 1;
+
+        // Movement alone (e.g. one-way exits) can lose winnability, but winnable spots
+        // don't need to be explored further since their successors are generated here.
+        let mut next = Vec::new();
+        for spot_ctx in accessible_spots(world, ctx.clone(), max_time, false).into_values() {
+            if seen.insert(spot_ctx.get().clone())
+                && can_win(world, spot_ctx.get(), max_time).is_err()
+            {
+                record_softlock(world, &mut softlocks, &ctx, spot_ctx);
+                continue;
+            }
+            next.extend(activate_actions(world, &spot_ctx));
+            next.extend(visit_locations(world, spot_ctx));
+        }
+
+        for after in next {
+            if world.won(after.get()) || !seen.insert(after.get().clone()) {
+                continue;
+            }
+            if can_win(world, after.get(), max_time).is_ok() {
+                queue.push_back(after);
+            } else {
+                record_softlock(world, &mut softlocks, &ctx, after);
+            }
+        }
+    }
+
+    softlocks.sort_by_key(|s| (!s.confirmed, std::cmp::Reverse(s.occurrences)));
+    SoftlockReport {
+        explored,
+        softlocks,
+    }
+}
+
+fn record_softlock<W, T>(
+    world: &W,
+    softlocks: &mut Vec<Softlock<T>>,
+    before: &ContextWrapper<T>,
+    after: ContextWrapper<T>,
+) where
+    W: World,
+    T: Ctx<World = W>,
+    W::Location: Location<Context = T>,
+    W::Exit: Exit<Context = T, Currency = <W::Location as Accessible>::Currency>,
+{
+    // Without a step, the state was never winnable from here, so there's nothing to blame.
+    let Some(&step) = after.recent_history().last() else {
+        log::warn!(
+            "State is unwinnable without taking any steps: {}",
+            after.get().diff(before.get())
+        );
+        return;
+    };
+    if let Some(s) = softlocks.iter_mut().find(|s| s.step == step) {
+        s.occurrences += 1;
+    } else {
+        softlocks.push(Softlock {
+            step,
+            before: before.clone(),
+            confirmed: can_win_just_locations(world, after.get()).is_err(),
+            after,
+            occurrences: 1,
+        });
+    }
+}
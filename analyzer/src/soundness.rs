@@ -0,0 +1,167 @@
+//! Checks that observations record everything the access rules read.
+//!
+//! The solve trie matches any state that agrees with a step's observations, so if a rule reads
+//! a property without observing it, the trie can match states where the step isn't possible.
+
+use crate::context::*;
+use crate::matchertrie::Observable;
+use crate::observer::Observer;
+use crate::solutions::Solution;
+use crate::util::SplitMix64;
+use crate::world::*;
+use std::sync::Arc;
+
+#[derive(Clone, Debug)]
+pub struct SoundnessOptions {
+    pub seed: u64,
+    /// The number of mutated states to try for each step.
+    pub mutants_per_step: usize,
+    /// The max number of random changes made to a state to create a mutant.
+    pub max_mutations: usize,
+}
+
+impl Default for SoundnessOptions {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            mutants_per_step: 64,
+            max_mutations: 4,
+        }
+    }
+}
+
+/// A step whose result can change without any of its observations changing.
+pub struct UnsoundStep<T: Ctx> {
+    pub solution: usize,
+    pub index: usize,
+    pub step: HistoryAlias<T>,
+    pub state: T,
+    /// A state matching all the observations of the step, but with a different result.
+    pub mutant: T,
+    /// Whether the step can be replayed from the original state.
+    pub expected: bool,
+    pub observations: Vec<T::PropertyObservation>,
+}
+
+impl<T: Ctx> UnsoundStep<T> {
+    pub fn summary(&self) -> String {
+        format!(
+            "Unsound observations at step {} of solution {}: {}\n\
+            can_replay is {} in the original state but {} in a state that matches its observations.\n\
+            Observations: {:?}\nMutated: {}",
+            self.index,
+            self.solution,
+            self.step,
+            self.expected,
+            !self.expected,
+            self.observations,
+            self.mutant.diff(&self.state),
+        )
+    }
+}
+
+fn mutate<W, T, L>(world: &W, state: &T, rng: &mut SplitMix64, max_mutations: usize) -> T
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+{
+    let mut mutant = state.clone();
+    let locs = world.get_all_locations();
+    let spots = world.get_all_spots();
+    for _ in 0..=rng.below(max_mutations.max(1)) {
+        match rng.below(5) {
+            0 if !locs.is_empty() => mutant.add_item(locs[rng.below(locs.len())].item()),
+            1 if !locs.is_empty() => mutant.visit(locs[rng.below(locs.len())].id()),
+            2 if !locs.is_empty() => mutant.reset(locs[rng.below(locs.len())].id()),
+            3 if !spots.is_empty() => mutant.set_position_raw(spots[rng.below(spots.len())]),
+            _ => {
+                let field = rng.next_u64() as usize;
+                mutant.mutate_context(field, rng.next_u64());
+            }
+        }
+    }
+    mutant
+}
+
+/// Checks each step of the given solutions against random mutations of the state it was taken from.
+///
+/// Mutants are made by adding items, changing visited locations, changing position, changing
+/// other context vars, or by taking a state from elsewhere in the solutions. Mutants that don't match all the step's observations
+/// are skipped, so any mutant remaining only differs in unobserved properties, and the step must
+/// be replayable from it exactly when it's replayable from the original state.
+///
+/// Returns the number of steps checked, or the first unsound step.
+pub fn check_observation_soundness<W, T, L>(
+    world: &W,
+    startctx: &T,
+    solutions: &[Arc<Solution<T>>],
+    options: &SoundnessOptions,
+) -> Result<usize, UnsoundStep<T>>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+{
+    check_soundness_with(world, startctx, solutions, options, |state, step| {
+        let mut obs = T::Observer::default();
+        state.observe_replay(world, step, &mut obs);
+        obs.apply_observations();
+        obs.to_vec(state)
+    })
+}
+
+/// Checks the given solutions like `check_observation_soundness`, but with the observations of
+/// each step taken from `observe`, e.g. to check that a missing observation is caught.
+pub fn check_soundness_with<W, T, L, F>(
+    world: &W,
+    startctx: &T,
+    solutions: &[Arc<Solution<T>>],
+    options: &SoundnessOptions,
+    mut observe: F,
+) -> Result<usize, UnsoundStep<T>>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+    F: FnMut(&T, HistoryAlias<T>) -> Vec<T::PropertyObservation>,
+{
+    let mut rng = SplitMix64::new(options.seed);
+    let series: Vec<Vec<T>> = solutions
+        .iter()
+        .map(|sol| history_to_full_series(startctx, world, sol.history.iter().copied()))
+        .collect();
+    let donors: Vec<&T> = series.iter().flatten().collect();
+    let mut checked = 0;
+
+    for (solution, (sol, states)) in solutions.iter().zip(series.iter()).enumerate() {
+        for (index, (step, state)) in sol.history.iter().zip(states.iter()).enumerate() {
+            let observations = observe(state, *step);
+            let expected = ContextWrapper::new(state.clone()).can_replay(world, *step);
+            checked += 1;
+
+            for _ in 0..options.mutants_per_step {
+                let mutant = if rng.below(4) == 0 {
+                    donors[rng.below(donors.len())].clone()
+                } else {
+                    mutate(world, state, &mut rng, options.max_mutations)
+                };
+                if mutant == *state || !mutant.matches_all(&observations) {
+                    continue;
+                }
+                if ContextWrapper::new(mutant.clone()).can_replay(world, *step) != expected {
+                    return Err(UnsoundStep {
+                        solution,
+                        index,
+                        step: *step,
+                        state: state.clone(),
+                        mutant,
+                        expected,
+                        observations,
+                    });
+                }
+            }
+        }
+    }
+    Ok(checked)
+}
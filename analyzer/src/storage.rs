@@ -113,6 +113,18 @@ where
     }
     // endregion
 
+    // region: Encoding
+
+    /// Encodes a state as it's stored in the db.
+    fn encode_state(&self, el: &T) -> Result<Vec<u8>> {
+        Ok(serialize_state(el))
+    }
+    /// Decodes a state encoded by `encode_state`.
+    fn decode_state(&self, buf: &[u8]) -> Result<T> {
+        deserialize_state(buf)
+    }
+    // endregion
+
     // region: Stats
 
     /// Returns the number of preserved elements in the queue.
@@ -172,7 +184,7 @@ where
     /// Returns the best times recorded to reach the given state.
     /// May only be called with a state known to exist in the db.
     fn get_best_times(&self, el: &T) -> Result<BestTimes> {
-        self.get_best_times_raw(&serialize_state(el))
+        self.get_best_times_raw(&self.encode_state(el)?)
     }
 
     /// Lookup the state's estimated remaining time in the db, or consult
@@ -192,7 +204,7 @@ where
     fn was_processed_raw(&self, key: &[u8]) -> Result<bool>;
     /// Returns whether the given state has been processed.
     fn was_processed(&self, el: &T) -> Result<bool> {
-        self.was_processed_raw(&serialize_state(el))
+        self.was_processed_raw(&self.encode_state(el)?)
     }
 
     /// Returns both whether the state was processed and what its best recorded times were.
@@ -203,14 +215,14 @@ where
     /// Returns both whether the state was processed and what its best recorded times were.
     /// May only be called with a state known to exist in the db.
     fn get_best_times_processed(&self, el: &T) -> Result<(BestTimes, bool)> {
-        self.get_best_times_processed_raw(&serialize_state(el))
+        self.get_best_times_processed_raw(&self.encode_state(el)?)
     }
 
     /// Returns the best route in the db to reach the given encoded state, and the route's total elapsed time.
     fn get_history_raw(&self, state_key: &Vec<u8>) -> Result<(Vec<HistoryAlias<T>>, u32)>;
     /// Returns the best route in the db to reach the given state, and the route's total elapsed time.
     fn get_history(&self, el: &T) -> Result<(Vec<HistoryAlias<T>>, u32)> {
-        self.get_history_raw(&serialize_state(el))
+        self.get_history_raw(&self.encode_state(el)?)
     }
     /// Returns the last step in the history of a state.
     fn get_last_history_step(&self, el: &T) -> Result<Option<HistoryAlias<T>>>;
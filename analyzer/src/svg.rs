@@ -0,0 +1,270 @@
+//! SVG maps of routes, drawn over the spot coordinates of each area visited.
+
+use crate::context::*;
+use crate::dot::spot_parts;
+use crate::world::*;
+use std::fmt::Write;
+
+const PANEL_WIDTH: f32 = 480.0;
+const PANEL_HEIGHT: f32 = 360.0;
+const PANEL_MARGIN: f32 = 32.0;
+const PANEL_COLUMNS: usize = 2;
+const HEADER_HEIGHT: f32 = 28.0;
+
+enum Mark {
+    /// Movement within an area.
+    Segment {
+        points: Vec<(f32, f32)>,
+        elapsed: u32,
+    },
+    /// A location visit, numbered in route order.
+    Collect {
+        at: (f32, f32),
+        num: usize,
+        label: String,
+    },
+    /// Arrival in an area by warp or by an exit from another area.
+    Arrive {
+        at: (f32, f32),
+        label: String,
+        warp: bool,
+    },
+}
+
+struct Panel<S> {
+    name: String,
+    /// A spot in the area, for looking up the other spots.
+    spot: S,
+    marks: Vec<Mark>,
+}
+
+fn area_name<S: Id>(spot: S) -> String {
+    let (region, area, _) = spot_parts(spot);
+    format!("{} > {}", region, area)
+}
+
+fn svg_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Colors a point in the route from blue at the start to red at the end.
+fn time_color(elapsed: u32, total: u32) -> String {
+    let t = if total == 0 {
+        0.0
+    } else {
+        elapsed as f32 / total as f32
+    };
+    format!("hsl({:.0},80%,45%)", 240.0 * (1.0 - t))
+}
+
+fn panel_for<'a, S: Id>(panels: &'a mut Vec<Panel<S>>, spot: S) -> &'a mut Panel<S> {
+    let name = area_name(spot);
+    let idx = match panels.iter().position(|p| p.name == name) {
+        Some(idx) => idx,
+        None => {
+            panels.push(Panel {
+                name,
+                spot,
+                marks: Vec::new(),
+            });
+            panels.len() - 1
+        }
+    };
+    &mut panels[idx]
+}
+
+/// Renders the route as an SVG with one panel per area visited.
+///
+/// Movement is drawn as segments colored by elapsed time, visits as numbered markers, and arrivals
+/// into an area as arrows (dashed for warps). Spots without coordinates are skipped.
+pub fn route_to_svg<W, T>(world: &W, startctx: &T, history: &[HistoryAlias<T>]) -> String
+where
+    W: World,
+    T: Ctx<World = W>,
+    W::Location: Location<Context = T>,
+{
+    let mut panels: Vec<Panel<<W::Exit as Exit>::SpotId>> = Vec::new();
+    let mut ctx = ContextWrapper::new(startctx.clone());
+    let mut visits = 0;
+    for h in history {
+        let prev = ctx.get().position();
+        let midpoints = if let History::C(_, idx) = h {
+            world.get_condensed_edges_from(prev)[*idx]
+                .midpoints()
+                .to_vec()
+        } else {
+            Vec::new()
+        };
+        ctx.replay(world, *h);
+        let pos = ctx.get().position();
+        let Some(at) = W::spot_coordinate(pos) else {
+            continue;
+        };
+        match h {
+            History::G(item, _) | History::V(item, ..) => {
+                visits += 1;
+                panel_for(&mut panels, pos).marks.push(Mark::Collect {
+                    at,
+                    num: visits,
+                    label: format!("{}. {} at {}ms", visits, item, ctx.elapsed()),
+                });
+            }
+            History::W(wp, ..) => {
+                panel_for(&mut panels, pos).marks.push(Mark::Arrive {
+                    at,
+                    label: wp.to_string(),
+                    warp: true,
+                });
+            }
+            _ if pos != prev && W::same_area(prev, pos) => {
+                let points: Vec<_> = std::iter::once(prev)
+                    .chain(midpoints)
+                    .chain(std::iter::once(pos))
+                    .filter_map(W::spot_coordinate)
+                    .collect();
+                panel_for(&mut panels, pos).marks.push(Mark::Segment {
+                    points,
+                    elapsed: ctx.elapsed(),
+                });
+            }
+            _ if pos != prev => {
+                panel_for(&mut panels, pos).marks.push(Mark::Arrive {
+                    at,
+                    label: format!("from {}", area_name(prev)),
+                    warp: false,
+                });
+            }
+            _ => (),
+        }
+    }
+
+    let total = ctx.elapsed();
+    let rows = (panels.len() + PANEL_COLUMNS - 1) / PANEL_COLUMNS;
+    let width = PANEL_WIDTH * PANEL_COLUMNS.min(panels.len().max(1)) as f32;
+    let height = HEADER_HEIGHT + PANEL_HEIGHT * rows as f32;
+    let mut out = String::new();
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif" font-size="11">"#,
+        w = width,
+        h = height
+    )
+    .unwrap();
+    out.push_str(concat!(
+        r#"<defs><marker id="arrow" viewBox="0 0 10 10" refX="10" refY="5" markerWidth="6" markerHeight="6" orient="auto-start-reverse">"#,
+        r#"<path d="M 0 0 L 10 5 L 0 10 z" fill="context-stroke"/></marker></defs>"#,
+        "\n"
+    ));
+    writeln!(
+        out,
+        r#"<text x="8" y="18" font-size="14">Route of {}ms: {} visits in {} areas</text>"#,
+        total,
+        visits,
+        panels.len()
+    )
+    .unwrap();
+
+    for (i, panel) in panels.iter().enumerate() {
+        let x0 = PANEL_WIDTH * (i % PANEL_COLUMNS) as f32;
+        let y0 = HEADER_HEIGHT + PANEL_HEIGHT * (i / PANEL_COLUMNS) as f32;
+        let coords: Vec<_> = world
+            .get_area_spots(panel.spot)
+            .iter()
+            .filter_map(|&s| W::spot_coordinate(s))
+            .collect();
+        let (min_x, max_x, min_y, max_y) = coords.iter().fold(
+            (f32::MAX, f32::MIN, f32::MAX, f32::MIN),
+            |(a, b, c, d), &(x, y)| (a.min(x), b.max(x), c.min(y), d.max(y)),
+        );
+        let scale = f32::min(
+            (PANEL_WIDTH - 2.0 * PANEL_MARGIN) / (max_x - min_x).max(1.0),
+            (PANEL_HEIGHT - 2.0 * PANEL_MARGIN) / (max_y - min_y).max(1.0),
+        );
+        let tr = |(x, y): (f32, f32)| {
+            (
+                x0 + PANEL_MARGIN + (x - min_x) * scale,
+                y0 + PANEL_MARGIN + (y - min_y) * scale,
+            )
+        };
+
+        writeln!(
+            out,
+            r##"<g><rect x="{}" y="{}" width="{}" height="{}" fill="none" stroke="#ccc"/><text x="{}" y="{}">{}</text>"##,
+            x0 + 2.0,
+            y0 + 2.0,
+            PANEL_WIDTH - 4.0,
+            PANEL_HEIGHT - 4.0,
+            x0 + 8.0,
+            y0 + 16.0,
+            svg_escape(&panel.name)
+        )
+        .unwrap();
+        for &c in &coords {
+            let (x, y) = tr(c);
+            writeln!(
+                out,
+                r##"<circle cx="{:.1}" cy="{:.1}" r="2" fill="#bbb"/>"##,
+                x, y
+            )
+            .unwrap();
+        }
+        for mark in &panel.marks {
+            match mark {
+                Mark::Segment { points, elapsed } => {
+                    let pts: Vec<_> = points
+                        .iter()
+                        .map(|&p| {
+                            let (x, y) = tr(p);
+                            format!("{:.1},{:.1}", x, y)
+                        })
+                        .collect();
+                    writeln!(
+                        out,
+                        r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="2" marker-end="url(#arrow)"/>"#,
+                        pts.join(" "),
+                        time_color(*elapsed, total)
+                    )
+                    .unwrap();
+                }
+                Mark::Collect { at, num, label } => {
+                    let (x, y) = tr(*at);
+                    writeln!(
+                        out,
+                        r#"<g><title>{}</title><circle cx="{:.1}" cy="{:.1}" r="8" fill="white" stroke="black"/><text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="9">{}</text></g>"#,
+                        svg_escape(label),
+                        x,
+                        y,
+                        x,
+                        y + 3.0,
+                        num
+                    )
+                    .unwrap();
+                }
+                Mark::Arrive { at, label, warp } => {
+                    let (x, y) = tr(*at);
+                    writeln!(
+                        out,
+                        r#"<g><title>{}</title><line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="{}" stroke-width="2"{} marker-end="url(#arrow)"/><text x="{:.1}" y="{:.1}" font-size="9">{}</text></g>"#,
+                        svg_escape(label),
+                        x - 24.0,
+                        y - 24.0,
+                        x,
+                        y,
+                        if *warp { "purple" } else { "gray" },
+                        if *warp { r#" stroke-dasharray="4 3""# } else { "" },
+                        x - 24.0,
+                        y - 27.0,
+                        svg_escape(label)
+                    )
+                    .unwrap();
+                }
+            }
+        }
+        out.push_str("</g>\n");
+    }
+    out.push_str("</svg>\n");
+    out
+}
@@ -1,6 +1,8 @@
 use crate::context::Ctx;
-use crate::db::RouteDb;
-use rocksdb::{ColumnFamily, DB};
+use crate::db::{DbMetadata, RouteDb, METADATA_KEY, ROUTES_DIR, STATES_DIR};
+use crate::storage::serialize_data;
+use rocksdb::{ColumnFamily, Options, DB};
+use std::path::Path;
 use tempfile::TempDir;
 use serde::{Deserialize, Serialize};
 
@@ -35,3 +37,25 @@ pub fn all_keys(db: &DB) -> impl Iterator<Item = Box<[u8]>> + use<'_> {
 pub fn all_keys_cf<'a>(db: &'a DB, cf: &'a ColumnFamily) -> impl Iterator<Item = Box<[u8]>> + use<'a> {
     db.iterator_cf(cf, rocksdb::IteratorMode::Start).map(|el| el.unwrap().0)
 }
+
+/// Stamps the state db in the given db directory with the given metadata, creating it if needed,
+/// as if it were written by another build.
+pub fn stamp_state_db<P: AsRef<Path>>(p: P, metadata: &DbMetadata) {
+    stamp_db(p.as_ref().join(STATES_DIR), metadata);
+}
+
+/// Stamps the route db in the given db directory with the given metadata, creating it if needed,
+/// as if it were written by another build.
+pub fn stamp_route_db<P: AsRef<Path>>(p: P, metadata: &DbMetadata) {
+    stamp_db(p.as_ref().join(ROUTES_DIR), metadata);
+}
+
+/// Stamps the db at the given path with the given metadata, creating it if needed,
+/// as if it were written by another build.
+pub fn stamp_db<P: AsRef<Path>>(p: P, metadata: &DbMetadata) {
+    let mut opts = Options::default();
+    opts.create_if_missing(true);
+    let cfs = DB::list_cf(&opts, p.as_ref()).unwrap_or_default();
+    let db = DB::open_cf(&opts, p.as_ref(), cfs).unwrap();
+    db.put(METADATA_KEY, serialize_data(metadata)).unwrap();
+}
@@ -0,0 +1,317 @@
+//! Seeded random walks over a world, checking logic invariants at every state.
+
+use crate::context::*;
+use crate::search::{classic_step, single_step};
+use crate::storage::{get_obj_from_data, serialize_data};
+use crate::util::SplitMix64;
+use crate::world::*;
+use std::fmt::{self, Display};
+
+/// Environment variable overriding the seed used by generated fuzz tests.
+pub const FUZZ_SEED_VAR: &str = "FUZZ_SEED";
+
+#[derive(Clone, Debug)]
+pub struct FuzzOptions {
+    pub seed: u64,
+    pub walks: usize,
+    pub max_steps: usize,
+}
+
+impl Default for FuzzOptions {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            walks: 32,
+            max_steps: 64,
+        }
+    }
+}
+
+impl FuzzOptions {
+    /// Default options, with the seed taken from `FUZZ_SEED` if set.
+    pub fn from_env() -> Self {
+        let mut options = Self::default();
+        if let Ok(s) = std::env::var(FUZZ_SEED_VAR) {
+            options.seed = s
+                .parse()
+                .unwrap_or_else(|e| panic!("Invalid {}={:?}: {}", FUZZ_SEED_VAR, s, e));
+        }
+        options
+    }
+}
+
+/// The successor function used for a step of a walk.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Stepper {
+    Classic,
+    Single,
+}
+
+impl Stepper {
+    fn step<W, T, L>(self, world: &W, ctx: ContextWrapper<T>) -> Vec<ContextWrapper<T>>
+    where
+        W: World<Location = L>,
+        T: Ctx<World = W>,
+        L: Location<Context = T>,
+    {
+        match self {
+            Stepper::Classic => classic_step(world, ctx, u32::MAX),
+            Stepper::Single => single_step(world, ctx, u32::MAX),
+        }
+    }
+}
+
+pub struct FuzzFailure<T: Ctx> {
+    pub seed: u64,
+    pub walk: usize,
+    pub error: String,
+    /// A minimized route from the start that reaches the state before the failing step.
+    pub route: Vec<HistoryAlias<T>>,
+    /// The failing step, as produced by `stepper`.
+    pub step: Vec<HistoryAlias<T>>,
+    pub stepper: Stepper,
+}
+
+impl<T: Ctx> Display for FuzzFailure<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Invariant violated (seed {}, walk {}): {}\nReproducing route:\n{}\nFailing {:?} step:\n{}",
+            self.seed,
+            self.walk,
+            self.error,
+            history_str::<T, _>(self.route.iter().copied()),
+            self.stepper,
+            history_str::<T, _>(self.step.iter().copied()),
+        )
+    }
+}
+
+fn check_accessible<A, T>(
+    world: &T::World,
+    ctx: &T,
+    a: &A,
+    name: impl Display,
+) -> Result<(), String>
+where
+    A: Accessible<Context = T>,
+    T: Ctx,
+{
+    let time = a.time(ctx, world);
+    if time < a.base_time() {
+        return Err(format!(
+            "{}: time {} is less than base time {}",
+            name,
+            time,
+            a.base_time()
+        ));
+    }
+    let mut observer = T::Observer::default();
+    let can = a.can_access(ctx, world);
+    let observed = a.observe_access(ctx, world, &mut observer);
+    if can != observed {
+        return Err(format!(
+            "{}: can_access is {} but observe_access is {}",
+            name, can, observed
+        ));
+    }
+    Ok(())
+}
+
+/// Checks invariants that hold for any single state.
+pub fn check_state<W, T, L>(world: &W, ctx: &T) -> Result<(), String>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+{
+    let pos = ctx.position();
+    for loc in world.get_spot_locations(pos) {
+        check_accessible(world, ctx, loc, loc.id())?;
+    }
+    for exit in world.get_spot_exits(pos) {
+        check_accessible(world, ctx, exit, exit.id())?;
+    }
+    for act in world
+        .get_spot_actions(pos)
+        .iter()
+        .chain(world.get_global_actions())
+    {
+        check_accessible(world, ctx, act, act.id())?;
+    }
+    for warp in world.get_warps() {
+        check_accessible(world, ctx, warp, warp.id())?;
+    }
+
+    let roundtrip: T = get_obj_from_data(&serialize_data(ctx))
+        .map_err(|e| format!("Failed to deserialize state: {}", e))?;
+    if roundtrip != *ctx {
+        return Err(format!(
+            "Serialization round-trip changed the state: {}",
+            roundtrip.diff(ctx)
+        ));
+    }
+
+    let diff = ctx.diff(ctx);
+    if !diff.is_empty() {
+        return Err(format!(
+            "Diff of state against itself is not empty: {}",
+            diff
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that replaying the history added by a step reproduces the step.
+pub fn check_transition<W, T, L>(
+    world: &W,
+    prev: &ContextWrapper<T>,
+    next: &ContextWrapper<T>,
+) -> Result<(), String>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+{
+    let mut replayed = prev.clone();
+    for step in &next.recent_history()[prev.recent_history().len()..] {
+        replayed.replay(world, *step);
+    }
+    if replayed.get() != next.get() {
+        return Err(format!(
+            "Replay differs from the original step: {}",
+            replayed.get().diff(next.get())
+        ));
+    }
+    if replayed.elapsed() != next.elapsed() {
+        return Err(format!(
+            "Replay took {}ms but the original step took {}ms",
+            replayed.elapsed() - prev.elapsed(),
+            next.elapsed() - prev.elapsed()
+        ));
+    }
+    Ok(())
+}
+
+/// Regenerates the given step from `prev` and checks it, or returns None if the stepper
+/// no longer produces it.
+fn recheck_step<W, T, L>(
+    world: &W,
+    prev: &ContextWrapper<T>,
+    stepper: Stepper,
+    step: &[HistoryAlias<T>],
+) -> Option<Result<(), String>>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+{
+    let len = prev.recent_history().len();
+    let next = stepper
+        .step(world, prev.clone())
+        .into_iter()
+        .find(|c| &c.recent_history()[len..] == step)?;
+    Some(check_transition(world, prev, &next).and_then(|_| check_state(world, next.get())))
+}
+
+/// Removes steps from the route one at a time, keeping each removal that still reproduces a failure.
+fn minimize_route<W, T, L>(
+    world: &W,
+    startctx: &T,
+    mut route: Vec<HistoryAlias<T>>,
+    stepper: Stepper,
+    step: &[HistoryAlias<T>],
+    mut error: String,
+) -> (Vec<HistoryAlias<T>>, String)
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+{
+    let mut i = 0;
+    while i < route.len() {
+        let mut candidate = route.clone();
+        candidate.remove(i);
+        if let Ok(prev) =
+            ContextWrapper::new(startctx.clone()).try_replay_all(world, candidate.iter())
+        {
+            if let Some(Err(e)) = recheck_step(world, &prev, stepper, step) {
+                route = candidate;
+                error = e;
+                continue;
+            }
+        }
+        i += 1;
+    }
+    (route, error)
+}
+
+/// Performs `options.walks` random walks of up to `options.max_steps` steps each from the start state,
+/// checking invariants at every state and transition. Returns the number of states checked,
+/// or the first failure with a minimized route that reproduces it.
+pub fn fuzz_world<W, T, L>(
+    world: &W,
+    startctx: &T,
+    options: &FuzzOptions,
+) -> Result<usize, FuzzFailure<T>>
+where
+    W: World<Location = L>,
+    T: Ctx<World = W>,
+    L: Location<Context = T>,
+{
+    let mut rng = SplitMix64::new(options.seed);
+    let mut checked = 1;
+    if let Err(error) = check_state(world, startctx) {
+        return Err(FuzzFailure {
+            seed: options.seed,
+            walk: 0,
+            error,
+            route: Vec::new(),
+            step: Vec::new(),
+            stepper: Stepper::Classic,
+        });
+    }
+
+    for walk in 0..options.walks {
+        let mut ctx = ContextWrapper::new(startctx.clone());
+        for _ in 0..options.max_steps {
+            let stepper = if rng.below(2) == 0 {
+                Stepper::Classic
+            } else {
+                Stepper::Single
+            };
+            let mut next = stepper.step(world, ctx.clone());
+            if next.is_empty() {
+                break;
+            }
+            let next = next.swap_remove(rng.below(next.len()));
+            checked += 1;
+            if let Err(e) =
+                check_transition(world, &ctx, &next).and_then(|_| check_state(world, next.get()))
+            {
+                let step = next.recent_history()[ctx.recent_history().len()..].to_vec();
+                let (route, error) = minimize_route(
+                    world,
+                    startctx,
+                    ctx.recent_history().to_vec(),
+                    stepper,
+                    &step,
+                    e,
+                );
+                return Err(FuzzFailure {
+                    seed: options.seed,
+                    walk,
+                    error,
+                    route,
+                    step,
+                    stepper,
+                });
+            }
+            if world.won(next.get()) {
+                break;
+            }
+            ctx = next;
+        }
+    }
+    Ok(checked)
+}
@@ -1,5 +1,7 @@
 pub mod heap;
 pub mod db;
+pub mod fuzz;
+pub mod report;
 
 pub use heap::LimitedHeap;
 
@@ -484,7 +486,7 @@ macro_rules! expect_eventually_requires {
                 let result = ($verify_req)(ctx.get());
                 assert!(
                     result.is_ok(),
-                    "Unexpectedly able to {} without requirements:\n{}\n{}\n",
+                    "Able to {} without meeting expectations:\n{}\nRoute taken:\n{}\n",
                     $desc,
                     result.unwrap_err(),
                     $crate::context::history_str::<$T, _>(ctx.recent_history().iter().copied()),
@@ -525,7 +527,7 @@ macro_rules! expect_eventually_requires_to_obtain {
                 let result = ($verify_req)(ctx.get());
                 assert!(
                     result.is_ok(),
-                    "Unexpectedly able to find {} without requirements:\n{}\n{}\n",
+                    "Able to find {} without meeting expectations:\n{}\nRoute taken:\n{}\n",
                     $item,
                     result.unwrap_err(),
                     $crate::context::history_str::<$T, _>(ctx.recent_history().iter().copied()),
@@ -565,7 +567,7 @@ macro_rules! expect_eventually_requires_to_reach {
                 let result = ($verify_req)(ctx.get());
                 assert!(
                     result.is_ok(),
-                    "Unexpectedly able to reach {} without requirements:\n{}\n{}\n",
+                    "Able to reach {} without meeting expectations:\n{}\nRoute taken:\n{}\n",
                     $spot,
                     result.unwrap_err(),
                     $crate::context::history_str::<$T, _>(ctx.recent_history().iter().copied()),
@@ -604,7 +606,7 @@ macro_rules! expect_eventually_requires_to_access {
                 let result = ($verify_req)(ctx.get());
                 assert!(
                     result.is_ok(),
-                    "Unexpectedly able to visit {} without requirements:\n{}\n{}\n",
+                    "Able to visit {} without meeting expectations:\n{}\nRoute taken:\n{}\n",
                     $loc_id,
                     result.unwrap_err(),
                     $crate::context::history_str::<$T, _>(ctx.recent_history().iter().copied()),
@@ -645,7 +647,7 @@ macro_rules! expect_eventually_requires_to_activate {
                     let result = ($verify_req)(ctx.get());
                     assert!(
                         result.is_ok(),
-                        "Unexpectedly able to activate {} without requirements:\n{}\n{}\n",
+                        "Able to activate {} without meeting expectations:\n{}\nRoute taken:\n{}\n",
                         $act_id,
                         result.unwrap_err(),
                         $crate::context::history_str::<$T, _>(ctx.recent_history().iter().copied()),
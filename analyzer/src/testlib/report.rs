@@ -0,0 +1,188 @@
+//! Machine-readable reports of unittest results.
+
+use libtest_mimic::Failed;
+use serde::Serialize;
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// Environment variable naming the file to write a JSON report to.
+pub const JSON_REPORT_VAR: &str = "UNITTEST_JSON";
+/// Environment variable naming the file to write a JUnit XML report to.
+pub const JUNIT_REPORT_VAR: &str = "UNITTEST_JUNIT";
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TestRecord {
+    pub name: String,
+    pub file: String,
+    pub mode: String,
+    pub time_secs: f64,
+    /// The failure explanation, if the test failed.
+    pub failure: Option<String>,
+}
+
+/// Collects the results of tests as they run, for reporting once all tests are done.
+#[derive(Clone, Default)]
+pub struct TestRecorder(Arc<Mutex<Vec<TestRecord>>>);
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        String::from("<non-string panic>")
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl TestRecorder {
+    /// Runs the test, recording its result whether it returns an error or panics.
+    ///
+    /// Cargo builds test targets with `panic = "unwind"` whatever the profile says, so the
+    /// workspace's `panic = "abort"` release profile doesn't affect `cargo test --release`.
+    /// When the tests are built to abort anyway (e.g. `-C panic=abort` or `-Z panic-abort-tests`),
+    /// a panic ends the process before `catch_unwind` returns, so each test is recorded as
+    /// aborted and the reports written before it runs, leaving reports that name the failing test.
+    pub fn run<F>(&self, name: String, file: String, mode: String, test: F) -> Result<(), Failed>
+    where
+        F: FnOnce() -> Result<(), String>,
+    {
+        let start = Instant::now();
+        let mut record = TestRecord {
+            name,
+            file,
+            mode,
+            time_secs: 0.0,
+            failure: None,
+        };
+        let index = if cfg!(panic = "abort") {
+            let index = {
+                let mut records = self.0.lock().unwrap();
+                records.push(TestRecord {
+                    failure: Some(String::from("aborted: the test panicked")),
+                    ..record.clone()
+                });
+                records.len() - 1
+            };
+            self.write_reports_from_env();
+            Some(index)
+        } else {
+            None
+        };
+
+        record.failure = match catch_unwind(AssertUnwindSafe(test)) {
+            Ok(Ok(())) => None,
+            Ok(Err(e)) => Some(e),
+            Err(payload) => Some(panic_message(payload)),
+        };
+        record.time_secs = start.elapsed().as_secs_f64();
+        let failure = record.failure.clone();
+        {
+            let mut records = self.0.lock().unwrap();
+            match index {
+                Some(i) => records[i] = record,
+                None => records.push(record),
+            }
+        }
+        match failure {
+            Some(e) => Err(e.into()),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the recorded results, sorted by file and name.
+    pub fn records(&self) -> Vec<TestRecord> {
+        let mut records = self.0.lock().unwrap().clone();
+        records.sort_by(|a, b| (&a.file, &a.name).cmp(&(&b.file, &b.name)));
+        records
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.records()).unwrap()
+    }
+
+    /// Formats the results as JUnit XML, with one test suite per file.
+    pub fn to_junit_xml(&self) -> String {
+        let records = self.records();
+        let mut suites: BTreeMap<&str, Vec<&TestRecord>> = BTreeMap::new();
+        for rec in &records {
+            suites.entry(rec.file.as_str()).or_default().push(rec);
+        }
+
+        let failures = records.iter().filter(|r| r.failure.is_some()).count();
+        let mut lines = vec![
+            String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#),
+            format!(
+                r#"<testsuites tests="{}" failures="{}">"#,
+                records.len(),
+                failures
+            ),
+        ];
+        for (file, recs) in suites {
+            let classname = Path::new(file)
+                .file_stem()
+                .and_then(|f| f.to_str())
+                .unwrap_or(file);
+            lines.push(format!(
+                r#"  <testsuite name="{}" tests="{}" failures="{}" time="{:.3}">"#,
+                xml_escape(file),
+                recs.len(),
+                recs.iter().filter(|r| r.failure.is_some()).count(),
+                recs.iter().map(|r| r.time_secs).sum::<f64>(),
+            ));
+            for rec in recs {
+                lines.push(format!(
+                    r#"    <testcase name="{}" classname="{}" time="{:.3}">"#,
+                    xml_escape(&rec.name),
+                    xml_escape(classname),
+                    rec.time_secs,
+                ));
+                lines.push(format!(
+                    r#"      <properties><property name="mode" value="{}"/></properties>"#,
+                    xml_escape(&rec.mode)
+                ));
+                if let Some(failure) = &rec.failure {
+                    lines.push(format!(
+                        r#"      <failure message="{}">{}</failure>"#,
+                        xml_escape(failure.lines().next().unwrap_or_default()),
+                        xml_escape(failure)
+                    ));
+                }
+                lines.push(String::from("    </testcase>"));
+            }
+            lines.push(String::from("  </testsuite>"));
+        }
+        lines.push(String::from("</testsuites>"));
+        lines.join("\n")
+    }
+
+    /// Writes the reports requested via `UNITTEST_JSON` and `UNITTEST_JUNIT`.
+    pub fn write_reports_from_env(&self) {
+        if let Ok(path) = std::env::var(JSON_REPORT_VAR) {
+            std::fs::write(&path, self.to_json())
+                .unwrap_or_else(|e| panic!("Couldn't write to file {:?}: {:?}", path, e));
+        }
+        if let Ok(path) = std::env::var(JUNIT_REPORT_VAR) {
+            std::fs::write(&path, self.to_junit_xml())
+                .unwrap_or_else(|e| panic!("Couldn't write to file {:?}: {:?}", path, e));
+        }
+    }
+}
@@ -4,6 +4,7 @@ use crate::context::*;
 use crate::estimates::ContextScorer;
 use crate::route::*;
 use crate::steiner::*;
+use crate::testlib::report::TestRecorder;
 use crate::world::*;
 use crate::*;
 use lazy_static::lazy_static;
@@ -36,11 +37,16 @@ where
     RequiresToReach(T, <<T::World as World>::Exit as Exit>::SpotId),
     RequiresToAccess(T, <<T::World as World>::Location as Location>::LocId),
     RequiresToActivate(T, <<T::World as World>::Action as Action>::ActionId),
-    // TODO: These require expectations provided in the unittest.
+    // Every state found within the iteration limit that reaches the target
+    // must meet the expectations provided in the unittest.
     EventuallyRequiresToObtain(T::ItemId, u32),
     EventuallyRequiresToReach(<<T::World as World>::Exit as Exit>::SpotId, u32),
     EventuallyRequiresToAccess(<<T::World as World>::Location as Location>::LocId, u32),
     EventuallyRequiresToActivate(<<T::World as World>::Action as Action>::ActionId, u32),
+    // Timing budgets, in ms.
+    ReachWithin(<<T::World as World>::Exit as Exit>::SpotId, u32),
+    AccessWithin(<<T::World as World>::Location as Location>::LocId, u32),
+    PathTime(Vec<(HistoryAlias<T>, String)>, u32),
 }
 
 const DEFAULT_ITERATION_LIMIT: u32 = 100;
@@ -94,6 +100,17 @@ where
             TestMode::EventuallyRequiresToActivate(act, limit) => {
                 write!(f, "EventuallyRequiresToActivate({}, {})", act, limit)
             }
+            TestMode::ReachWithin(spot, ms) => write!(f, "ReachWithin({}, {}ms)", spot, ms),
+            TestMode::AccessWithin(loc_id, ms) => write!(f, "AccessWithin({}, {}ms)", loc_id, ms),
+            TestMode::PathTime(v, ms) => write!(
+                f,
+                "PathTime({}, {}ms)",
+                v.iter()
+                    .map(|(h, _)| format!("{:?}", h))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                ms
+            ),
         }
     }
 }
@@ -411,7 +428,8 @@ where
                             } else {
                                 TestMode::RequiresToAccess(rctx.clone(), loc_id)
                             }
-                        })
+                        }),
+                        name
                     )
                 }
                 Some("to_activate") => {
@@ -424,7 +442,8 @@ where
                             } else {
                                 TestMode::RequiresToActivate(rctx.clone(), act)
                             }
-                        })
+                        }),
+                        name
                     )
                 }
                 Some("iteration_limit") => {
@@ -442,6 +461,15 @@ where
                 _ => {}
             }
         }
+        if eventually && rctx != *initial {
+            errs.push(format!(
+                "{}: eventually_requires takes no setup; requirements go in expect",
+                name
+            ));
+        }
+        if !errs.is_empty() {
+            return Err(errs.join("\n"));
+        }
         match mode {
             Some(TestMode::EventuallyRequiresToObtain(item, _)) => {
                 return Ok((TestMode::EventuallyRequiresToObtain(item, ilimit), expects))
@@ -495,6 +523,89 @@ where
     }
 }
 
+fn route_from_yaml<T>(yaml: &Yaml) -> Result<Vec<(HistoryAlias<T>, String)>, String>
+where
+    T: Ctx,
+{
+    match yaml {
+        Yaml::String(s) => histlines_from_string::<T>(s),
+        Yaml::Array(v) => histlines_from_yaml_vec::<T>(v),
+        _ => Err(String::from("Expected string or vec for path value")),
+    }
+    .map(|route| {
+        route
+            .into_iter()
+            .map(|(h, s)| (h, format!("{}", s)))
+            .collect()
+    })
+}
+
+fn ms_from_yaml(yaml: &Yaml) -> Result<u32, String> {
+    if let Some(v) = yaml.as_i64() {
+        u32::try_from(v).map_err(|e| format!("{}: {}", v, e))
+    } else {
+        Err(format!("Time value not an integer: {:?}", yaml))
+    }
+}
+
+/// Parses a `reach_within` test, which takes a time budget `ms` and exactly one of:
+/// - `spot`: the spot to move to, or
+/// - `location`: the location to move to and collect.
+fn handle_within_test<W, T>(yaml: &Yaml) -> Result<TestMode<T>, String>
+where
+    T: Ctx<World = W>,
+    W: World,
+    W::Location: Location<Context = T>,
+{
+    let map = yaml
+        .as_hash()
+        .ok_or_else(|| format!("Expected key-value map: {:?}", yaml))?;
+    let mut spot = None;
+    let mut loc = None;
+    let mut ms = None;
+    for (key, value) in map {
+        match key.as_str() {
+            Some("spot") => spot = Some(obj_from_yaml::<<W::Exit as Exit>::SpotId>(value)?),
+            Some("location") => {
+                loc = Some(obj_from_yaml::<<W::Location as Location>::LocId>(value)?)
+            }
+            Some("ms") => ms = Some(ms_from_yaml(value)?),
+            _ => return Err(format!("Unrecognized key {:?}", key)),
+        }
+    }
+    match (spot, loc, ms) {
+        (Some(sp), None, Some(ms)) => Ok(TestMode::ReachWithin(sp, ms)),
+        (None, Some(loc_id), Some(ms)) => Ok(TestMode::AccessWithin(loc_id, ms)),
+        (_, _, None) => Err(String::from("Missing time budget \"ms\"")),
+        _ => Err(String::from(
+            "Expected exactly one of \"spot\" or \"location\"",
+        )),
+    }
+}
+
+fn handle_path_time_test<T>(yaml: &Yaml) -> Result<TestMode<T>, String>
+where
+    T: Ctx,
+{
+    let map = yaml
+        .as_hash()
+        .ok_or_else(|| format!("Expected key-value map: {:?}", yaml))?;
+    let mut route = None;
+    let mut ms = None;
+    for (key, value) in map {
+        match key.as_str() {
+            Some("path" | "route") => route = Some(route_from_yaml::<T>(value)?),
+            Some("max_ms") => ms = Some(ms_from_yaml(value)?),
+            _ => return Err(format!("Unrecognized key {:?}", key)),
+        }
+    }
+    match (route, ms) {
+        (Some(route), Some(ms)) => Ok(TestMode::PathTime(route, ms)),
+        (None, _) => Err(String::from("Missing \"path\"")),
+        (_, None) => Err(String::from("Missing time budget \"max_ms\"")),
+    }
+}
+
 pub fn build_test<'a, W, T>(
     yaml: &'a Yaml,
     initial: &T,
@@ -601,19 +712,15 @@ where
                 Some("path") | Some("route") => assign_mode_or_append_err!(
                     mode,
                     errs,
-                    match value {
-                        Yaml::String(s) => histlines_from_string::<T>(s),
-                        Yaml::Array(v) => histlines_from_yaml_vec::<T>(v),
-                        _ => Err(String::from("Expected string or vec for path value")),
-                    }
-                    .map(|route| TestMode::Route(
-                        route
-                            .into_iter()
-                            .map(|(h, s)| (h, format!("{}", s)))
-                            .collect()
-                    )),
+                    route_from_yaml::<T>(value).map(TestMode::Route),
                     tname
                 ),
+                Some("reach_within") => {
+                    assign_mode_or_append_err!(mode, errs, handle_within_test::<W, T>(value), tname)
+                }
+                Some("path_time") => {
+                    assign_mode_or_append_err!(mode, errs, handle_path_time_test::<T>(value), tname)
+                }
                 Some("requires") => assign_mode_expects_or_append_err!(
                     mode,
                     expects,
@@ -636,6 +743,23 @@ where
             }
         }
 
+        if expects.is_empty()
+            && matches!(
+                mode,
+                Some(
+                    TestMode::EventuallyRequiresToObtain(..)
+                        | TestMode::EventuallyRequiresToReach(..)
+                        | TestMode::EventuallyRequiresToAccess(..)
+                        | TestMode::EventuallyRequiresToActivate(..)
+                )
+            )
+        {
+            errs.push(format!(
+                "{}: eventually_requires needs expect",
+                test_name.unwrap_or(name)
+            ));
+        }
+
         if errs.is_empty() {
             match (test_name, mode) {
                 (Some(tn), Some(m)) => {
@@ -694,6 +818,24 @@ where
     }
 }
 
+fn check_time_budget<T>(ctx: &ContextWrapper<T>, max_ms: u32, goal: &str) -> Result<(), String>
+where
+    T: Ctx,
+{
+    if ctx.elapsed() <= max_ms {
+        Ok(())
+    } else {
+        Err(format!(
+            "Fastest route found to {} takes {}ms, exceeding the budget of {}ms by {}ms:\n{}",
+            goal,
+            ctx.elapsed(),
+            max_ms,
+            ctx.elapsed() - max_ms,
+            history_str::<T, _>(ctx.recent_history().iter().copied())
+        ))
+    }
+}
+
 pub fn run_test<W, T>(
     world: &W,
     mut initial: T,
@@ -823,6 +965,39 @@ where
                 ilimit
             )
         }
+        TestMode::ReachWithin(spot, ms) => {
+            let ctx = access::move_to(world, ContextWrapper::new(initial), spot, shortest_paths)
+                .map_err(|e| format!("Could not reach {} from {}:\n{}", spot, start, e))?;
+            check_time_budget(&ctx, ms, &spot.to_string())?;
+            ctx.get().assert_expectations(&expects)?;
+        }
+        TestMode::AccessWithin(loc_id, ms) => {
+            let item = world.get_location(loc_id).item();
+            let ctx = step_from_route(
+                ContextWrapper::new(initial),
+                0,
+                History::G(item, loc_id),
+                world,
+                shortest_paths,
+            )?;
+            check_time_budget(&ctx, ms, &loc_id.to_string())?;
+            ctx.get().assert_expectations(&expects)?;
+        }
+        TestMode::PathTime(route, ms) => {
+            let mut ctx = ContextWrapper::new(initial);
+            for (i, (h, s)) in route.into_iter().enumerate() {
+                ctx = step_from_route(ctx.clone(), i, h, world, shortest_paths).map_err(|e| {
+                    format!(
+                        "Route so far:\n{}\nAt {}: {}",
+                        history_str::<T, _>(ctx.recent_history().iter().copied()),
+                        s,
+                        e
+                    )
+                })?;
+            }
+            check_time_budget(&ctx, ms, "the end of the path")?;
+            ctx.get().assert_expectations(&expects)?;
+        }
     }
     Ok(())
 }
@@ -831,6 +1006,7 @@ pub fn parse_test_file<W, T>(
     world: Arc<Box<W>>,
     filename: &Path,
     shortest_paths: Arc<Box<ShortestPaths<NodeId<W>, EdgeId<W>>>>,
+    recorder: &TestRecorder,
 ) -> Vec<Trial>
 where
     T: Ctx<World = W> + 'static,
@@ -888,8 +1064,14 @@ where
         .map(|t| {
             let wp = world.clone();
             let sp = shortest_paths.clone();
-            Trial::test(format!("{}:{}", prefix, t.name), move || {
-                Ok(run_test(&**wp, t.initial, t.mode, t.expects, &**sp)?)
+            let rec = recorder.clone();
+            let name = format!("{}:{}", prefix, t.name);
+            let file = filename.display().to_string();
+            let mode = t.mode.to_string();
+            Trial::test(name.clone(), move || {
+                rec.run(name, file, mode, || {
+                    run_test(&**wp, t.initial, t.mode, t.expects, &**sp)
+                })
             })
         })
         .collect()
@@ -899,6 +1081,7 @@ pub fn parse_route_file<W, T>(
     world: Arc<Box<W>>,
     filename: &Path,
     shortest_paths: Arc<Box<ShortestPaths<NodeId<W>, EdgeId<W>>>>,
+    recorder: &TestRecorder,
 ) -> Trial
 where
     T: Ctx<World = W> + 'static,
@@ -923,14 +1106,13 @@ where
                 .collect(),
         )
     });
-    Trial::test(format!("routes/{}", prefix), move || {
-        Ok(run_test(
-            &**world,
-            T::default(),
-            mode?,
-            vec![],
-            &**shortest_paths,
-        )?)
+    let rec = recorder.clone();
+    let name = format!("routes/{}", prefix);
+    let file = filename.display().to_string();
+    Trial::test(name.clone(), move || {
+        rec.run(name, file, String::from("Route"), || {
+            run_test(&**world, T::default(), mode?, vec![], &**shortest_paths)
+        })
     })
 }
 
@@ -943,9 +1125,11 @@ pub fn run_test_file<W, T>(
     W: World + Send + 'static,
     W::Location: Location<Context = T>,
 {
-    let tests = parse_test_file(world, filename, shortest_paths);
+    let recorder = TestRecorder::default();
+    let tests = parse_test_file(world, filename, shortest_paths, &recorder);
     let args = Arguments::from_args();
     run(&args, tests); //.exit_if_failed();
+    recorder.write_reports_from_env();
 }
 
 pub fn run_all_tests_in_dir<W, T>(dirname: &Path, route_dir: Option<&Path>)
@@ -963,6 +1147,7 @@ where
 
     let wp = Arc::new(world);
     let mut tests = Vec::new();
+    let recorder = TestRecorder::default();
 
     for entry in std::fs::read_dir(dirname).expect(&format!("{:?}", dirname)) {
         let path = entry.unwrap().path();
@@ -972,6 +1157,7 @@ where
                 wp.clone(),
                 &path,
                 shortest_paths.clone(),
+                &recorder,
             ));
         }
     }
@@ -986,6 +1172,7 @@ where
                         wp.clone(),
                         &path,
                         shortest_paths.clone(),
+                        &recorder,
                     ));
                 }
             }
@@ -994,4 +1181,5 @@ where
 
     let args = Arguments::from_args();
     run(&args, tests); //.exit_if_failed();
+    recorder.write_reports_from_env();
 }
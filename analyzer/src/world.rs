@@ -1,5 +1,6 @@
 use crate::condense::CondensedEdge;
 use crate::context::Ctx;
+use crate::goal::Goal;
 use crate::new_hashset;
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
@@ -135,6 +136,8 @@ pub trait Action: Accessible {
     fn id(&self) -> Self::ActionId;
     fn perform(&self, ctx: &mut Self::Context, world: &<Self::Context as Ctx>::World);
     fn dest(&self, ctx: &Self::Context, world: &<Self::Context as Ctx>::World) -> Self::SpotId;
+    /// Whether the destination comes from a context variable, so it can change along a route.
+    fn dest_from_context(&self) -> bool;
     fn observe_effects(
         &self,
         ctx: &mut Self::Context,
@@ -154,6 +157,8 @@ pub trait Warp: Accessible {
     fn postwarp(&self, ctx: &mut Self::Context, world: &<Self::Context as Ctx>::World);
     fn should_reload(&self) -> bool;
     fn is_bulk_exit(&self) -> bool;
+    /// Whether the destination comes from a context variable, so it can change along a route.
+    fn dest_from_context(&self) -> bool;
     fn observe_effects(
         &self,
         ctx: &mut Self::Context,
@@ -202,6 +207,9 @@ pub trait World: Sync {
     fn get_all_spots(&self) -> &[<Self::Exit as Exit>::SpotId];
     fn same_region(sp1: <Self::Exit as Exit>::SpotId, sp2: <Self::Exit as Exit>::SpotId) -> bool;
     fn same_area(sp1: <Self::Exit as Exit>::SpotId, sp2: <Self::Exit as Exit>::SpotId) -> bool;
+    fn get_spot_region(
+        spot_id: <Self::Exit as Exit>::SpotId,
+    ) -> <<Self::Location as Accessible>::Context as Ctx>::RegionId;
     fn get_area_spots(
         &self,
         spot_id: <Self::Exit as Exit>::SpotId,
@@ -231,6 +239,9 @@ pub trait World: Sync {
     fn action_has_visit(act_id: <Self::Action as Action>::ActionId) -> bool;
 
     fn won(&self, ctx: &<Self::Location as Accessible>::Context) -> bool;
+    /// The goal from the settings file, if any, which replaces the victory rules
+    /// in `won`, `items_needed` and `required_items`.
+    fn goal(&self) -> Option<&Goal<Self>>;
     fn items_needed(
         &self,
         ctx: &<Self::Location as Accessible>::Context,
@@ -296,6 +307,8 @@ pub trait World: Sync {
     /// Returns the Euclidean straight-line distance between spots' coordinates,
     /// or infinity if either has no coordinate.
     fn spot_distance(a: <Self::Exit as Exit>::SpotId, b: <Self::Exit as Exit>::SpotId) -> f32;
+    /// Returns the spot's coordinates within its area, or None if it has no coordinate.
+    fn spot_coordinate(spot: <Self::Exit as Exit>::SpotId) -> Option<(f32, f32)>;
     fn spot_of_interest(&self, sp: <Self::Exit as Exit>::SpotId) -> bool;
 
     fn spot_community(spot_id: <Self::Exit as Exit>::SpotId) -> usize;
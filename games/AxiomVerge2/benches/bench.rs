@@ -5,12 +5,19 @@
 use analyzer::access::*;
 use analyzer::cli::read_from_file;
 use analyzer::context::*;
+use analyzer::db::HeapDB;
+use analyzer::dbprofile::DbProfile;
+use analyzer::encoding::{make_codec, sample_states, StateCodec, StateEncoding};
 use analyzer::estimates::ContextScorer;
 use analyzer::greedy::*;
+use analyzer::heap::DbBackedQueue;
 use analyzer::matchertrie::MatcherTrie;
 use analyzer::observer::record_observations;
 use analyzer::route::route_from_string;
+use analyzer::scoring::{ScoreMetric, TimeSinceAndElapsed};
+use analyzer::search::single_step;
 use analyzer::solutions::{Solution, SolutionSuffix};
+use analyzer::storage::ContextDB;
 use analyzer::world::World as _;
 use analyzer::CommonHasher;
 use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
@@ -21,9 +28,51 @@ use libaxiom_verge2::items::Item;
 use libaxiom_verge2::observe::ObservationMatcher;
 use rustc_hash::FxHashSet;
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+/// Small enough that most states spill to the state db.
+const BENCH_QUEUE_MEMORY: usize = 1 << 16;
+const BENCH_SEARCH_STEPS: usize = 2_000;
+
+type BenchQueue<'w> = DbBackedQueue<
+    'w,
+    World,
+    Context,
+    16,
+    TimeSinceAndElapsed<'w, World>,
+    HeapDB<'w, World, Context, 16, TimeSinceAndElapsed<'w, World>>,
+>;
+
+/// Runs a single-threaded search for the given number of steps.
+fn search_steps(world: &World, queue: &BenchQueue<'_>, start: &Context, steps: usize) {
+    queue
+        .push(ContextWrapper::new(start.clone()), None)
+        .unwrap();
+    for _ in 0..steps {
+        let Some(ctx) = queue.pop().unwrap() else {
+            break;
+        };
+        let next = single_step(world, ctx.clone(), queue.max_time());
+        queue.extend(next, ctx.get()).unwrap();
+    }
+}
+
+fn dir_size(path: &Path) -> u64 {
+    std::fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| match e.metadata() {
+                    Ok(m) if m.is_dir() => dir_size(&e.path()),
+                    Ok(m) => m.len(),
+                    Err(_) => 0,
+                })
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
 pub fn criterion_benchmark(c: &mut Criterion) {
     let mut world = World::new();
     world.rule_victory = RuleVictory::Bench;
@@ -59,6 +108,81 @@ pub fn criterion_benchmark(c: &mut Criterion) {
         });
     }
 
+    let samples = sample_states(&*world, &[Context::default()], 1024, 0);
+    let codecs = [StateCodec::rmp(), StateCodec::train(&samples).unwrap()];
+    for codec in &codecs {
+        let encoded: Vec<Vec<u8>> = samples.iter().map(|s| codec.encode(s).unwrap()).collect();
+        println!(
+            "{:?} encoding: {:.1} bytes per state",
+            codec.encoding(),
+            encoded.iter().map(Vec::len).sum::<usize>() as f64 / encoded.len() as f64
+        );
+        c.bench_function(&format!("encode states {:?}", codec.encoding()), |b| {
+            b.iter(|| {
+                for s in &samples {
+                    black_box(codec.encode(s).unwrap());
+                }
+            })
+        });
+        c.bench_function(&format!("decode states {:?}", codec.encoding()), |b| {
+            b.iter(|| {
+                for e in &encoded {
+                    black_box(codec.decode::<Context>(e).unwrap());
+                }
+            })
+        });
+    }
+
+    // Measures the state dbs as the search uses them, so encoding costs show up alongside the
+    // savings in db reads and writes.
+    for encoding in [StateEncoding::Rmp, StateEncoding::ZstdDict] {
+        let record = make_codec(&*world, &[Context::default()], encoding, None)
+            .unwrap()
+            .record()
+            .clone();
+        let db_path = std::env::temp_dir().join(format!("axiom_verge2-bench-{:?}", encoding));
+        let open_queue = || {
+            BenchQueue::new(
+                &db_path,
+                &*world,
+                TimeSinceAndElapsed::new(&*world, ctx.get()),
+                u32::MAX,
+                BENCH_QUEUE_MEMORY,
+                true,
+                StateCodec::from_record(record.clone()),
+                false,
+                &DbProfile::default(),
+            )
+            .unwrap()
+        };
+
+        let queue = open_queue();
+        search_steps(&*world, &queue, ctx.get(), BENCH_SEARCH_STEPS);
+        let states = queue.db().seen();
+        drop(queue);
+        let size = dir_size(&db_path);
+        println!(
+            "{:?} encoding: {} bytes on disk for {} states ({:.1} bytes per state)",
+            encoding,
+            size,
+            states,
+            size as f64 / states.max(1) as f64
+        );
+
+        c.bench_function(&format!("search steps {:?}", encoding), |b| {
+            b.iter_batched(
+                open_queue,
+                |queue| {
+                    search_steps(&*world, &queue, ctx.get(), BENCH_SEARCH_STEPS);
+                    // Returned so the db is closed outside the measurement.
+                    queue
+                },
+                BatchSize::PerIteration,
+            )
+        });
+        let _ = std::fs::remove_dir_all(&db_path);
+    }
+
     if let Ok(win) = greedy_search(&*world, &ctx, u32::MAX, 2) {
         let sol = Arc::new(Solution {
             elapsed: win.elapsed(),
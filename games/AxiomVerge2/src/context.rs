@@ -20,7 +20,7 @@ use yaml_rust::Yaml;
 
 pub mod enums {
 use std::fmt;
-#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Ord, PartialOrd, Default, serde_repr::Serialize_repr, serde_repr::Deserialize_repr)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Ord, PartialOrd, enum_map::Enum, Default, serde_repr::Serialize_repr, serde_repr::Deserialize_repr)]
 #[repr(u8)]
 pub enum Mode {
     #[default]
@@ -47,7 +47,7 @@ impl std::str::FromStr for Mode {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Ord, PartialOrd, Default, serde_repr::Serialize_repr, serde_repr::Deserialize_repr)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Ord, PartialOrd, enum_map::Enum, Default, serde_repr::Serialize_repr, serde_repr::Deserialize_repr)]
 #[repr(u8)]
 pub enum Realm {
     #[default]
@@ -10267,6 +10267,131 @@ impl context::Ctx for Context {
         Ok(())
     }
 
+    // soundness helper for context vars
+    fn mutate_context(&mut self, field: usize, value: u64) {
+        match field % 118 {
+            0 => self.set_energy(value as i16),
+            1 => self.set_flasks(value as i8),
+            2 => self.set_refills(value as i8),
+            3 => self.set_mode(<enums::Mode as enum_map::Enum>::from_usize(value as usize % <enums::Mode as enum_map::Enum>::LENGTH)),
+            4 => self.set_save(<SpotId as enum_map::Enum>::from_usize(value as usize % <SpotId as enum_map::Enum>::LENGTH)),
+            5 => self.set_breach_save(<SpotId as enum_map::Enum>::from_usize(value as usize % <SpotId as enum_map::Enum>::LENGTH)),
+            6 => self.set_indra(<SpotId as enum_map::Enum>::from_usize(value as usize % <SpotId as enum_map::Enum>::LENGTH)),
+            7 => self.set_last(<SpotId as enum_map::Enum>::from_usize(value as usize % <SpotId as enum_map::Enum>::LENGTH)),
+            8 => self.set_portal(<SpotId as enum_map::Enum>::from_usize(value as usize % <SpotId as enum_map::Enum>::LENGTH)),
+            9 => self.set_prev_portal(<SpotId as enum_map::Enum>::from_usize(value as usize % <SpotId as enum_map::Enum>::LENGTH)),
+            10 => self.set_prev_area(<AreaId as enum_map::Enum>::from_usize(value as usize % <AreaId as enum_map::Enum>::LENGTH)),
+            11 => self.set_map__amagi_breach__east_entrance__save(value & 1 == 1),
+            12 => self.set_map__amagi_breach__east_ruins__save(value & 1 == 1),
+            13 => self.set_map__amagi_breach__upper_lake__save(value & 1 == 1),
+            14 => self.set_map__amagi_breach__divided__save(value & 1 == 1),
+            15 => self.set_map__amagi__main_area__save(value & 1 == 1),
+            16 => self.set_map__amagi__east_lake__save(value & 1 == 1),
+            17 => self.set_map__annuna_breach__double_corridor__save(value & 1 == 1),
+            18 => self.set_map__annuna_breach__rear_entrance__save(value & 1 == 1),
+            19 => self.set_map__annuna_breach__north_hallway__save(value & 1 == 1),
+            20 => self.set_map__annuna_breach__bridge__save(value & 1 == 1),
+            21 => self.set_map__annuna_breach__bottom__save(value & 1 == 1),
+            22 => self.set_map__annuna_breach__enclosed__save(value & 1 == 1),
+            23 => self.set_map__annuna__mirror_match__save(value & 1 == 1),
+            24 => self.set_map__annuna__vertical_room__save(value & 1 == 1),
+            25 => self.set_map__annuna__factory_entrance__save(value & 1 == 1),
+            26 => self.set_map__annuna__upper_save__save(value & 1 == 1),
+            27 => self.set_map__annuna__center_save__save(value & 1 == 1),
+            28 => self.set_map__ebih_breach__portals_101__save(value & 1 == 1),
+            29 => self.set_map__ebih_breach__in_n_out__save(value & 1 == 1),
+            30 => self.set_map__ebih_breach__rock_block__save(value & 1 == 1),
+            31 => self.set_map__ebih_breach__peak__save(value & 1 == 1),
+            32 => self.set_map__ebih__base_camp__save(value & 1 == 1),
+            33 => self.set_map__ebih__ebih_west__mid_save(value & 1 == 1),
+            34 => self.set_map__ebih__ebih_west__upper_save(value & 1 == 1),
+            35 => self.set_map__ebih__ebih_west__lower_save(value & 1 == 1),
+            36 => self.set_map__ebih__hidden_portal__save(value & 1 == 1),
+            37 => self.set_map__emergence__camp_exterior__save(value & 1 == 1),
+            38 => self.set_map__giguna_breach__peak__save(value & 1 == 1),
+            39 => self.set_map__giguna_breach__sw_save__save(value & 1 == 1),
+            40 => self.set_map__giguna_breach__labyrinth__save(value & 1 == 1),
+            41 => self.set_map__giguna_breach__east__save(value & 1 == 1),
+            42 => self.set_map__giguna_breach__south__save(value & 1 == 1),
+            43 => self.set_map__giguna__giguna_northeast__save(value & 1 == 1),
+            44 => self.set_map__giguna__giguna_base__save(value & 1 == 1),
+            45 => self.set_map__giguna__ruins_west__save(value & 1 == 1),
+            46 => self.set_map__giguna__ruins_top__save(value & 1 == 1),
+            47 => self.set_map__giguna__labyrinth__save(value & 1 == 1),
+            48 => self.set_map__giguna__separator__save(value & 1 == 1),
+            49 => self.set_map__glacier_breach__south_save__save(value & 1 == 1),
+            50 => self.set_map__glacier_breach__west_save__save(value & 1 == 1),
+            51 => self.set_map__glacier_breach__guarded_corridor__save(value & 1 == 1),
+            52 => self.set_map__glacier_breach__save_and_exit__save(value & 1 == 1),
+            53 => self.set_map__glacier_breach__hammonds_breach__save(value & 1 == 1),
+            54 => self.set_map__glacier__revival__save(value & 1 == 1),
+            55 => self.set_map__irikar_breach__save_room__save(value & 1 == 1),
+            56 => self.set_map__irikar_breach__gauntlet__save(value & 1 == 1),
+            57 => self.set_map__irikar_breach__basement_save__save(value & 1 == 1),
+            58 => self.set_map__irikar_breach__corridor__save(value & 1 == 1),
+            59 => self.set_map__irikar__hub__save(value & 1 == 1),
+            60 => self.set_map__irikar__midwest__save(value & 1 == 1),
+            61 => self.set_map__irikar__beach_save__save(value & 1 == 1),
+            62 => self.set_map__uhrum_breach__save_and_go__save(value & 1 == 1),
+            63 => self.set_map__uhrum_breach__east_glitch__save(value & 1 == 1),
+            64 => self.set_map__uhrum_breach__annuna_gate__save(value & 1 == 1),
+            65 => self.set_map__uhrum_breach__locked_down__save(value & 1 == 1),
+            66 => self.set_map__uhrum__west_entrance__save(value & 1 == 1),
+            67 => self.set_map__uhrum__save_room__save(value & 1 == 1),
+            68 => self.set_map__uhrum__annuna_corridor__save(value & 1 == 1),
+            69 => self.set_map__uhrum__emergence_save__save(value & 1 == 1),
+            70 => self.set_glacier__ctx__hammonds_doors(value & 1 == 1),
+            71 => self.set_amagi__main_area__ctx__combo(value & 1 == 1),
+            72 => self.set_amagi__secret_chamber__ctx__west_dur_esla(value & 1 == 1),
+            73 => self.set_amagi__secret_chamber__ctx__east_dur_esla(value & 1 == 1),
+            74 => self.set_amagi__gated_community__ctx__dur_esla(value & 1 == 1),
+            75 => self.set_annuna__invisible_enemies__ctx__door_opened(value & 1 == 1),
+            76 => self.set_annuna__invisible_enemies__ctx__nw_utu(value & 1 == 1),
+            77 => self.set_annuna__west_bridge__ctx__doors_opened(value & 1 == 1),
+            78 => self.set_annuna__east_bridge__ctx__combo(value & 1 == 1),
+            79 => self.set_annuna__vertical_room__ctx__door_opened(value & 1 == 1),
+            80 => self.set_annuna__udug_gate__ctx__door(value & 1 == 1),
+            81 => self.set_annuna__west_climb__ctx__door_opened(value & 1 == 1),
+            82 => self.set_annuna__boss_gate__ctx__door(value & 1 == 1),
+            83 => self.set_ebih__base_camp__ctx__left_platform_moved(value & 1 == 1),
+            84 => self.set_ebih__truck_gate__ctx__door_open(value & 1 == 1),
+            85 => self.set_ebih__grid_25_10_12__ctx__door_open(value & 1 == 1),
+            86 => self.set_ebih__waterfall__ctx__west_door_open(value & 1 == 1),
+            87 => self.set_ebih__waterfall__ctx__east_door_open(value & 1 == 1),
+            88 => self.set_ebih__ebih_west__ctx__door_open(value & 1 == 1),
+            89 => self.set_ebih__ebih_east__ctx__platform1_moved(value & 1 == 1),
+            90 => self.set_ebih__ebih_east__ctx__platform2_moved(value & 1 == 1),
+            91 => self.set_ebih__drone_room__ctx__platform_moved(value & 1 == 1),
+            92 => self.set_ebih__vertical_interchange__ctx__door_open(value & 1 == 1),
+            93 => self.set_emergence__storage__ctx__door_open(value & 1 == 1),
+            94 => self.set_giguna_breach__sw_save__ctx__door_opened(value & 1 == 1),
+            95 => self.set_giguna__giguna_northeast__ctx__door_opened(value & 1 == 1),
+            96 => self.set_giguna__carnelian__ctx__door_opened(value & 1 == 1),
+            97 => self.set_giguna__carnelian__ctx__upper_susar(value & 1 == 1),
+            98 => self.set_giguna__carnelian__ctx__lower_susar(value & 1 == 1),
+            99 => self.set_giguna__west_caverns__ctx__east_susar(value & 1 == 1),
+            100 => self.set_giguna__giguna_base__ctx__door_open(value & 1 == 1),
+            101 => self.set_giguna__ruins_west__ctx__kishib_handled(value & 1 == 1),
+            102 => self.set_giguna__ruins_top__ctx__doors_open(value & 1 == 1),
+            103 => self.set_giguna__clouds__ctx__platform(value & 1 == 1),
+            104 => self.set_giguna__east_caverns__ctx__door_opened(value & 1 == 1),
+            105 => self.set_giguna__east_caverns__ctx__combo_entered(value & 1 == 1),
+            106 => self.set_giguna__east_caverns__ctx__upper_susar(value & 1 == 1),
+            107 => self.set_giguna__east_caverns__ctx__mid_susar(value & 1 == 1),
+            108 => self.set_giguna__east_caverns__ctx__lower_susar(value & 1 == 1),
+            109 => self.set_giguna__gateway__ctx__door_opened(value & 1 == 1),
+            110 => self.set_giguna__labyrinth__ctx__door(value & 1 == 1),
+            111 => self.set_glacier__the_big_drop__ctx__bridge_open(value & 1 == 1),
+            112 => self.set_glacier__vertical_room__ctx__upper_gatestone(value & 1 == 1),
+            113 => self.set_glacier__vertical_room__ctx__lower_gatestones(value & 1 == 1),
+            114 => self.set_glacier__lonely_bull__ctx__door(value & 1 == 1),
+            115 => self.set_irikar__basement_portal__ctx__platform_moved(value & 1 == 1),
+            116 => self.set_irikar__midwest__ctx__left_platform(value & 1 == 1),
+            117 => self.set_irikar__midwest__ctx__right_platform(value & 1 == 1),
+            _ => unreachable!(),
+        }
+    }
+
     fn parse_expect_context(ckey: &str, cval: &Yaml) -> Result<Expectation, String> {
         Ok(match (ckey, cval) {
             ("position", Yaml::String(s)) => Expectation::Position(SpotId::from_str(s).map_err(|e| format!("{}", e))?),
@@ -31748,6 +31873,37 @@ fn observe_reset_area(&mut self, area_id: AreaId, world: &World, full_obs: &mut
         }
     }
 
+    fn dominates(&self, other: &Context) -> bool {
+        self.position == other.position
+            && self.energy >= other.energy
+            && self.flasks >= other.flasks
+            && self.refills >= other.refills
+            && self.mode == other.mode
+            && self.save == other.save
+            && self.breach_save == other.breach_save
+            && self.indra == other.indra
+            && self.last == other.last
+            && self.portal == other.portal
+            && self.prev_portal == other.prev_portal
+            && self.prev_area == other.prev_area
+            && self.big_flask == other.big_flask
+            && self.flask == other.flask
+            && self.health_fragment == other.health_fragment
+            && self.health_node == other.health_node
+            && self.power_matrix == other.power_matrix
+            && self.cbits1 == other.cbits1
+            && self.cbits2 == other.cbits2
+            && self.cbits3 == other.cbits3
+            && self.cbits4 == other.cbits4
+            && self.cbits5 == other.cbits5
+            && self.cbits6 == other.cbits6
+            && self.cbits7 == other.cbits7
+            && self.cbits8 == other.cbits8
+            && self.cbits9 == other.cbits9
+            && self.cbits10 == other.cbits10
+            && self.cbits11 == other.cbits11
+    }
+
     fn diff(&self, old: &Context) -> String {
         let mut list: Vec<String> = Vec::new();
 
@@ -2261,6 +2261,12 @@ impl world::Action for Action {
             _ => SpotId::None,
         }
     }
+    fn dest_from_context(&self) -> bool {
+        match self.id {
+            ActionId::Global__Recall_Drone => true,
+            _ => false,
+        }
+    }
     fn observe_effects(&self, ctx: &mut Context, world: &World, full_obs: &mut FullObservation) {
         match self.id {
             ActionId::Global__Recall_Drone => {
@@ -10,6 +10,7 @@ use crate::items::*;
 use crate::movements;
 use analyzer::condense::{condense_graph, CondensedEdge};
 use analyzer::context::Ctx;
+use analyzer::goal::Goal;
 use analyzer::world;
 use analyzer::world::Location as _;
 use enum_map::{enum_map, Enum, EnumMap};
@@ -5444,6 +5445,8 @@ lazy_static! {
 pub struct World {
     pub rule_victory: RuleVictory,
     pub rule_objective: RuleObjective,
+    // runtime goal from the settings file, replacing the victory rules
+    pub goal: Option<Goal<World>>,
     // settings
     pub boomerang_steering: bool,
     pub major_glitches: bool,
@@ -5484,6 +5487,7 @@ impl world::World for World {
 
         world.rule_victory = RuleVictory::default();
         world.rule_objective = RuleObjective::default();
+        world.goal = None;
         world.boomerang_steering = Default::default();
         world.major_glitches = Default::default();
         world.minor_glitches = Default::default();
@@ -5508,6 +5512,9 @@ impl world::World for World {
     }
 
     fn ruleset(&self) -> String {
+        if let Some(goal) = &self.goal {
+            return format!("[goal: {}]", goal);
+        }
         format!(
             "[$victory: {}, $objective: {}]",
             self.rule_victory,
@@ -5777,6 +5784,9 @@ impl world::World for World {
     fn same_area(sp1: SpotId, sp2: SpotId) -> bool {
         get_area(sp1) == get_area(sp2)
     }
+    fn get_spot_region(spot_id: SpotId) -> RegionId {
+        get_region(spot_id)
+    }
     fn get_area_spots(&self, spot_id: SpotId) -> &[SpotId] {
         if spot_id == SpotId::None {
             &RAW_SPOTS[0..0]
@@ -5817,10 +5827,20 @@ impl world::World for World {
     }
 
     fn won(&self, ctx: &Context) -> bool {
+        if let Some(goal) = &self.goal {
+            return goal.met(ctx);
+        }
         crate::rule__victory!(ctx, self)
     }
 
+    fn goal(&self) -> Option<&Goal<World>> {
+        self.goal.as_ref()
+    }
+
     fn items_needed(&self, ctx: &Context) -> Vec<(Item, i16)> {
+        if let Some(goal) = &self.goal {
+            return goal.items_needed(ctx);
+        }
         let mut map = FxHashMap::default();
 
         match self.rule_victory {
@@ -6894,6 +6914,9 @@ impl world::World for World {
     }
 
     fn required_items(&self) -> Vec<(Item, i16)> {
+        if let Some(goal) = &self.goal {
+            return goal.items.clone();
+        }
         let mut map = FxHashMap::default();
 
         match self.rule_victory {
@@ -14772,6 +14795,10 @@ impl world::World for World {
         spot_distance_or_inf(a, b)
     }
 
+    fn spot_coordinate(spot: SpotId) -> Option<(f32, f32)> {
+        coordinate(spot).ok()
+    }
+
     fn spot_of_interest(&self, sp: SpotId) -> bool {
         match sp {
             SpotId::Amagi__Divided_Room__Column_East | SpotId::Amagi__Divided_Room__Column_West | SpotId::Amagi__Divided_Room__East | SpotId::Amagi__Divided_Room__Portal_East | SpotId::Amagi__Divided_Room__Portal_East_Mid_air | SpotId::Amagi__Divided_Room__Portal_Stand | SpotId::Amagi__Divided_Room__Portal_West | SpotId::Amagi__Divided_Room__Upper_Left_Corner | SpotId::Amagi__Divided_Room__Upper_Right_Corner | SpotId::Amagi__Divided_Room__West | SpotId::Amagi__East_Lake__Arch_East | SpotId::Amagi__East_Lake__Arch_West | SpotId::Amagi__East_Lake__Center_Above | SpotId::Amagi__East_Lake__Center_Northeast_Platform | SpotId::Amagi__East_Lake__Center_Upper_Platform | SpotId::Amagi__East_Lake__Center_West_Platform | SpotId::Amagi__East_Lake__East_15_Flat | SpotId::Amagi__East_Lake__East_15_Lower | SpotId::Amagi__East_Lake__East_15_Lower_Hover | SpotId::Amagi__East_Lake__East_15_Upper_Hover | SpotId::Amagi__East_Lake__Far_West_Above | SpotId::Amagi__East_Lake__Foot | SpotId::Amagi__East_Lake__Portal_Stand | SpotId::Amagi__East_Lake__Save_Point | SpotId::Amagi__East_Lake__Southeast_Column | SpotId::Amagi__East_Lake__Southwest_Column_East | SpotId::Amagi__East_Lake__Southwest_Column_West | SpotId::Amagi__East_Lake__West_17 | SpotId::Amagi__East_Lake__West_18 | SpotId::Amagi__Gated_Community__Button | SpotId::Amagi__Gated_Community__Dur_Esla | SpotId::Amagi__Gated_Community__East_17 | SpotId::Amagi__Gated_Community__East_18 | SpotId::Amagi__Gated_Community__Lower_Gate_West | SpotId::Amagi__Gated_Community__Platform_1_West | SpotId::Amagi__Gated_Community__Platform_2_West | SpotId::Amagi__Gated_Community__Upper_East_Ledge | SpotId::Amagi__Gated_Community__Upper_Gate_East_Mid_air | SpotId::Amagi__Gated_Community__Upper_Gate_West | SpotId::Amagi__Gated_Community__Upper_West_Hook_Point | SpotId::Amagi__Gated_Community__West_16 | SpotId::Amagi__Gated_Community__West_18 | SpotId::Amagi__Grid_31_19__Center | SpotId::Amagi__Grid_31_19__East | SpotId::Amagi__Grid_31_19__West | SpotId::Amagi__Isolation__Empty_Plinth | SpotId::Amagi__Isolation__Midway | SpotId::Amagi__Isolation__Portal_Hole | SpotId::Amagi__Isolation__Save_Point | SpotId::Amagi__Liru_Room__Hidden_Exit | SpotId::Amagi__Liru_Room__Shrine | SpotId::Amagi__Liru_Room__West_19 | SpotId::Amagi__Liru_Room__West_20 | SpotId::Amagi__Main_Area__Broken_Wall | SpotId::Amagi__Main_Area__Cache | SpotId::Amagi__Main_Area__Cache_Jump | SpotId::Amagi__Main_Area__Carving | SpotId::Amagi__Main_Area__Catwalk_Center | SpotId::Amagi__Main_Area__Catwalk_Far_East | SpotId::Amagi__Main_Area__East_15 | SpotId::Amagi__Main_Area__East_16 | SpotId::Amagi__Main_Area__East_18 | SpotId::Amagi__Main_Area__East_19 | SpotId::Amagi__Main_Area__East_Ledge | SpotId::Amagi__Main_Area__Platform_2 | SpotId::Amagi__Main_Area__Platform_3 | SpotId::Amagi__Main_Area__Save_Point | SpotId::Amagi__Main_Area__Secret_Outcropping | SpotId::Amagi__Main_Area__Secret_Waterfall | SpotId::Amagi__Main_Area__Wall_Stuck_Spot | SpotId::Amagi__Main_Area__Way_Off_To_The_Side | SpotId::Amagi__Main_Area__West_15 | SpotId::Amagi__Main_Area__West_18 | SpotId::Amagi__Main_Area__West_18_Hook_Point | SpotId::Amagi__Main_Area__West_19 | SpotId::Amagi__Main_Area__West_Side | SpotId::Amagi__Secret_Chamber__Column | SpotId::Amagi__Secret_Chamber__East_Dur_Esla | SpotId::Amagi__Secret_Chamber__Enclosed_Cache | SpotId::Amagi__Secret_Chamber__Middle_Ledge | SpotId::Amagi__Secret_Chamber__Portal_Wall | SpotId::Amagi__Secret_Chamber__Upper_Cache | SpotId::Amagi__Secret_Chamber__Upper_Ledge | SpotId::Amagi__Secret_Chamber__West | SpotId::Amagi__Secret_Chamber__West_Dur_Esla | SpotId::Amagi__West_Lake__Cavern_Eye | SpotId::Amagi__West_Lake__Cavern_Rear_Pillar | SpotId::Amagi__West_Lake__Cavern_Refill_Station | SpotId::Amagi__West_Lake__Cavern_Tear_Duct | SpotId::Amagi__West_Lake__East_15 | SpotId::Amagi__West_Lake__East_18 | SpotId::Amagi__West_Lake__East_19 | SpotId::Amagi__West_Lake__East_20 | SpotId::Amagi__West_Lake__East_Platform | SpotId::Amagi__West_Lake__East_Shore | SpotId::Amagi__West_Lake__Mid_water_Above_Gap | SpotId::Amagi__West_Lake__Stronghold_Ceiling_Left | SpotId::Amagi__West_Lake__Stronghold_Ceiling_Right | SpotId::Amagi__West_Lake__Stronghold_Front_Door | SpotId::Amagi__West_Lake__Stronghold_Front_Room | SpotId::Amagi__West_Lake__Stronghold_Item | SpotId::Amagi__West_Lake__Stronghold_Middle_Column | SpotId::Amagi__West_Lake__Stronghold_Rear_Wall | SpotId::Amagi__West_Lake__Stronghold_Top | SpotId::Amagi__West_Lake__Surface_Wall_Left | SpotId::Amagi__West_Lake__Surface_Wall_Right | SpotId::Amagi__West_Lake__West_15 | SpotId::Amagi__West_Lake__West_20 | SpotId::Amagi__West_Lake__West_Stronghold_Button | SpotId::Amagi__West_Lake__West_Stronghold_Wall | SpotId::Amagi__Wiggly_Room__Button | SpotId::Amagi__Wiggly_Room__Cache | SpotId::Amagi__Wiggly_Room__East | SpotId::Amagi__Wiggly_Room__East_Bottom | SpotId::Amagi__Wiggly_Room__East_Chamber | SpotId::Amagi__Wiggly_Room__Gate_East | SpotId::Amagi__Wiggly_Room__Portal_Hole | SpotId::Amagi__Wiggly_Room__South | SpotId::Amagi__Wiggly_Room__West | SpotId::Amagi_Breach__Center_Ruins__Cache | SpotId::Amagi_Breach__Center_Ruins__East_18 | SpotId::Amagi_Breach__Center_Ruins__East_19 | SpotId::Amagi_Breach__Center_Ruins__Passage_Entrance | SpotId::Amagi_Breach__Center_Ruins__Passage_Exit | SpotId::Amagi_Breach__Center_Ruins__South | SpotId::Amagi_Breach__Center_Ruins__West | SpotId::Amagi_Breach__Corner__East | SpotId::Amagi_Breach__Corner__North | SpotId::Amagi_Breach__Divided__Empty_Plinth | SpotId::Amagi_Breach__Divided__North | SpotId::Amagi_Breach__Divided__Portal_Hole | SpotId::Amagi_Breach__Divided__Save_Point | SpotId::Amagi_Breach__Divided__South | SpotId::Amagi_Breach__Divided__Wall_East | SpotId::Amagi_Breach__East_Column__East | SpotId::Amagi_Breach__East_Column__South | SpotId::Amagi_Breach__East_Connector__Center_Left | SpotId::Amagi_Breach__East_Connector__Center_Right | SpotId::Amagi_Breach__East_Connector__East | SpotId::Amagi_Breach__East_Connector__Outcrop | SpotId::Amagi_Breach__East_Connector__West | SpotId::Amagi_Breach__East_Entrance__East | SpotId::Amagi_Breach__East_Entrance__Grate_Right | SpotId::Amagi_Breach__East_Entrance__Save_Point | SpotId::Amagi_Breach__East_Entrance__Upper_Slope | SpotId::Amagi_Breach__East_Entrance__West | SpotId::Amagi_Breach__East_Ruins__Arch_East | SpotId::Amagi_Breach__East_Ruins__Arch_West | SpotId::Amagi_Breach__East_Ruins__Northeast_Bubbles_Corner_Access | SpotId::Amagi_Breach__East_Ruins__Northeast_Corner | SpotId::Amagi_Breach__East_Ruins__Plinth | SpotId::Amagi_Breach__East_Ruins__Save_Point | SpotId::Amagi_Breach__East_Ruins__Small_Column_East | SpotId::Amagi_Breach__East_Ruins__Small_Column_West | SpotId::Amagi_Breach__East_Ruins__South | SpotId::Amagi_Breach__East_Ruins__Southeast_Rock_Wall | SpotId::Amagi_Breach__East_Ruins__West | SpotId::Amagi_Breach__East_Ruins__West_Bubbly_Pillar | SpotId::Amagi_Breach__Fishy_Secrets__Center_Column | SpotId::Amagi_Breach__Fishy_Secrets__East_Corner | SpotId::Amagi_Breach__Fishy_Secrets__East_Pentomino | SpotId::Amagi_Breach__Fishy_Secrets__North | SpotId::Amagi_Breach__Fishy_Secrets__Northeast_Corner | SpotId::Amagi_Breach__Fishy_Secrets__Shaft_Bottom | SpotId::Amagi_Breach__Fishy_Secrets__South | SpotId::Amagi_Breach__Fishy_Secrets__West | SpotId::Amagi_Breach__Lakebed__Big_Rock_West | SpotId::Amagi_Breach__Lakebed__Bottom | SpotId::Amagi_Breach__Lakebed__East_Rock_Middle | SpotId::Amagi_Breach__Lakebed__Lower_East_Ledge | SpotId::Amagi_Breach__Lakebed__Save_Point | SpotId::Amagi_Breach__Lakebed__West | SpotId::Amagi_Breach__Lower_Hallway__Cache | SpotId::Amagi_Breach__Lower_Hallway__East | SpotId::Amagi_Breach__Lower_Hallway__East_Ledge | SpotId::Amagi_Breach__Lower_Hallway__Middle_Bubbles | SpotId::Amagi_Breach__Lower_Hallway__West | SpotId::Amagi_Breach__Lower_Hallway__West_Ledge | SpotId::Amagi_Breach__Split_One_Way__Button | SpotId::Amagi_Breach__Split_One_Way__East | SpotId::Amagi_Breach__Split_One_Way__West_19 | SpotId::Amagi_Breach__Split_One_Way__West_20 | SpotId::Amagi_Breach__Twisty_Passages__Closed_Chamber | SpotId::Amagi_Breach__Twisty_Passages__East | SpotId::Amagi_Breach__Twisty_Passages__East_Bottom | SpotId::Amagi_Breach__Twisty_Passages__North | SpotId::Amagi_Breach__Twisty_Passages__South | SpotId::Amagi_Breach__Twisty_Passages__West_Flora | SpotId::Amagi_Breach__Upper_Hallway__Column_West | SpotId::Amagi_Breach__Upper_Hallway__East | SpotId::Amagi_Breach__Upper_Hallway__North | SpotId::Amagi_Breach__Upper_Hallway__West | SpotId::Amagi_Breach__Upper_Hallway__West_Hook_Point | SpotId::Amagi_Breach__Upper_Lake__Center_of_Platform | SpotId::Amagi_Breach__Upper_Lake__Column | SpotId::Amagi_Breach__Upper_Lake__Middle | SpotId::Amagi_Breach__Upper_Lake__Portal_Stand | SpotId::Amagi_Breach__Upper_Lake__Save_Point | SpotId::Amagi_Breach__West_Entrance__North | SpotId::Amagi_Breach__West_Entrance__South | SpotId::Amagi_Breach__West_Ruins__Button | SpotId::Amagi_Breach__West_Ruins__Center_Platform | SpotId::Amagi_Breach__West_Ruins__East | SpotId::Amagi_Breach__West_Ruins__East_Platform | SpotId::Annuna__Apocalypse__Center_Scaffold_West | SpotId::Annuna__Apocalypse__West | SpotId::Annuna__Apocalypse_Hallway__Lower_East | SpotId::Annuna__Apocalypse_Hallway__Upper_East | SpotId::Annuna__Apocalypse_Hallway__West | SpotId::Annuna__Boss_Gate__Door_East | SpotId::Annuna__Boss_Gate__East | SpotId::Annuna__Boss_Gate__North_Mid_air | SpotId::Annuna__Boss_Gate__Portal_Stand | SpotId::Annuna__Boss_Gate__Switch | SpotId::Annuna__Center_Climb__East | SpotId::Annuna__Center_Climb__West | SpotId::Annuna__Center_Save__East | SpotId::Annuna__Center_Save__Save_Point | SpotId::Annuna__Center_Save__West_Catwalk | SpotId::Annuna__Center_Save__West_Floor | SpotId::Annuna__Double_Trouble__East_Side_Lower | SpotId::Annuna__Double_Trouble__West | SpotId::Annuna__East_Bridge__Basement_Corridor | SpotId::Annuna__East_Bridge__Below_Cavern_West | SpotId::Annuna__East_Bridge__Below_Gate_Button | SpotId::Annuna__East_Bridge__Cavern_Cache | SpotId::Annuna__East_Bridge__Cavern_Foyer | SpotId::Annuna__East_Bridge__Center_Corridor | SpotId::Annuna__East_Bridge__Center_Gap_East | SpotId::Annuna__East_Bridge__Center_Gap_West | SpotId::Annuna__East_Bridge__East_22 | SpotId::Annuna__East_Bridge__East_23 | SpotId::Annuna__East_Bridge__East_24 | SpotId::Annuna__East_Bridge__East_25_Lower | SpotId::Annuna__East_Bridge__East_25_Upper | SpotId::Annuna__East_Bridge__East_27 | SpotId::Annuna__East_Bridge__Flung_West | SpotId::Annuna__East_Bridge__Gate_Button | SpotId::Annuna__East_Bridge__Lower_East_Ledge | SpotId::Annuna__East_Bridge__Lower_Gate_East | SpotId::Annuna__East_Bridge__Tower_East_Ledge | SpotId::Annuna__East_Bridge__Tower_Gate | SpotId::Annuna__East_Bridge__Tower_Mid_air_East | SpotId::Annuna__East_Bridge__Tower_Mid_air_West | SpotId::Annuna__East_Bridge__Tower_Opening | SpotId::Annuna__East_Bridge__Tower_Secret | SpotId::Annuna__East_Bridge__Tower_West_Ledge | SpotId::Annuna__East_Bridge__Upper_West_Gap | SpotId::Annuna__East_Bridge__West_24 | SpotId::Annuna__East_Bridge__West_25_Lower | SpotId::Annuna__East_Bridge__West_26 | SpotId::Annuna__East_Bridge__West_28_Lower | SpotId::Annuna__East_Bridge__West_28_Upper | SpotId::Annuna__East_Bridge__West_Under_Gap | SpotId::Annuna__East_Climb__West_24 | SpotId::Annuna__East_Climb__West_25 | SpotId::Annuna__East_Climb__West_26 | SpotId::Annuna__East_Hideout__Bottom | SpotId::Annuna__East_Hideout__Ladder_Middle | SpotId::Annuna__East_Hideout__Left_Mid_air | SpotId::Annuna__East_Hideout__West | SpotId::Annuna__Egg_Room__Cache | SpotId::Annuna__Egg_Room__Corner_Platform | SpotId::Annuna__Egg_Room__East | SpotId::Annuna__Egg_Room__Second_Egg | SpotId::Annuna__Egg_Room__West | SpotId::Annuna__Factory_Access__East_22 | SpotId::Annuna__Factory_Access__East_24 | SpotId::Annuna__Factory_Access__West_22 | SpotId::Annuna__Factory_Entrance__East | SpotId::Annuna__Factory_Entrance__Save_Point | SpotId::Annuna__Factory_Entrance__West | SpotId::Annuna__Filter_Teleporter__Door_Ledge | SpotId::Annuna__Filter_Teleporter__Egg | SpotId::Annuna__Filter_Teleporter__Northeast_Cubby | SpotId::Annuna__Filter_Teleporter__Northeast_Ministair | SpotId::Annuna__Filter_Teleporter__Shaft_Bottom | SpotId::Annuna__Filter_Teleporter__Shaft_Top | SpotId::Annuna__Filter_Teleporter__West_19 | SpotId::Annuna__Filter_Teleporter__West_19_Mid_flight | SpotId::Annuna__Filter_Teleporter__West_21 | SpotId::Annuna__Final_Cache__Cache | SpotId::Annuna__Final_Cache__Ledge | SpotId::Annuna__Final_Cache__West | SpotId::Annuna__Final_Save__East | SpotId::Annuna__Final_Save__Lower_West | SpotId::Annuna__Final_Save__Save_Point | SpotId::Annuna__Final_Save__Upper_West | SpotId::Annuna__Hidden_Portal__East | SpotId::Annuna__Hidden_Portal__Plinth_Left | SpotId::Annuna__Hidden_Portal__Plinth_Right | SpotId::Annuna__Hidden_Portal__Portal_Stand | SpotId::Annuna__Hidden_Portal__West | SpotId::Annuna__Invisible_Enemies__Corner_Cache | SpotId::Annuna__Invisible_Enemies__Door_East | SpotId::Annuna__Invisible_Enemies__East_22 | SpotId::Annuna__Invisible_Enemies__East_23 | SpotId::Annuna__Invisible_Enemies__East_Rock_East | SpotId::Annuna__Invisible_Enemies__East_Rock_Hook_Point | SpotId::Annuna__Invisible_Enemies__Portal_Stand | SpotId::Annuna__Invisible_Enemies__Shock_Distance | SpotId::Annuna__Invisible_Enemies__Switch_Above | SpotId::Annuna__Invisible_Enemies__Switch_East | SpotId::Annuna__Invisible_Enemies__Switch_West | SpotId::Annuna__Invisible_Enemies__West_23 | SpotId::Annuna__Invisible_Enemies__West_Corner | SpotId::Annuna__Lamassu__Bottom_Hill_West | SpotId::Annuna__Lamassu__East_14 | SpotId::Annuna__Lamassu__East_16 | SpotId::Annuna__Lamassu__East_17 | SpotId::Annuna__Lamassu__Portal_Stand | SpotId::Annuna__Lamassu__Upper_Brick_Ledge | SpotId::Annuna__Lamassu__West_15 | SpotId::Annuna__Lamassu__West_17 | SpotId::Annuna__Lower_Hallway__East | SpotId::Annuna__Lower_Hallway__West | SpotId::Annuna__Middle_Hallway__East | SpotId::Annuna__Middle_Hallway__West | SpotId::Annuna__Mirror_Match__Below_Switch | SpotId::Annuna__Mirror_Match__East_24 | SpotId::Annuna__Mirror_Match__East_25_Lower | SpotId::Annuna__Mirror_Match__East_25_Upper | SpotId::Annuna__Mirror_Match__East_26_Lower | SpotId::Annuna__Mirror_Match__East_26_Upper | SpotId::Annuna__Mirror_Match__Middle_Rock_East | SpotId::Annuna__Mirror_Match__Plinth | SpotId::Annuna__Mirror_Match__Plinth_Adjacent | SpotId::Annuna__Mirror_Match__Save_Point | SpotId::Annuna__Mirror_Match__Upper_Floor_East | SpotId::Annuna__Mirror_Match__Waving_Distance | SpotId::Annuna__Mirror_Match__West_23 | SpotId::Annuna__Mirror_Match__West_25 | SpotId::Annuna__Mirror_Match__West_Gap | SpotId::Annuna__Remote_Training__Button | SpotId::Annuna__Remote_Training__Plinth | SpotId::Annuna__Remote_Training__Upper_Climb | SpotId::Annuna__Remote_Training__Upper_Gate_East | SpotId::Annuna__Remote_Training__West_26 | SpotId::Annuna__Remote_Training__West_27 | SpotId::Annuna__Seals__Breakable_Rock | SpotId::Annuna__Seals__East_15 | SpotId::Annuna__Seals__East_17_Lower | SpotId::Annuna__Seals__East_17_Upper | SpotId::Annuna__Seals__Inner_Wall | SpotId::Annuna__Secret_Passage__East | SpotId::Annuna__Secret_Passage__In_Boome_Range | SpotId::Annuna__Secret_Passage__In_Shock_Range | SpotId::Annuna__Secret_Passage__Wall_East | SpotId::Annuna__Secret_Passage__Wall_West | SpotId::Annuna__Secret_Passage__West | SpotId::Annuna__Siuna_Storage__Cache | SpotId::Annuna__Siuna_Storage__Portal_Entry | SpotId::Annuna__Siuna_Storage__Second_Platform | SpotId::Annuna__Siuna_Storage__Top_Platform | SpotId::Annuna__Siuna_Storage__Wall_Left | SpotId::Annuna__Siuna_Storage__Wall_Right | SpotId::Annuna__Siuna_Storage__West | SpotId::Annuna__Siuna_Storage__Within_Range | SpotId::Annuna__Smiley_Room__Bottom | SpotId::Annuna__Smiley_Room__East_Column_East | SpotId::Annuna__Smiley_Room__East_Column_West | SpotId::Annuna__Smiley_Room__Lower_Portal_Stand | SpotId::Annuna__Smiley_Room__Upper_Portal_Stand | SpotId::Annuna__Smiley_Room__West_Side | SpotId::Annuna__Sniper_Valley__Bridge_End | SpotId::Annuna__Sniper_Valley__Cavern_Cache | SpotId::Annuna__Sniper_Valley__Cavern_Inner_Rock_East | SpotId::Annuna__Sniper_Valley__Cavern_Inner_Rock_West | SpotId::Annuna__Sniper_Valley__Cavern_Outer_Rock_East | SpotId::Annuna__Sniper_Valley__Cavern_Outer_Rock_West | SpotId::Annuna__Sniper_Valley__East | SpotId::Annuna__Sniper_Valley__Table | SpotId::Annuna__Sniper_Valley__West_23 | SpotId::Annuna__Sniper_Valley__West_24 | SpotId::Annuna__Sniper_Valley__West_25_Lower | SpotId::Annuna__Sniper_Valley__West_25_Upper | SpotId::Annuna__Spider_Room__East | SpotId::Annuna__Spider_Room__Healthy_Corner | SpotId::Annuna__Spider_Room__West | SpotId::Annuna__Twisty_Passages__East_Catwalk | SpotId::Annuna__Twisty_Passages__East_Floor | SpotId::Annuna__Twisty_Passages__Northwest_Alcove | SpotId::Annuna__Twisty_Passages__Top | SpotId::Annuna__Twisty_Passages__West | SpotId::Annuna__Udug_Gate__Door_West | SpotId::Annuna__Udug_Gate__East | SpotId::Annuna__Udug_Gate__Switch | SpotId::Annuna__Udug_Gate__West | SpotId::Annuna__Udug_Lair__Covered_Bottom | SpotId::Annuna__Udug_Lair__East | SpotId::Annuna__Udug_Lair__Overhang_Top | SpotId::Annuna__Udug_Lair__Portal_Stand | SpotId::Annuna__Udug_Lair__West_Ditch | SpotId::Annuna__Upper_Hallway__Behind_Pedestal | SpotId::Annuna__Upper_Hallway__Portal_Stand | SpotId::Annuna__Upper_Hallway__Wall | SpotId::Annuna__Upper_Hallway__West | SpotId::Annuna__Upper_Save__East | SpotId::Annuna__Upper_Save__Save_Point | SpotId::Annuna__Upper_Save__West | SpotId::Annuna__Vertical_Room__Door_Switch | SpotId::Annuna__Vertical_Room__East_18 | SpotId::Annuna__Vertical_Room__East_20 | SpotId::Annuna__Vertical_Room__East_21 | SpotId::Annuna__Vertical_Room__East_22 | SpotId::Annuna__Vertical_Room__Gate_Button | SpotId::Annuna__Vertical_Room__Middle_Overhang | SpotId::Annuna__Vertical_Room__Plinth | SpotId::Annuna__Vertical_Room__Save_Point | SpotId::Annuna__Vertical_Room__Save_Point_Left | SpotId::Annuna__Vertical_Room__Upper_Cache | SpotId::Annuna__Vertical_Room__West_17 | SpotId::Annuna__Vertical_Room__West_20 | SpotId::Annuna__Vertical_Room__West_21 | SpotId::Annuna__Vertical_Room__West_22 | SpotId::Annuna__West_Bridge__Below_Tunnel | SpotId::Annuna__West_Bridge__Bridge_Top_East | SpotId::Annuna__West_Bridge__Bridge_Top_West | SpotId::Annuna__West_Bridge__Cavern_Plinth | SpotId::Annuna__West_Bridge__East_24 | SpotId::Annuna__West_Bridge__East_25_Lower | SpotId::Annuna__West_Bridge__East_25_Upper | SpotId::Annuna__West_Bridge__East_26 | SpotId::Annuna__West_Bridge__East_28_Lower | SpotId::Annuna__West_Bridge__East_28_Upper | SpotId::Annuna__West_Bridge__Far_Below_Tunnel_Wall | SpotId::Annuna__West_Bridge__Lower_Brick_Column_East | SpotId::Annuna__West_Bridge__Lower_Brick_Column_West | SpotId::Annuna__West_Bridge__Lower_West_Ledge | SpotId::Annuna__West_Bridge__Lower_West_Mid_air | SpotId::Annuna__West_Bridge__Mid_Tunnel | SpotId::Annuna__West_Bridge__North | SpotId::Annuna__West_Bridge__Plinth | SpotId::Annuna__West_Bridge__Portal_Hole | SpotId::Annuna__West_Bridge__Save_Point | SpotId::Annuna__West_Bridge__Tower_Base_West | SpotId::Annuna__West_Bridge__Tower_East_Peak | SpotId::Annuna__West_Bridge__Tower_Level_4 | SpotId::Annuna__West_Bridge__Tower_Peak | SpotId::Annuna__West_Bridge__Tower_Upper_Middle | SpotId::Annuna__West_Bridge__Tower_Upper_West_Ledge | SpotId::Annuna__West_Bridge__Tower_West_Mid_air | SpotId::Annuna__West_Bridge__Tower_West_Peak | SpotId::Annuna__West_Bridge__Upper_Tier_Middle | SpotId::Annuna__West_Bridge__West_22 | SpotId::Annuna__West_Bridge__West_24 | SpotId::Annuna__West_Bridge__West_25_Lower | SpotId::Annuna__West_Bridge__West_25_Upper | SpotId::Annuna__West_Bridge__West_26_Lower | SpotId::Annuna__West_Bridge__West_26_Upper | SpotId::Annuna__West_Bridge__West_Outcropping | SpotId::Annuna__West_Climb__Cache | SpotId::Annuna__West_Climb__East_19 | SpotId::Annuna__West_Climb__East_20 | SpotId::Annuna__West_Climb__North | SpotId::Annuna__West_Climb__South | SpotId::Annuna__West_Climb__Switch_Ledge | SpotId::Annuna_Breach__Ambushes__West_18 | SpotId::Annuna_Breach__Ambushes__West_19 | SpotId::Annuna_Breach__Bottom__Save_Point | SpotId::Annuna_Breach__Bridge__East | SpotId::Annuna_Breach__Bridge__North | SpotId::Annuna_Breach__Bridge__Northwest_Plinth | SpotId::Annuna_Breach__Bridge__Portal_Stand | SpotId::Annuna_Breach__Bridge__Save_Point | SpotId::Annuna_Breach__Bridge__Tower_Base_West | SpotId::Annuna_Breach__Bridge__Tower_Peak | SpotId::Annuna_Breach__Bridge__Tower_Upper_Interior | SpotId::Annuna_Breach__Bridge__Tower_Upper_West_Ledge | SpotId::Annuna_Breach__Center_Hallway__South | SpotId::Annuna_Breach__Center_Hallway__West | SpotId::Annuna_Breach__Corner__East | SpotId::Annuna_Breach__Corner__Somewhat_Middle_ish | SpotId::Annuna_Breach__Corner__South | SpotId::Annuna_Breach__Depths__North | SpotId::Annuna_Breach__Depths__South | SpotId::Annuna_Breach__Depths__South_Overhang | SpotId::Annuna_Breach__Double_Corridor__Bottom_Left | SpotId::Annuna_Breach__Double_Corridor__Center_Platform_East | SpotId::Annuna_Breach__Double_Corridor__Save_Point | SpotId::Annuna_Breach__Double_Corridor__South | SpotId::Annuna_Breach__Enclosed__Save_Point | SpotId::Annuna_Breach__Factory_Foyer__East | SpotId::Annuna_Breach__Factory_Foyer__West | SpotId::Annuna_Breach__Factory_Foyer__West_Flower | SpotId::Annuna_Breach__Factory_Gate__Button | SpotId::Annuna_Breach__Factory_Gate__East | SpotId::Annuna_Breach__Factory_Gate__West | SpotId::Annuna_Breach__Gate_and_Floor__Button | SpotId::Annuna_Breach__Gate_and_Floor__East_18 | SpotId::Annuna_Breach__Gate_and_Floor__East_19 | SpotId::Annuna_Breach__Gate_and_Floor__East_20 | SpotId::Annuna_Breach__Gate_and_Floor__East_21 | SpotId::Annuna_Breach__Gate_and_Floor__Long_Ledge | SpotId::Annuna_Breach__Gate_and_Floor__Middle_Floor | SpotId::Annuna_Breach__Gate_and_Floor__Northwest | SpotId::Annuna_Breach__Lower_Gate__Button | SpotId::Annuna_Breach__Lower_Gate__Flower_Cache | SpotId::Annuna_Breach__Lower_Gate__North | SpotId::Annuna_Breach__Lower_Gate__South | SpotId::Annuna_Breach__Lower_Gate__West | SpotId::Annuna_Breach__North_Hallway__Save_Point | SpotId::Annuna_Breach__North_Hallway__West | SpotId::Annuna_Breach__Rear_Entrance__East | SpotId::Annuna_Breach__Rear_Entrance__Save_Point | SpotId::Annuna_Breach__Smiley__Column_East | SpotId::Annuna_Breach__Smiley__Drool | SpotId::Annuna_Breach__Smiley__Lower_Portal_Exit | SpotId::Annuna_Breach__Smiley__North | SpotId::Annuna_Breach__Smiley__Northeast_Corner | SpotId::Annuna_Breach__Smiley__Northeast_Ledge | SpotId::Annuna_Breach__Smiley__Upper_Portal_Exit | SpotId::Annuna_Breach__Smiley__West | SpotId::Annuna_Breach__Smiley__West_Ground | SpotId::Annuna_Breach__South_Hallway__North | SpotId::Annuna_Breach__South_Hallway__South | SpotId::Annuna_Breach__Underbelly__East_Ledge | SpotId::Annuna_Breach__Underbelly__North | SpotId::Annuna_Breach__Underbelly__West | SpotId::Annuna_Breach__Underbelly__West_Ledge | SpotId::Antarctica__Building_1E__Connector | SpotId::Antarctica__Building_1E__East_Entry | SpotId::Antarctica__Building_1W__Connector | SpotId::Antarctica__Building_1W__West_Entry | SpotId::Antarctica__Building_2__Behind_Boxes | SpotId::Antarctica__Building_2__Entry | SpotId::Antarctica__Building_2__Upper_Door | SpotId::Antarctica__East__Building_1_Entry | SpotId::Antarctica__East__Building_2_Entry | SpotId::Antarctica__East__Building_2_Upper | SpotId::Antarctica__Freight_Elevator__Controls | SpotId::Antarctica__Freight_Elevator__Left | SpotId::Antarctica__Power_Room__Entry | SpotId::Antarctica__Power_Room__Switch | SpotId::Antarctica__Shed__Interior | SpotId::Antarctica__Top__Power_Entry | SpotId::Antarctica__West__Boxes | SpotId::Antarctica__West__Shed_Entry | SpotId::Ebih__Base_Camp__Building_Entry | SpotId::Ebih__Base_Camp__Bunker_Entry | SpotId::Ebih__Base_Camp__East_11 | SpotId::Ebih__Base_Camp__East_12 | SpotId::Ebih__Base_Camp__Lake_Access | SpotId::Ebih__Base_Camp__Left_Platform | SpotId::Ebih__Base_Camp__Left_Platform_Moved | SpotId::Ebih__Base_Camp__Save_Point | SpotId::Ebih__Base_Camp__Tent_Entry | SpotId::Ebih__Base_Camp__Top_Platform | SpotId::Ebih__Base_Camp__West_11 | SpotId::Ebih__Base_Camp__West_12 | SpotId::Ebih__Base_Camp__West_13 | SpotId::Ebih__Boss_Room__Boss | SpotId::Ebih__Boss_Room__East_4 | SpotId::Ebih__Boss_Room__East_6 | SpotId::Ebih__Boss_Room__East_Ledge | SpotId::Ebih__Boss_Room__West_5 | SpotId::Ebih__Boss_Room__West_6 | SpotId::Ebih__By_Garage__Crawlspace | SpotId::Ebih__By_Garage__Crawlspace_Opening | SpotId::Ebih__By_Garage__East_13 | SpotId::Ebih__By_Garage__Garage_Entry | SpotId::Ebih__By_Garage__West_12 | SpotId::Ebih__By_Garage__West_13 | SpotId::Ebih__By_Garage__West_Bush | SpotId::Ebih__Drone_Pit__Above_Rocks | SpotId::Ebih__Drone_Pit__Bush_Left | SpotId::Ebih__Drone_Pit__East | SpotId::Ebih__Drone_Pit__First_Rocks | SpotId::Ebih__Drone_Pit__Highest_Point | SpotId::Ebih__Drone_Pit__Pit | SpotId::Ebih__Drone_Pit__Second_Rocks | SpotId::Ebih__Drone_Pit__Top | SpotId::Ebih__Drone_Pit__Upper_Mid_way | SpotId::Ebih__Drone_Pit__West_3 | SpotId::Ebih__Drone_Pit__West_4 | SpotId::Ebih__Drone_Room__East_4 | SpotId::Ebih__Drone_Room__Item | SpotId::Ebih__Drone_Room__Middle_Platform | SpotId::Ebih__Drone_Room__Moving_Platform | SpotId::Ebih__Drone_Room__Pit_Left | SpotId::Ebih__Drone_Room__Portal | SpotId::Ebih__Drone_Room__Portal_Exit | SpotId::Ebih__Drone_Room__West_4 | SpotId::Ebih__Drone_Room__West_6 | SpotId::Ebih__Ebih_East__Corner | SpotId::Ebih__Ebih_East__Dispenser | SpotId::Ebih__Ebih_East__East_9 | SpotId::Ebih__Ebih_East__East_Ledge | SpotId::Ebih__Ebih_East__East_Mid_air | SpotId::Ebih__Ebih_East__Lower_Moving_Platform | SpotId::Ebih__Ebih_East__Moving_Platform | SpotId::Ebih__Ebih_East__Wall_East | SpotId::Ebih__Ebih_East__Wall_West | SpotId::Ebih__Ebih_East__West_7 | SpotId::Ebih__Ebih_East__West_8 | SpotId::Ebih__Ebih_East__West_9 | SpotId::Ebih__Ebih_West__Alcove | SpotId::Ebih__Ebih_West__Below_Door | SpotId::Ebih__Ebih_West__Block_Left | SpotId::Ebih__Ebih_West__Block_Right | SpotId::Ebih__Ebih_West__East_10 | SpotId::Ebih__Ebih_West__East_11 | SpotId::Ebih__Ebih_West__East_13 | SpotId::Ebih__Ebih_West__East_6 | SpotId::Ebih__Ebih_West__East_7 | SpotId::Ebih__Ebih_West__East_8 | SpotId::Ebih__Ebih_West__East_9 | SpotId::Ebih__Ebih_West__Giguna_Pillar | SpotId::Ebih__Ebih_West__High_Platform | SpotId::Ebih__Ebih_West__Highest_Platform | SpotId::Ebih__Ebih_West__Left_of_Switch | SpotId::Ebih__Ebih_West__Lower_Save | SpotId::Ebih__Ebih_West__Medium_High_Platform | SpotId::Ebih__Ebih_West__Mid_air_near_Flask | SpotId::Ebih__Ebih_West__Mid_Save | SpotId::Ebih__Ebih_West__Refill_Station | SpotId::Ebih__Ebih_West__Upper_Save | SpotId::Ebih__Ebih_West__West_11 | SpotId::Ebih__Ebih_West__West_12 | SpotId::Ebih__Ebih_West__West_13 | SpotId::Ebih__Gem_Room__Between_Gates | SpotId::Ebih__Gem_Room__Button_Corner | SpotId::Ebih__Gem_Room__Button_Nearby | SpotId::Ebih__Gem_Room__East_14 | SpotId::Ebih__Gem_Room__Hidden_Cache | SpotId::Ebih__Gem_Room__Hidden_Tunnel_Corner | SpotId::Ebih__Gem_Room__West_13 | SpotId::Ebih__Grid_25_10_12__Below_Bush | SpotId::Ebih__Grid_25_10_12__Door_Left | SpotId::Ebih__Grid_25_10_12__East_10 | SpotId::Ebih__Grid_25_10_12__East_11 | SpotId::Ebih__Grid_25_10_12__East_12 | SpotId::Ebih__Grid_25_10_12__Hidden_Bush | SpotId::Ebih__Grid_25_10_12__West_10 | SpotId::Ebih__Grid_25_10_12__West_11 | SpotId::Ebih__Grid_25_10_12__West_12 | SpotId::Ebih__Grid_26_10_11__East_10 | SpotId::Ebih__Grid_26_10_11__Ledge | SpotId::Ebih__Grid_26_10_11__West_10 | SpotId::Ebih__Grid_26_10_11__West_11 | SpotId::Ebih__Guarded_Health__East | SpotId::Ebih__Guarded_Health__Far_West | SpotId::Ebih__Guarded_Health__West_Basin | SpotId::Ebih__Hidden_Portal__Button | SpotId::Ebih__Hidden_Portal__East_3 | SpotId::Ebih__Hidden_Portal__East_5 | SpotId::Ebih__Hidden_Portal__East_6 | SpotId::Ebih__Hidden_Portal__Gate_Ledge_East | SpotId::Ebih__Hidden_Portal__Portal_Stand | SpotId::Ebih__Hidden_Portal__Save_Point | SpotId::Ebih__Hidden_Portal__Tunnel_Entrance | SpotId::Ebih__Hidden_Portal__Upper_Slope | SpotId::Ebih__Hidden_Portal__West_3 | SpotId::Ebih__Hidden_Portal__West_6 | SpotId::Ebih__Observation_Tower_Room__East_11 | SpotId::Ebih__Observation_Tower_Room__West_10 | SpotId::Ebih__Observation_Tower_Room__West_9 | SpotId::Ebih__Open_Air__Northeast | SpotId::Ebih__Open_Air__South | SpotId::Ebih__Party_Central__Below_Between_Waterfalls | SpotId::Ebih__Party_Central__Below_Waterfall | SpotId::Ebih__Party_Central__Bottom_East | SpotId::Ebih__Party_Central__East_Trough | SpotId::Ebih__Party_Central__Mid_air_Between_Waterfalls | SpotId::Ebih__Party_Central__Mid_air_East_of_Waterfall | SpotId::Ebih__Party_Central__Portal_Stand | SpotId::Ebih__Peak__Between_West_Door_and_Tree | SpotId::Ebih__Peak__East_3 | SpotId::Ebih__Peak__Observatory_East | SpotId::Ebih__Peak__Observatory_West | SpotId::Ebih__Peak__Portal_Stand | SpotId::Ebih__Peak__Tunnel_Entrance | SpotId::Ebih__Peak__West_3 | SpotId::Ebih__Truck_Gate__East | SpotId::Ebih__Truck_Gate__Portal_Stand | SpotId::Ebih__Truck_Gate__Switch | SpotId::Ebih__Vertical_Interchange__Block_Cubby | SpotId::Ebih__Vertical_Interchange__East_13 | SpotId::Ebih__Vertical_Interchange__East_15 | SpotId::Ebih__Vertical_Interchange__Switch | SpotId::Ebih__Vertical_Interchange__Under_Switch | SpotId::Ebih__Vertical_Interchange__West_13 | SpotId::Ebih__Walled_Off__East_12 | SpotId::Ebih__Walled_Off__Wall_Left | SpotId::Ebih__Walled_Off__Wall_Right | SpotId::Ebih__Walled_Off__West_12 | SpotId::Ebih__Walled_Off__West_14 | SpotId::Ebih__Waterfall__Alcove | SpotId::Ebih__Waterfall__Alcove_Left | SpotId::Ebih__Waterfall__Alcove_Right | SpotId::Ebih__Waterfall__Below_Left_Switch | SpotId::Ebih__Waterfall__Cave_Entrance | SpotId::Ebih__Waterfall__Center_Main_Path | SpotId::Ebih__Waterfall__East_10 | SpotId::Ebih__Waterfall__East_11 | SpotId::Ebih__Waterfall__East_7 | SpotId::Ebih__Waterfall__East_8 | SpotId::Ebih__Waterfall__East_9 | SpotId::Ebih__Waterfall__East_Door_Right | SpotId::Ebih__Waterfall__East_Horizontal_Door | SpotId::Ebih__Waterfall__East_Switch | SpotId::Ebih__Waterfall__Ledge_Below_East_Door | SpotId::Ebih__Waterfall__Ledge_Below_Hole | SpotId::Ebih__Waterfall__Platform_by_East_Door | SpotId::Ebih__Waterfall__Wall_Left | SpotId::Ebih__Waterfall__Wall_Right | SpotId::Ebih__Waterfall__Waterfall_Center_Center | SpotId::Ebih__Waterfall__West_10 | SpotId::Ebih__Waterfall__West_11 | SpotId::Ebih__Waterfall__West_7 | SpotId::Ebih__Waterfall__West_8 | SpotId::Ebih__Waterfall__West_9 | SpotId::Ebih__Waterfall__West_Grassy_Plains | SpotId::Ebih__Waterfall__West_Mid_air_Between_Trees | SpotId::Ebih__Waterfall__West_Waterfall_Mid_air_Tier_2 | SpotId::Ebih__West_Face__East | SpotId::Ebih__West_Face__Lower_Ledge | SpotId::Ebih__West_Face__South | SpotId::Ebih__West_Face__Southwest | SpotId::Ebih_Breach__Bubble_Bobble__East_11 | SpotId::Ebih_Breach__Bubble_Bobble__Upper_Ledge | SpotId::Ebih_Breach__Bubble_Bobble__West_10 | SpotId::Ebih_Breach__Bubble_Bobble__West_7 | SpotId::Ebih_Breach__Connector__Column | SpotId::Ebih_Breach__Connector__East | SpotId::Ebih_Breach__Connector__West | SpotId::Ebih_Breach__Corner__East | SpotId::Ebih_Breach__Corner__South | SpotId::Ebih_Breach__Corridor__Northeast | SpotId::Ebih_Breach__Corridor__South | SpotId::Ebih_Breach__Corridor__West | SpotId::Ebih_Breach__Double_Gate__Button_Left | SpotId::Ebih_Breach__Double_Gate__Button_Right | SpotId::Ebih_Breach__Double_Gate__Grate_West | SpotId::Ebih_Breach__Double_Gate__North | SpotId::Ebih_Breach__Double_Gate__Portal_Stand | SpotId::Ebih_Breach__Double_Gate__South | SpotId::Ebih_Breach__Double_Gate__West | SpotId::Ebih_Breach__Drone_Tutorial__Above_Rocks | SpotId::Ebih_Breach__Drone_Tutorial__Bottom | SpotId::Ebih_Breach__Drone_Tutorial__First_Rocks | SpotId::Ebih_Breach__Drone_Tutorial__Second_Rocks | SpotId::Ebih_Breach__Drone_Tutorial__Top | SpotId::Ebih_Breach__Drone_Tutorial__Upper_Mid_way | SpotId::Ebih_Breach__Drone_Tutorial__West_4 | SpotId::Ebih_Breach__Drone_Tutorial__West_6 | SpotId::Ebih_Breach__Giguna_Corner__South | SpotId::Ebih_Breach__In_n_Out__Button | SpotId::Ebih_Breach__In_n_Out__Center_Rock_East | SpotId::Ebih_Breach__In_n_Out__Gate_East | SpotId::Ebih_Breach__In_n_Out__Northwest | SpotId::Ebih_Breach__In_n_Out__Northwest_Ledge | SpotId::Ebih_Breach__In_n_Out__Portal_Stand | SpotId::Ebih_Breach__In_n_Out__Save_Point | SpotId::Ebih_Breach__In_n_Out__Southeast_Plains | SpotId::Ebih_Breach__In_n_Out__Southwest | SpotId::Ebih_Breach__In_n_Out__Southwest_Plains | SpotId::Ebih_Breach__Lake_Gate__Button | SpotId::Ebih_Breach__Lake_Gate__Interior | SpotId::Ebih_Breach__Lake_Gate__North | SpotId::Ebih_Breach__Lake_Gate__Outside_West | SpotId::Ebih_Breach__Lake_Gate__South | SpotId::Ebih_Breach__Peak__Ground_Near_Door | SpotId::Ebih_Breach__Peak__Save_Point | SpotId::Ebih_Breach__Peak__South | SpotId::Ebih_Breach__Peak__West | SpotId::Ebih_Breach__Portals_101__East_4 | SpotId::Ebih_Breach__Portals_101__East_6 | SpotId::Ebih_Breach__Portals_101__Portal_Stand | SpotId::Ebih_Breach__Portals_101__Save_Point | SpotId::Ebih_Breach__Portals_101__West_4 | SpotId::Ebih_Breach__Portals_101__West_Lower_Ledge | SpotId::Ebih_Breach__Rock_Block__East_11 | SpotId::Ebih_Breach__Rock_Block__East_Tunnel | SpotId::Ebih_Breach__Rock_Block__North | SpotId::Ebih_Breach__Rock_Block__Rock_East | SpotId::Ebih_Breach__Rock_Block__Rock_Southwest | SpotId::Ebih_Breach__Rock_Block__Rock_Top | SpotId::Ebih_Breach__Rock_Block__Save_Point | SpotId::Ebih_Breach__Rock_Block__West_13 | SpotId::Ebih_Breach__Rocky_Cages__Button | SpotId::Ebih_Breach__Rocky_Cages__Cage_Bottom | SpotId::Ebih_Breach__Rocky_Cages__Cage_North | SpotId::Ebih_Breach__Rocky_Cages__East | SpotId::Ebih_Breach__Rocky_Cages__Lower_Cage_East | SpotId::Ebih_Breach__Rocky_Cages__Northeast | SpotId::Ebih_Breach__Rocky_Cages__West_10 | SpotId::Ebih_Breach__Rocky_Cages__West_11 | SpotId::Ebih_Breach__Snowy_Rocks__Cache | SpotId::Ebih_Breach__Snowy_Rocks__East_4 | SpotId::Ebih_Breach__Snowy_Rocks__North | SpotId::Ebih_Breach__Snowy_Rocks__West | SpotId::Ebih_Breach__Spider_Guards__Button | SpotId::Ebih_Breach__Spider_Guards__East | SpotId::Ebih_Breach__Spider_Guards__East_Sand_Pit | SpotId::Ebih_Breach__Spider_Guards__Northwest | SpotId::Ebih_Breach__Spider_Guards__South | SpotId::Ebih_Breach__Switchback__East | SpotId::Ebih_Breach__Switchback__West | SpotId::Ebih_Breach__The_Vault__Dais_Center | SpotId::Ebih_Breach__The_Vault__East | SpotId::Ebih_Breach__The_Vault__South | SpotId::Ebih_Breach__The_Vault__Southwest_Corner | SpotId::Ebih_Breach__Vertical_Room__Cache | SpotId::Ebih_Breach__Vertical_Room__East_2 | SpotId::Ebih_Breach__Vertical_Room__East_6 | SpotId::Ebih_Breach__Vertical_Room__Passage_Bottom | SpotId::Ebih_Breach__Vertical_Room__Portal_Stand | SpotId::Emergence__Aquarium__North | SpotId::Emergence__Aquarium__West_10 | SpotId::Emergence__Aquarium__West_11 | SpotId::Emergence__Aquarium__West_Ledge | SpotId::Emergence__Audience_Chamber__Cache | SpotId::Emergence__Audience_Chamber__East_10 | SpotId::Emergence__Audience_Chamber__East_11 | SpotId::Emergence__Audience_Chamber__Left_Column_Wall | SpotId::Emergence__Audience_Chamber__West | SpotId::Emergence__Camp_Exterior__Door | SpotId::Emergence__Camp_Exterior__East | SpotId::Emergence__Camp_Exterior__Save_Point | SpotId::Emergence__Drop_off__Basin | SpotId::Emergence__Drop_off__East_10 | SpotId::Emergence__Drop_off__East_7 | SpotId::Emergence__Drop_off__Upper_Ledge | SpotId::Emergence__Drop_off__Upper_Right | SpotId::Emergence__Drop_off__Wall_Left | SpotId::Emergence__Drop_off__Wall_Right | SpotId::Emergence__Drop_off__West | SpotId::Emergence__Rocks_Fall__East | SpotId::Emergence__Rocks_Fall__Fling_Point | SpotId::Emergence__Rocks_Fall__Lower_Ledge | SpotId::Emergence__Rocks_Fall__Middle_Ledge | SpotId::Emergence__Rocks_Fall__Northeast_Ledge | SpotId::Emergence__Rocks_Fall__Wall_1_East | SpotId::Emergence__Rocks_Fall__Wall_1_Northeast | SpotId::Emergence__Rocks_Fall__Wall_1_West | SpotId::Emergence__Rocks_Fall__Wall_2_East | SpotId::Emergence__Rocks_Fall__Wall_2_Northwest | SpotId::Emergence__Rocks_Fall__Wall_2_West | SpotId::Emergence__Rocks_Fall__Wall_3_Northeast | SpotId::Emergence__Rocks_Fall__Wall_3_West | SpotId::Emergence__Rocks_Fall__Wall_4_East | SpotId::Emergence__Rocks_Fall__Wall_4_East_Ledge | SpotId::Emergence__Rocks_Fall__Wall_4_West | SpotId::Emergence__Rocks_Fall__Wall_4_West_Ledge | SpotId::Emergence__Rocks_Fall__West | SpotId::Emergence__Storage__At_Door | SpotId::Emergence__Storage__Behind_Facade | SpotId::Emergence__Storage__Dais_Left | SpotId::Emergence__Storage__Dais_Middle | SpotId::Emergence__Storage__Dais_Right | SpotId::Emergence__Storage__Portal_Stand | SpotId::Emergence__Storage__Secret_Corner | SpotId::Emergence__Storage__South | SpotId::Emergence__Storage__Tunnel_Entrance | SpotId::Emergence__Storage__Tunnel_Ledge | SpotId::Emergence__Storage__Wall_Left | SpotId::Emergence__Storage__Wall_Left_Ledge | SpotId::Emergence__Storage__Wall_Right | SpotId::Emergence__Storage__Wall_Right_Ledge | SpotId::Emergence__Storage__West | SpotId::Emergence__Storage__West_Hook_Point | SpotId::Filter__Core__East | SpotId::Filter__Core__Terminal_East | SpotId::Filter__Core__West | SpotId::Filter__Entrance__East | SpotId::Filter__Exit__East | SpotId::Filter__Exit__Egg | SpotId::Filter__Lower_Hallway__East | SpotId::Filter__Lower_Hallway__West | SpotId::Filter__Scaffolding__Catwalk_Hook_Point | SpotId::Filter__Scaffolding__Lower_West | SpotId::Filter__Scaffolding__Upper_West | SpotId::Filter__Upper_Hallway__Corner | SpotId::Filter__Upper_Hallway__East | SpotId::Filter__Upper_Hallway__West | SpotId::Giguna__Antechamber__East_16 | SpotId::Giguna__Antechamber__Statue_Head | SpotId::Giguna__Antechamber__West_15 | SpotId::Giguna__Breachable_Wall__Above_Middle_Catwalk | SpotId::Giguna__Breachable_Wall__Above_the_Hole | SpotId::Giguna__Breachable_Wall__Above_West_Catwalk | SpotId::Giguna__Breachable_Wall__Cave_Mouth_East | SpotId::Giguna__Breachable_Wall__Cave_Mouth_West | SpotId::Giguna__Breachable_Wall__East_24 | SpotId::Giguna__Breachable_Wall__East_25 | SpotId::Giguna__Breachable_Wall__East_Ledge | SpotId::Giguna__Breachable_Wall__Portal_Entry | SpotId::Giguna__Breachable_Wall__Wall_Interior | SpotId::Giguna__Breachable_Wall__West_Ledge | SpotId::Giguna__Breachable_Wall__West_Mid_air | SpotId::Giguna__Cache__Pit | SpotId::Giguna__Cache__Upper_Ledge | SpotId::Giguna__Cache__West | SpotId::Giguna__Carnelian__East_10 | SpotId::Giguna__Carnelian__Lower_Susar | SpotId::Giguna__Carnelian__Switch | SpotId::Giguna__Carnelian__Upper_Susar | SpotId::Giguna__Carnelian__Vault | SpotId::Giguna__Carnelian__West_10 | SpotId::Giguna__Clouds__Cache | SpotId::Giguna__Clouds__Platform_Early | SpotId::Giguna__Clouds__Platform_Early_Portal | SpotId::Giguna__Clouds__Platform_Start | SpotId::Giguna__Clouds__Platform_Stop | SpotId::Giguna__Clouds__Pull_Right | SpotId::Giguna__Clouds__Southeast | SpotId::Giguna__Clouds__Southwest | SpotId::Giguna__Clouds__Straight_Down | SpotId::Giguna__Dual_Path__Base_of_Wall | SpotId::Giguna__Dual_Path__Below_Left_Switch | SpotId::Giguna__Dual_Path__Below_Right_Switch | SpotId::Giguna__Dual_Path__East_17 | SpotId::Giguna__Dual_Path__East_18 | SpotId::Giguna__Dual_Path__Left_Switch | SpotId::Giguna__Dual_Path__Right_Switch | SpotId::Giguna__Dual_Path__Wall_Secret | SpotId::Giguna__Dual_Path__West_17 | SpotId::Giguna__Dual_Path__West_18 | SpotId::Giguna__East_Caverns__Arc_Passage | SpotId::Giguna__East_Caverns__Carving | SpotId::Giguna__East_Caverns__East_17 | SpotId::Giguna__East_Caverns__Hidden_Passage_Center | SpotId::Giguna__East_Caverns__Lower_Susar | SpotId::Giguna__East_Caverns__Mid_Susar | SpotId::Giguna__East_Caverns__Statues_Ledge | SpotId::Giguna__East_Caverns__Switch | SpotId::Giguna__East_Caverns__Upper_Susar | SpotId::Giguna__East_Caverns__Upper_Susar_Jump_from_East | SpotId::Giguna__East_Caverns__Upper_Susar_Mid_jump | SpotId::Giguna__East_Caverns__West_14 | SpotId::Giguna__East_Caverns__West_16 | SpotId::Giguna__East_Caverns__West_17 | SpotId::Giguna__Far_Corner__East_13 | SpotId::Giguna__Far_Corner__Grass | SpotId::Giguna__Far_Corner__South | SpotId::Giguna__Gateway__Block_Left | SpotId::Giguna__Gateway__Block_Right | SpotId::Giguna__Gateway__Button | SpotId::Giguna__Gateway__East_19 | SpotId::Giguna__Gateway__Far_Ledge | SpotId::Giguna__Gateway__Flask_Ledge | SpotId::Giguna__Gateway__Near_Button | SpotId::Giguna__Gateway__One_Jump | SpotId::Giguna__Gateway__West_18 | SpotId::Giguna__Gateway__West_19 | SpotId::Giguna__Giguna_Base__Building_Entry | SpotId::Giguna__Giguna_Base__East_14 | SpotId::Giguna__Giguna_Base__East_17 | SpotId::Giguna__Giguna_Base__Ruin | SpotId::Giguna__Giguna_Base__Save_Point | SpotId::Giguna__Giguna_Base__Stone_Knob | SpotId::Giguna__Giguna_Base__Switch_Distance_1 | SpotId::Giguna__Giguna_Base__Switch_Distance_2 | SpotId::Giguna__Giguna_Base__Switch_Distance_3 | SpotId::Giguna__Giguna_Base__Switch_Distance_4 | SpotId::Giguna__Giguna_Base__Table | SpotId::Giguna__Giguna_Base__West_15 | SpotId::Giguna__Giguna_Base__West_16 | SpotId::Giguna__Giguna_Northeast__Door | SpotId::Giguna__Giguna_Northeast__East_11 | SpotId::Giguna__Giguna_Northeast__Gate_Button | SpotId::Giguna__Giguna_Northeast__Gate_Left | SpotId::Giguna__Giguna_Northeast__Gate_Right | SpotId::Giguna__Giguna_Northeast__Northeast_Wall | SpotId::Giguna__Giguna_Northeast__Portal_Exit | SpotId::Giguna__Giguna_Northeast__Portal_Hole | SpotId::Giguna__Giguna_Northeast__Right_Column | SpotId::Giguna__Giguna_Northeast__Save_Point | SpotId::Giguna__Giguna_Northeast__Switch | SpotId::Giguna__Giguna_Northeast__Upper_Column | SpotId::Giguna__Giguna_Northeast__Vault | SpotId::Giguna__Giguna_Northeast__Vault_East | SpotId::Giguna__Giguna_Northeast__West_10 | SpotId::Giguna__Giguna_Northeast__West_9 | SpotId::Giguna__Gubi_Lair__Center_Platform | SpotId::Giguna__Gubi_Lair__East_15 | SpotId::Giguna__Gubi_Lair__Pedestal | SpotId::Giguna__Hard_Rock__East_17 | SpotId::Giguna__Hard_Rock__Rock_Center | SpotId::Giguna__Hard_Rock__Rock_Left | SpotId::Giguna__Hard_Rock__Rock_Right | SpotId::Giguna__Hard_Rock__West_17 | SpotId::Giguna__Helipad__East_15 | SpotId::Giguna__Helipad__East_16 | SpotId::Giguna__Helipad__East_18 | SpotId::Giguna__Helipad__Helicopter | SpotId::Giguna__Helipad__Irikar_Drop | SpotId::Giguna__Helipad__Lowest_Ledge | SpotId::Giguna__Helipad__South_Left | SpotId::Giguna__Helipad__South_Middle | SpotId::Giguna__Helipad__South_Right | SpotId::Giguna__Helipad__Tablet_Ledge | SpotId::Giguna__Helipad__Wall_Top | SpotId::Giguna__Labyrinth__Door_Ledge | SpotId::Giguna__Labyrinth__East_22 | SpotId::Giguna__Labyrinth__East_23 | SpotId::Giguna__Labyrinth__Empty_Plinth | SpotId::Giguna__Labyrinth__Ledge_22 | SpotId::Giguna__Labyrinth__Lower_East | SpotId::Giguna__Labyrinth__Lower_Tier_West | SpotId::Giguna__Labyrinth__Middle_Brick | SpotId::Giguna__Labyrinth__Middle_East | SpotId::Giguna__Labyrinth__Portal_Stand | SpotId::Giguna__Labyrinth__Save_Point | SpotId::Giguna__Labyrinth__Second_Tier_Upper | SpotId::Giguna__Labyrinth__Switch_Ledge | SpotId::Giguna__Labyrinth__Top_East | SpotId::Giguna__Labyrinth__Upper_Level_Ledge | SpotId::Giguna__Labyrinth_East__East_19 | SpotId::Giguna__Labyrinth_East__East_20 | SpotId::Giguna__Labyrinth_East__Hole | SpotId::Giguna__Labyrinth_East__Lower_Middle | SpotId::Giguna__Labyrinth_East__Lower_West | SpotId::Giguna__Labyrinth_East__Middle_Drop | SpotId::Giguna__Labyrinth_East__Middle_Tree | SpotId::Giguna__Labyrinth_East__Middle_West | SpotId::Giguna__Labyrinth_East__Top_West | SpotId::Giguna__Lamassu__Deposit | SpotId::Giguna__Lamassu__Deposit_Ledge | SpotId::Giguna__Lamassu__East_18 | SpotId::Giguna__Lamassu__West_18 | SpotId::Giguna__Mural__East_21 | SpotId::Giguna__Mural__Enclosed_Cache | SpotId::Giguna__Mural__Enclosed_Portal | SpotId::Giguna__Mural__Lower_Middle_Column | SpotId::Giguna__Mural__NE_Ledge | SpotId::Giguna__Mural__NW_Bottom | SpotId::Giguna__Mural__NW_Cache | SpotId::Giguna__Mural__NW_Ledge | SpotId::Giguna__Mural__Pedestal | SpotId::Giguna__Mural__Platform_Below_Ledge | SpotId::Giguna__Mural__Shocking_Distance | SpotId::Giguna__Mural__Southwest_Cache | SpotId::Giguna__Mural__West_20 | SpotId::Giguna__Ruins_Center__East_8 | SpotId::Giguna__Ruins_Center__East_9 | SpotId::Giguna__Ruins_Center__Tablet | SpotId::Giguna__Ruins_Center__West_9 | SpotId::Giguna__Ruins_East__Bottom_Rock | SpotId::Giguna__Ruins_East__East_9 | SpotId::Giguna__Ruins_East__Way_Up_High | SpotId::Giguna__Ruins_East__West_7 | SpotId::Giguna__Ruins_East__West_8 | SpotId::Giguna__Ruins_East__West_9 | SpotId::Giguna__Ruins_Top__East_7 | SpotId::Giguna__Ruins_Top__Flask | SpotId::Giguna__Ruins_Top__Portal | SpotId::Giguna__Ruins_Top__Rooftop_East | SpotId::Giguna__Ruins_Top__Rooftop_Gutter | SpotId::Giguna__Ruins_Top__Rooftop_West | SpotId::Giguna__Ruins_Top__Save_Point | SpotId::Giguna__Ruins_Top__Small_Ledge | SpotId::Giguna__Ruins_Top__Switch | SpotId::Giguna__Ruins_Top__Turret_Balcony_West | SpotId::Giguna__Ruins_Top__West_7 | SpotId::Giguna__Ruins_Top__West_Pillar | SpotId::Giguna__Ruins_West__East_7 | SpotId::Giguna__Ruins_West__East_9 | SpotId::Giguna__Ruins_West__Lower_Ledge | SpotId::Giguna__Ruins_West__Platform | SpotId::Giguna__Ruins_West__Rooftop_East_Edge | SpotId::Giguna__Ruins_West__Save_Point | SpotId::Giguna__Ruins_West__Upper_Ledge | SpotId::Giguna__Ruins_West__West_7 | SpotId::Giguna__Secrecy__East_Column | SpotId::Giguna__Secrecy__Near_East_Column | SpotId::Giguna__Secrecy__Portal_Stand | SpotId::Giguna__Secrecy__West_Column_East | SpotId::Giguna__Secrecy__West_Column_West | SpotId::Giguna__Separator__East_25 | SpotId::Giguna__Separator__Lower_Brick | SpotId::Giguna__Separator__Platform | SpotId::Giguna__Separator__Save_Point | SpotId::Giguna__Separator__Switch | SpotId::Giguna__Separator__Upper_Brick | SpotId::Giguna__Separator__West_23 | SpotId::Giguna__Separator__West_24 | SpotId::Giguna__Separator__West_25 | SpotId::Giguna__Vertical_Interchange__Above_Rock | SpotId::Giguna__Vertical_Interchange__Dead_end | SpotId::Giguna__Vertical_Interchange__Dead_end_Ledge | SpotId::Giguna__Vertical_Interchange__East_20 | SpotId::Giguna__Vertical_Interchange__East_22 | SpotId::Giguna__Vertical_Interchange__Gate | SpotId::Giguna__Vertical_Interchange__Middle_Hill_By_Switch | SpotId::Giguna__Vertical_Interchange__North | SpotId::Giguna__Vertical_Interchange__Rock_East | SpotId::Giguna__Vertical_Interchange__Rock_West | SpotId::Giguna__Vertical_Interchange__South | SpotId::Giguna__Vertical_Interchange__Switch | SpotId::Giguna__Vertical_Interchange__Top_Middle_Ledge | SpotId::Giguna__Vertical_Interchange__Top_Right_Ledge | SpotId::Giguna__Vertical_Interchange__West_17 | SpotId::Giguna__Vertical_Interchange__West_19 | SpotId::Giguna__Vertical_Interchange__West_21 | SpotId::Giguna__Wasteland__Below_Platforms_Center | SpotId::Giguna__Wasteland__Cache_Ledge | SpotId::Giguna__Wasteland__Door_Right | SpotId::Giguna__Wasteland__East_12 | SpotId::Giguna__Wasteland__East_13 | SpotId::Giguna__Wasteland__East_14 | SpotId::Giguna__Wasteland__Mid_air_Near_Cache | SpotId::Giguna__Wasteland__Middle_Cliff | SpotId::Giguna__Wasteland__Passage_Cache | SpotId::Giguna__Wasteland__Passage_East | SpotId::Giguna__Wasteland__Switch | SpotId::Giguna__Wasteland__Switch_Approach | SpotId::Giguna__Wasteland__Upper_Cache | SpotId::Giguna__Wasteland__West_12 | SpotId::Giguna__Wasteland__West_13 | SpotId::Giguna__Wasteland__West_14 | SpotId::Giguna__West_Caverns__Bush | SpotId::Giguna__West_Caverns__Cache | SpotId::Giguna__West_Caverns__Column_1_Top_Left | SpotId::Giguna__West_Caverns__East_10 | SpotId::Giguna__West_Caverns__East_12 | SpotId::Giguna__West_Caverns__East_13 | SpotId::Giguna__West_Caverns__East_Susar | SpotId::Giguna__West_Caverns__Small_Platform | SpotId::Giguna__West_Caverns__West_13 | SpotId::Giguna__West_Tower__East_7 | SpotId::Giguna__West_Tower__Southwest | SpotId::Giguna__West_Tower__Top | SpotId::Giguna_Breach__Antechamber__Middle | SpotId::Giguna_Breach__Antechamber__North | SpotId::Giguna_Breach__Antechamber__South | SpotId::Giguna_Breach__Ascent__Bottom | SpotId::Giguna_Breach__Ascent__West_6 | SpotId::Giguna_Breach__Ascent__West_9 | SpotId::Giguna_Breach__Below_Chimney__Cubby_Entrance | SpotId::Giguna_Breach__Below_Chimney__North | SpotId::Giguna_Breach__Below_Chimney__Southwest | SpotId::Giguna_Breach__Below_Chimney__West_Passage | SpotId::Giguna_Breach__Central__East_9 | SpotId::Giguna_Breach__Central__South | SpotId::Giguna_Breach__Central__West_8 | SpotId::Giguna_Breach__Central__West_9 | SpotId::Giguna_Breach__Chimney__Cache | SpotId::Giguna_Breach__Chimney__East_7 | SpotId::Giguna_Breach__Chimney__East_8 | SpotId::Giguna_Breach__Chimney__East_9 | SpotId::Giguna_Breach__Chimney__Middle_Platform | SpotId::Giguna_Breach__Chimney__South | SpotId::Giguna_Breach__Chimney__Top | SpotId::Giguna_Breach__Crab_Rave__A_Crabs_Treasure | SpotId::Giguna_Breach__Crab_Rave__Button | SpotId::Giguna_Breach__Crab_Rave__East | SpotId::Giguna_Breach__Crab_Rave__North | SpotId::Giguna_Breach__Cubby__Entrance | SpotId::Giguna_Breach__Cubby__Rocks | SpotId::Giguna_Breach__Deadly_Flora__East | SpotId::Giguna_Breach__Deadly_Flora__Upper_Left_Ledge | SpotId::Giguna_Breach__Deadly_Flora__West | SpotId::Giguna_Breach__East__East_Tunnel | SpotId::Giguna_Breach__East__North | SpotId::Giguna_Breach__East__Save_Point | SpotId::Giguna_Breach__East__South | SpotId::Giguna_Breach__East__Upper_Ledge | SpotId::Giguna_Breach__Emergence__Corner | SpotId::Giguna_Breach__Emergence__East | SpotId::Giguna_Breach__Emergence__Left_Mid_air | SpotId::Giguna_Breach__Emergence__Left_Side | SpotId::Giguna_Breach__Emergence__Save_Point | SpotId::Giguna_Breach__Fire_Room__East_10 | SpotId::Giguna_Breach__Fire_Room__East_11 | SpotId::Giguna_Breach__Fire_Room__South | SpotId::Giguna_Breach__Fire_Room__West_10 | SpotId::Giguna_Breach__Fire_Room__West_11 | SpotId::Giguna_Breach__Fork__East_13 | SpotId::Giguna_Breach__Fork__East_14 | SpotId::Giguna_Breach__Fork__West_13 | SpotId::Giguna_Breach__Gate_and_Wall__Brick_Column | SpotId::Giguna_Breach__Gate_and_Wall__Button_Left | SpotId::Giguna_Breach__Gate_and_Wall__Button_Right | SpotId::Giguna_Breach__Gate_and_Wall__East_9 | SpotId::Giguna_Breach__Gate_and_Wall__Portal_Stand | SpotId::Giguna_Breach__Gate_and_Wall__Walled_Off | SpotId::Giguna_Breach__Gate_and_Wall__West_10 | SpotId::Giguna_Breach__Grid_14_10_11__East_10 | SpotId::Giguna_Breach__Grid_14_10_11__East_11 | SpotId::Giguna_Breach__Grid_14_10_11__North | SpotId::Giguna_Breach__Grid_14_10_11__South | SpotId::Giguna_Breach__Labyrinth__Button | SpotId::Giguna_Breach__Labyrinth__East_19 | SpotId::Giguna_Breach__Labyrinth__East_20 | SpotId::Giguna_Breach__Labyrinth__Lower_Tier_East | SpotId::Giguna_Breach__Labyrinth__Lower_Tier_West | SpotId::Giguna_Breach__Labyrinth__Middle_Brick | SpotId::Giguna_Breach__Labyrinth__Middle_Tier_Ledge | SpotId::Giguna_Breach__Labyrinth__Middle_Tier_Ledge_East | SpotId::Giguna_Breach__Labyrinth__Middle_Tier_Upper | SpotId::Giguna_Breach__Labyrinth__Pipe_Cache | SpotId::Giguna_Breach__Labyrinth__Pipe_Corner | SpotId::Giguna_Breach__Labyrinth__Plinth | SpotId::Giguna_Breach__Labyrinth__Plinth_Approach | SpotId::Giguna_Breach__Labyrinth__Save_Point | SpotId::Giguna_Breach__Labyrinth__South | SpotId::Giguna_Breach__Labyrinth__Upper_Tier_West | SpotId::Giguna_Breach__Lower_Machinery__Bottom | SpotId::Giguna_Breach__Lower_Machinery__Button | SpotId::Giguna_Breach__Lower_Machinery__Center_Pillar_West | SpotId::Giguna_Breach__Lower_Machinery__East_Mid_air | SpotId::Giguna_Breach__Lower_Machinery__North | SpotId::Giguna_Breach__Lower_Machinery__South | SpotId::Giguna_Breach__Lower_Machinery__West | SpotId::Giguna_Breach__Lower_Portal__Bottom | SpotId::Giguna_Breach__Lower_Portal__East | SpotId::Giguna_Breach__Lower_Portal__East_Upper_Ledge | SpotId::Giguna_Breach__Lower_Portal__North | SpotId::Giguna_Breach__Lower_Portal__Portal_Dais | SpotId::Giguna_Breach__Lower_Portal__West_Ledge | SpotId::Giguna_Breach__Peak__East_6 | SpotId::Giguna_Breach__Peak__Portal | SpotId::Giguna_Breach__Peak__Save_Point | SpotId::Giguna_Breach__Peak__West_7 | SpotId::Giguna_Breach__Pink_Clouds__Corner | SpotId::Giguna_Breach__Pink_Clouds__Normal_Entry | SpotId::Giguna_Breach__Pink_Clouds__Quick_Entry | SpotId::Giguna_Breach__Pinkness__Rock_Bottom | SpotId::Giguna_Breach__Pinkness__Rock_Top | SpotId::Giguna_Breach__Pinkness__South | SpotId::Giguna_Breach__Pinkness__West | SpotId::Giguna_Breach__Portal_Exchange__Bottom | SpotId::Giguna_Breach__Portal_Exchange__Middle_Gap | SpotId::Giguna_Breach__Portal_Exchange__Middle_Ledge | SpotId::Giguna_Breach__Portal_Exchange__Upper_Side | SpotId::Giguna_Breach__Portal_Exchange__West | SpotId::Giguna_Breach__Robopede__Center | SpotId::Giguna_Breach__Robopede__North | SpotId::Giguna_Breach__Robopede__West | SpotId::Giguna_Breach__Secret_Tunnel__Button_Obverse | SpotId::Giguna_Breach__Secret_Tunnel__East | SpotId::Giguna_Breach__Secret_Tunnel__West | SpotId::Giguna_Breach__Slingshot__Column | SpotId::Giguna_Breach__Slingshot__Ravine | SpotId::Giguna_Breach__Slingshot__West | SpotId::Giguna_Breach__South__North | SpotId::Giguna_Breach__South__Save_Point | SpotId::Giguna_Breach__South__South | SpotId::Giguna_Breach__SW_Save__East_12 | SpotId::Giguna_Breach__SW_Save__North | SpotId::Giguna_Breach__SW_Save__Save_Point | SpotId::Giguna_Breach__SW_Save__West_11 | SpotId::Giguna_Breach__Upper_Machinery__North | SpotId::Giguna_Breach__Upper_Machinery__Northeast_Scaffold | SpotId::Giguna_Breach__Upper_Machinery__South | SpotId::Giguna_Breach__Upper_Machinery__West | SpotId::Giguna_Breach__White_Clouds__Entry | SpotId::Giguna_Breach__White_Clouds__Exit | SpotId::Giguna_Breach__White_Clouds__Upper_Middle | SpotId::Giguna_Breach__White_Clouds__West_Ledge | SpotId::Glacier__Angry_Guards__Corner | SpotId::Glacier__Angry_Guards__East_11 | SpotId::Glacier__Angry_Guards__East_12 | SpotId::Glacier__Angry_Guards__Gate | SpotId::Glacier__Angry_Guards__Portal_Stand | SpotId::Glacier__Apocalypse_Entry__Grate_Ledge | SpotId::Glacier__Apocalypse_Entry__West_10 | SpotId::Glacier__Apocalypse_Entry__West_15_Lower | SpotId::Glacier__Apocalypse_Entry__West_15_Upper | SpotId::Glacier__Boomerang_Antechamber__East_12 | SpotId::Glacier__Boomerang_Antechamber__East_13 | SpotId::Glacier__Boomerang_Antechamber__Upper_East | SpotId::Glacier__Boomerang_Antechamber__West_12 | SpotId::Glacier__Boomerang_Antechamber__West_13 | SpotId::Glacier__Boomerang_Room__Pedestal | SpotId::Glacier__Boomerang_Room__Upper_West | SpotId::Glacier__Boomerang_Room__West | SpotId::Glacier__Compass_Room__Center | SpotId::Glacier__Compass_Room__East | SpotId::Glacier__Compass_Room__West | SpotId::Glacier__Crystals__East | SpotId::Glacier__Crystals__Grate_Left | SpotId::Glacier__Crystals__Lower_Corner | SpotId::Glacier__Crystals__Portal_Cage | SpotId::Glacier__Crystals__Portal_Stand | SpotId::Glacier__Crystals__Top_Corner | SpotId::Glacier__Crystals__West | SpotId::Glacier__Dock_Outside__Cave_Treasure | SpotId::Glacier__Dock_Outside__Do_Not_Enter | SpotId::Glacier__Dock_Outside__Entry | SpotId::Glacier__Dock_Outside__Lower_Platforms | SpotId::Glacier__Dock_Outside__Ruins_Platform | SpotId::Glacier__Dock_Outside__Ruins_Stairs | SpotId::Glacier__Dock_Outside__Upper_West_Hill | SpotId::Glacier__Fortress__Button | SpotId::Glacier__Fortress__Gate_East | SpotId::Glacier__Fortress__Inner_Center | SpotId::Glacier__Fortress__Inner_East_Ledge | SpotId::Glacier__Fortress__Inner_Mid_Tier_East | SpotId::Glacier__Fortress__Inner_Mid_Tier_West | SpotId::Glacier__Fortress__Inner_Upper_Tier_East | SpotId::Glacier__Fortress__Inner_Upper_Tier_West | SpotId::Glacier__Fortress__Parapet_Center_East | SpotId::Glacier__Fortress__Parapet_Crenelle_2 | SpotId::Glacier__Fortress__Parapet_East_Hook_Point | SpotId::Glacier__Fortress__Parapet_East_Ledge | SpotId::Glacier__Fortress__Parapet_West_Hook_Point | SpotId::Glacier__Fortress__Parapet_West_Ledge | SpotId::Glacier__Fortress__Portal_Stand | SpotId::Glacier__Fortress__West_Staircase | SpotId::Glacier__Grid_31_9_12__East_10 | SpotId::Glacier__Grid_31_9_12__East_9 | SpotId::Glacier__Grid_31_9_12__Midair | SpotId::Glacier__Grid_31_9_12__West_12 | SpotId::Glacier__Grid_32_7_10__East_8 | SpotId::Glacier__Grid_32_7_10__East_9 | SpotId::Glacier__Grid_32_7_10__West_10 | SpotId::Glacier__Grid_32_7_10__West_9 | SpotId::Glacier__Grid_37_38_9__East | SpotId::Glacier__Grid_37_38_9__West | SpotId::Glacier__Grid_39_40_7_9__East_9 | SpotId::Glacier__Grid_39_40_7_9__First_Upper_Platform | SpotId::Glacier__Grid_39_40_7_9__Floating_Rock | SpotId::Glacier__Grid_39_40_7_9__Fortress_Ledge | SpotId::Glacier__Grid_39_40_7_9__Third_Upper_Platform | SpotId::Glacier__Grid_39_40_7_9__Upper_East | SpotId::Glacier__Grid_39_40_7_9__West | SpotId::Glacier__Grid_42_10__East | SpotId::Glacier__Grid_42_10__West | SpotId::Glacier__Grid_43_10_11__East | SpotId::Glacier__Grid_43_10_11__Lower | SpotId::Glacier__Grid_43_10_11__Top | SpotId::Glacier__Hammonds_End__Between_Center_Doors | SpotId::Glacier__Hammonds_End__Corner | SpotId::Glacier__Hammonds_End__East_11_Door | SpotId::Glacier__Hammonds_End__Hammond | SpotId::Glacier__Hammonds_End__Lower_Pedestal_West | SpotId::Glacier__Hammonds_End__Lower_Right_Pedestal | SpotId::Glacier__Hammonds_End__Switch_from_Ledge | SpotId::Glacier__Hammonds_End__Switch_Near | SpotId::Glacier__Hammonds_End__Upper_Floor | SpotId::Glacier__Hammonds_End__Upper_Grate_Right | SpotId::Glacier__Hammonds_End__Upper_Portal_Stand | SpotId::Glacier__Hammonds_End__Upper_Right_Mid_air | SpotId::Glacier__Hammonds_End__Upper_Right_Pedestal | SpotId::Glacier__Hammonds_End__West_10 | SpotId::Glacier__Hammonds_End__West_11 | SpotId::Glacier__Inner_Caverns__Northwest_Drone | SpotId::Glacier__Inner_Caverns__Northwest_Stone | SpotId::Glacier__Inner_Caverns__Southwest_Hill | SpotId::Glacier__Inner_Caverns__West_12 | SpotId::Glacier__Inner_Caverns__West_13 | SpotId::Glacier__Inner_Caverns__West_14 | SpotId::Glacier__Lake_Main_Entrance__Cache | SpotId::Glacier__Lake_Main_Entrance__Close_Enough | SpotId::Glacier__Lake_Main_Entrance__East_13 | SpotId::Glacier__Lake_Main_Entrance__Ebih_Access | SpotId::Glacier__Lake_Main_Entrance__Lake_Access | SpotId::Glacier__Ledge_Grab_Room__Cliff_Bottom | SpotId::Glacier__Ledge_Grab_Room__Column | SpotId::Glacier__Ledge_Grab_Room__East_11 | SpotId::Glacier__Ledge_Grab_Room__East_9 | SpotId::Glacier__Ledge_Grab_Room__Gate | SpotId::Glacier__Ledge_Grab_Room__North | SpotId::Glacier__Ledge_Grab_Room__Pedestal | SpotId::Glacier__Ledge_Grab_Room__West_11 | SpotId::Glacier__Ledge_Grab_Room__West_9 | SpotId::Glacier__Lonely_Bull__Arena | SpotId::Glacier__Lonely_Bull__West | SpotId::Glacier__Peak__Birds_Eye | SpotId::Glacier__Peak__East_8 | SpotId::Glacier__Peak__South | SpotId::Glacier__Peak__Top_Platform_East | SpotId::Glacier__Peak__West_8 | SpotId::Glacier__Revival__East_9 | SpotId::Glacier__Revival__Lower_East | SpotId::Glacier__Revival__Mid_air | SpotId::Glacier__Revival__Pillar | SpotId::Glacier__Revival__Pillar_Step | SpotId::Glacier__Revival__Save_Point | SpotId::Glacier__Revival__West_8 | SpotId::Glacier__Revival__West_9 | SpotId::Glacier__Sea_Burial__Breakable_Rock_Left | SpotId::Glacier__Sea_Burial__Breakable_Rock_Right | SpotId::Glacier__Sea_Burial__Collapsing_Ceiling | SpotId::Glacier__Sea_Burial__Deep_Cache | SpotId::Glacier__Sea_Burial__East_14 | SpotId::Glacier__Sea_Burial__Inside_the_Grate | SpotId::Glacier__Sea_Burial__Right_Center_Rock | SpotId::Glacier__Sea_Burial__West_13 | SpotId::Glacier__Sea_Burial__West_14 | SpotId::Glacier__Secret_Chamber__East_10 | SpotId::Glacier__Secret_Chamber__East_11 | SpotId::Glacier__Secret_Chamber__Upper_East_Ledge | SpotId::Glacier__Secret_Chamber__West_11 | SpotId::Glacier__Sliced_Cavern__Cache | SpotId::Glacier__Sliced_Cavern__East_12 | SpotId::Glacier__Sliced_Cavern__East_13 | SpotId::Glacier__Sliced_Cavern__East_14 | SpotId::Glacier__Sliced_Cavern__Upper_Middle | SpotId::Glacier__Sliced_Cavern__West_12 | SpotId::Glacier__Sliced_Cavern__West_13 | SpotId::Glacier__The_Big_Drop__Breakable_Rock_Right | SpotId::Glacier__The_Big_Drop__East | SpotId::Glacier__The_Big_Drop__Small_Path | SpotId::Glacier__The_Big_Drop__Solid_Rock | SpotId::Glacier__The_Big_Drop__Water_Surface | SpotId::Glacier__The_Big_Drop__West_11_Door | SpotId::Glacier__The_Big_Drop__West_14 | SpotId::Glacier__Vertical_Room__Below_Upper_Switch | SpotId::Glacier__Vertical_Room__East_11 | SpotId::Glacier__Vertical_Room__East_12 | SpotId::Glacier__Vertical_Room__East_13 | SpotId::Glacier__Vertical_Room__East_14 | SpotId::Glacier__Vertical_Room__East_17 | SpotId::Glacier__Vertical_Room__East_9 | SpotId::Glacier__Vertical_Room__Flying_West | SpotId::Glacier__Vertical_Room__Fortress_Ledge | SpotId::Glacier__Vertical_Room__Hovering_East | SpotId::Glacier__Vertical_Room__Lower_Switch | SpotId::Glacier__Vertical_Room__Middle_Gatestone | SpotId::Glacier__Vertical_Room__Middle_Gatestone_Left | SpotId::Glacier__Vertical_Room__Outcrop_by_Middle_Gatestone | SpotId::Glacier__Vertical_Room__Past_Gate | SpotId::Glacier__Vertical_Room__Peak | SpotId::Glacier__Vertical_Room__South | SpotId::Glacier__Vertical_Room__Under_Switch | SpotId::Glacier__Vertical_Room__Upper_Switch | SpotId::Glacier__Vertical_Room__Waters_Ledge | SpotId::Glacier__Vertical_Room__West_15_Flat | SpotId::Glacier__Vertical_Room__West_15_Lower | SpotId::Glacier__Vertical_Room__West_8 | SpotId::Glacier__Vertical_Room__West_9 | SpotId::Glacier_Breach__Angry_Lions__North | SpotId::Glacier_Breach__Angry_Lions__Portal_Stand | SpotId::Glacier_Breach__Angry_Lions__Second_Platform | SpotId::Glacier_Breach__Angry_Lions__Top_Platform | SpotId::Glacier_Breach__Control__East | SpotId::Glacier_Breach__Control__Upper_Corner | SpotId::Glacier_Breach__Control__West | SpotId::Glacier_Breach__Crystals__East | SpotId::Glacier_Breach__Crystals__West | SpotId::Glacier_Breach__Electric_Arena__East | SpotId::Glacier_Breach__Electric_Arena__East_Ledge | SpotId::Glacier_Breach__Electric_Arena__Lower_East_Platform | SpotId::Glacier_Breach__Electric_Arena__Lower_West_Platform | SpotId::Glacier_Breach__Electric_Arena__North | SpotId::Glacier_Breach__Electric_Arena__West | SpotId::Glacier_Breach__Empty_Space__West | SpotId::Glacier_Breach__Floaters__East | SpotId::Glacier_Breach__Floaters__Hidden | SpotId::Glacier_Breach__Floaters__West | SpotId::Glacier_Breach__Fortress__East_Ledge | SpotId::Glacier_Breach__Fortress__Mid_Tier_East | SpotId::Glacier_Breach__Fortress__Mid_Tier_West | SpotId::Glacier_Breach__Fortress__Portal_Entry | SpotId::Glacier_Breach__Fortress__Southeast | SpotId::Glacier_Breach__Fortress__Southwest | SpotId::Glacier_Breach__Fortress__Southwest_Stair | SpotId::Glacier_Breach__Fortress__Upper_Tier_East | SpotId::Glacier_Breach__Fortress__Upper_Tier_Middle | SpotId::Glacier_Breach__Fortress__Upper_Tier_West | SpotId::Glacier_Breach__Grate_Work__Below_Grate | SpotId::Glacier_Breach__Grate_Work__East | SpotId::Glacier_Breach__Grate_Work__East_Hill | SpotId::Glacier_Breach__Grate_Work__Grate_Interior | SpotId::Glacier_Breach__Grate_Work__Southeast | SpotId::Glacier_Breach__Grate_Work__West_13 | SpotId::Glacier_Breach__Grate_Work__West_14 | SpotId::Glacier_Breach__Guarded_Corridor__East | SpotId::Glacier_Breach__Guarded_Corridor__Past_the_Guards | SpotId::Glacier_Breach__Guarded_Corridor__Save_Point | SpotId::Glacier_Breach__Guarded_Corridor__West | SpotId::Glacier_Breach__Hammonds_Breach__East | SpotId::Glacier_Breach__Hammonds_Breach__East_Side | SpotId::Glacier_Breach__Hammonds_Breach__First_Step | SpotId::Glacier_Breach__Hammonds_Breach__Save_Point | SpotId::Glacier_Breach__Hammonds_Breach__West_Side | SpotId::Glacier_Breach__Piano_Roll__East_10 | SpotId::Glacier_Breach__Piano_Roll__East_9 | SpotId::Glacier_Breach__Piano_Roll__West | SpotId::Glacier_Breach__Save_and_Exit__Halfway | SpotId::Glacier_Breach__Save_and_Exit__Portal_Stand | SpotId::Glacier_Breach__Save_and_Exit__Save_Point | SpotId::Glacier_Breach__Save_and_Exit__West | SpotId::Glacier_Breach__South_Save__Brick_Ledge | SpotId::Glacier_Breach__South_Save__East | SpotId::Glacier_Breach__South_Save__Save_Point | SpotId::Glacier_Breach__South_Save__West | SpotId::Glacier_Breach__Spidery_Connector__Button | SpotId::Glacier_Breach__Spidery_Connector__East | SpotId::Glacier_Breach__Spidery_Connector__Midway | SpotId::Glacier_Breach__Spidery_Connector__West | SpotId::Glacier_Breach__Stable_Floor__South | SpotId::Glacier_Breach__Stable_Floor__West | SpotId::Glacier_Breach__Stacked_Enemies__Mid_air_East | SpotId::Glacier_Breach__Stacked_Enemies__Mideast | SpotId::Glacier_Breach__Stacked_Enemies__Northwest | SpotId::Glacier_Breach__Stacked_Enemies__South | SpotId::Glacier_Breach__Stacked_Enemies__West | SpotId::Glacier_Breach__West_Save__East_11 | SpotId::Glacier_Breach__West_Save__East_12 | SpotId::Glacier_Breach__West_Save__Hidden | SpotId::Glacier_Breach__West_Save__Save_Point | SpotId::Glacier_Breach__West_Save__West_11 | SpotId::Glacier_Breach__West_Save__West_Ledge | SpotId::Glacier_Breach__Zappers__East | SpotId::Glacier_Breach__Zappers__Trough | SpotId::Glacier_Breach__Zappers__West | SpotId::Interior__Building_Interior__Corner | SpotId::Interior__Building_Interior__Entry | SpotId::Interior__Bunker_Interior__Desk | SpotId::Interior__Bunker_Interior__Entry | SpotId::Interior__Cave_Behind_Waterfall__Bottom | SpotId::Interior__Cave_Behind_Waterfall__Middle | SpotId::Interior__Cave_Behind_Waterfall__Top | SpotId::Interior__Dock_Elevator__Connector | SpotId::Interior__Dock_Interior__Connector | SpotId::Interior__Dock_Interior__Entry | SpotId::Interior__Ebih_Cave__Entry | SpotId::Interior__Emergence_Hangar__Door | SpotId::Interior__Emergence_Hangar__Freight_Elevator | SpotId::Interior__Facility_Interior__Door | SpotId::Interior__Facility_Interior__Freight_Elevator | SpotId::Interior__Garage__Boxes | SpotId::Interior__Garage__Entry | SpotId::Interior__Observatory__Atop_Structure_Center | SpotId::Interior__Observatory__Atop_Structure_East | SpotId::Interior__Observatory__Below_Catwalk | SpotId::Interior__Observatory__Catwalk | SpotId::Interior__Observatory__East | SpotId::Interior__Observatory__East_Desk | SpotId::Interior__Observatory__East_Staircase_Top | SpotId::Interior__Observatory__West | SpotId::Interior__Outpost_Interior__Bookshelf | SpotId::Interior__Outpost_Interior__Entry | SpotId::Interior__Tent_Interior__Desk | SpotId::Interior__Tent_Interior__Entry | SpotId::Interior__Uhrum_Waterfall_Cave__Cache | SpotId::Interior__Uhrum_Waterfall_Cave__Entrance | SpotId::Interior__Uhrum_Waterfall_Cave__Slope | SpotId::Irikar__Abandoned_Room__Corner_Core | SpotId::Irikar__Abandoned_Room__Empty_Pedestal | SpotId::Irikar__Abandoned_Room__West | SpotId::Irikar__Airy__Cavern_Cache | SpotId::Irikar__Airy__Cavern_Mouth | SpotId::Irikar__Airy__Cavern_Side | SpotId::Irikar__Airy__Lower_Throw_End | SpotId::Irikar__Airy__Right_Hover_Throw_End | SpotId::Irikar__Airy__South_Center | SpotId::Irikar__Airy__South_Sight | SpotId::Irikar__Basement_Pipes__East_28 | SpotId::Irikar__Basement_Pipes__High_Pipe | SpotId::Irikar__Basement_Pipes__Left_Vertical_Pipe | SpotId::Irikar__Basement_Pipes__West_27 | SpotId::Irikar__Basement_Pipes__West_28 | SpotId::Irikar__Basement_Portal__East_27 | SpotId::Irikar__Basement_Portal__East_28 | SpotId::Irikar__Basement_Portal__Moving_Platform_Start | SpotId::Irikar__Basement_Portal__North | SpotId::Irikar__Basement_Portal__Portal_Stand | SpotId::Irikar__Basement_Portal__West_28 | SpotId::Irikar__Beach__Cache | SpotId::Irikar__Beach__East | SpotId::Irikar__Beach_Save__East_28_Ground | SpotId::Irikar__Beach_Save__East_28_Platform | SpotId::Irikar__Beach_Save__Save_Point | SpotId::Irikar__Beach_Save__Top_Platform | SpotId::Irikar__Beach_Save__West | SpotId::Irikar__Beach_Save__West_Mid_air | SpotId::Irikar__Boss_Room__Bulls_Feet | SpotId::Irikar__Boss_Room__Catwalk_East | SpotId::Irikar__Boss_Room__East_28 | SpotId::Irikar__Boss_Room__Healthy_Rooftop | SpotId::Irikar__Boss_Room__Upper_Rooftops | SpotId::Irikar__Boss_Room__West_28 | SpotId::Irikar__East_Rooftops__Directly_Down | SpotId::Irikar__East_Rooftops__East_Mid_air | SpotId::Irikar__East_Rooftops__Off_the_Edge | SpotId::Irikar__East_Rooftops__Top_Rooftop | SpotId::Irikar__Empty_Foyer__East | SpotId::Irikar__Empty_Foyer__West | SpotId::Irikar__Hub__Collapsed_Column | SpotId::Irikar__Hub__Collapsed_Column_Debris | SpotId::Irikar__Hub__Dagger_Altar | SpotId::Irikar__Hub__East_Rim | SpotId::Irikar__Hub__Portal_Stand | SpotId::Irikar__Hub__Royal_Storage_By_Wall | SpotId::Irikar__Hub__Royal_Storage_in_Wall | SpotId::Irikar__Hub__Royal_Storage_South | SpotId::Irikar__Hub__Ruined_Hallway_Atop_Well | SpotId::Irikar__Hub__Ruined_Hallway_By_Well | SpotId::Irikar__Hub__Sat_Tower_East_24 | SpotId::Irikar__Hub__Sat_Tower_Long_Ledge | SpotId::Irikar__Hub__Sat_Tower_Lower_Exterior | SpotId::Irikar__Hub__Sat_Tower_Northeast | SpotId::Irikar__Hub__Sat_Tower_Top_Ledge | SpotId::Irikar__Hub__Save_Point | SpotId::Irikar__Hub__SW_Building_Hallway_West | SpotId::Irikar__Hub__SW_Building_Top_Platform | SpotId::Irikar__Hub__West_23 | SpotId::Irikar__Hub__West_24_Ground | SpotId::Irikar__Hub__West_24_Rooftop | SpotId::Irikar__Hub__West_25 | SpotId::Irikar__Hub__West_26 | SpotId::Irikar__Hub__West_Rim | SpotId::Irikar__Lamassu__Catwalk_West | SpotId::Irikar__Lamassu__Desk | SpotId::Irikar__Lamassu__East_26 | SpotId::Irikar__Lamassu__East_27 | SpotId::Irikar__Lamassu__Hidden_Passage_East | SpotId::Irikar__Lamassu__West_28 | SpotId::Irikar__Midwest__East_24_on_Building | SpotId::Irikar__Midwest__East_24_on_Floor | SpotId::Irikar__Midwest__East_25 | SpotId::Irikar__Midwest__East_26 | SpotId::Irikar__Midwest__East_27 | SpotId::Irikar__Midwest__East_28 | SpotId::Irikar__Midwest__Left_Platform_Dest | SpotId::Irikar__Midwest__Left_Platform_Start | SpotId::Irikar__Midwest__Lower_Rock_West | SpotId::Irikar__Midwest__Right_Platform_Start | SpotId::Irikar__Midwest__Save_Point | SpotId::Irikar__Midwest__Tablet_Platform | SpotId::Irikar__Midwest__West_28_Ground | SpotId::Irikar__Midwest__West_28_Platform | SpotId::Irikar__Sight_Room__Above_Room_East | SpotId::Irikar__Sight_Room__Item_Pedestal | SpotId::Irikar__Sight_Room__Portal | SpotId::Irikar__Sight_Room__West_24 | SpotId::Irikar_Breach__Basement_Save__Save_Point | SpotId::Irikar_Breach__Basement_Save__Save_West | SpotId::Irikar_Breach__Basement_Save__West | SpotId::Irikar_Breach__Beach__Cache | SpotId::Irikar_Breach__Beach__East_26 | SpotId::Irikar_Breach__Beach__East_28 | SpotId::Irikar_Breach__Buried_Treasure__East | SpotId::Irikar_Breach__Buried_Treasure__Pillar_Bottom | SpotId::Irikar_Breach__Buried_Treasure__Pillar_Left | SpotId::Irikar_Breach__Buried_Treasure__Pillar_Right | SpotId::Irikar_Breach__Buried_Treasure__West | SpotId::Irikar_Breach__Corridor__East | SpotId::Irikar_Breach__Corridor__Save_Point | SpotId::Irikar_Breach__Corridor__West | SpotId::Irikar_Breach__Exit_Corridor__East | SpotId::Irikar_Breach__Exit_Corridor__North_12 | SpotId::Irikar_Breach__Exit_Corridor__North_13 | SpotId::Irikar_Breach__Exit_Corridor__Portal_Stand | SpotId::Irikar_Breach__Exit_Corridor__West | SpotId::Irikar_Breach__Flappy_Drone__Passage_Corner | SpotId::Irikar_Breach__Flappy_Drone__South | SpotId::Irikar_Breach__Flappy_Drone__West_Door | SpotId::Irikar_Breach__Four_way__East | SpotId::Irikar_Breach__Four_way__North | SpotId::Irikar_Breach__Four_way__South | SpotId::Irikar_Breach__Four_way__West | SpotId::Irikar_Breach__Gauntlet__East_22 | SpotId::Irikar_Breach__Gauntlet__Hidden_Path_Bottom | SpotId::Irikar_Breach__Gauntlet__Hidden_Path_Reward | SpotId::Irikar_Breach__Gauntlet__Lower_West_Ledge | SpotId::Irikar_Breach__Gauntlet__Save_Point | SpotId::Irikar_Breach__Gauntlet__West_26 | SpotId::Irikar_Breach__Hover_Room__Bottom | SpotId::Irikar_Breach__Hover_Room__East | SpotId::Irikar_Breach__Hover_Room__West | SpotId::Irikar_Breach__Neon_Corridor__East | SpotId::Irikar_Breach__Neon_Corridor__West | SpotId::Irikar_Breach__Rocky_Dunes__Center_Rock_West | SpotId::Irikar_Breach__Rocky_Dunes__Ditch | SpotId::Irikar_Breach__Rocky_Dunes__East_27 | SpotId::Irikar_Breach__Rocky_Dunes__East_28 | SpotId::Irikar_Breach__Rocky_Dunes__West_28 | SpotId::Irikar_Breach__Save_Room__Save_Point | SpotId::Irikar_Breach__Save_Room__West | SpotId::Irikar_Breach__Uhrum_Connector__East | SpotId::Irikar_Breach__Uhrum_Connector__Rock_East | SpotId::Irikar_Breach__Uhrum_Connector__Rock_East_Ledge | SpotId::Irikar_Breach__Uhrum_Connector__Rock_West | SpotId::Irikar_Breach__Uhrum_Connector__Rock_West_Ledge | SpotId::Irikar_Breach__Uhrum_Connector__Sand_East | SpotId::Irikar_Breach__Uhrum_Connector__Sand_Swarm | SpotId::Irikar_Breach__Uhrum_Connector__V_Rock_West | SpotId::Irikar_Breach__Uhrum_Connector__West | SpotId::Irikar_Breach__Worm_Rave__Corner | SpotId::Irikar_Breach__Worm_Rave__East | SpotId::Irikar_Breach__Worm_Rave__South | SpotId::Menu__Breach_Map__AGB_Bridge_Flask | SpotId::Menu__Breach_Map__AGB_Bridge_Lower | SpotId::Menu__Breach_Map__AGB_Bridge_Northwest | SpotId::Menu__Breach_Map__AGB_Bridge_Portal | SpotId::Menu__Breach_Map__AGB_Bridge_Upper | SpotId::Menu__Breach_Map__AGB_East | SpotId::Menu__Breach_Map__AGB_Enclosed | SpotId::Menu__Breach_Map__AGB_Factory_Foyer_Flask | SpotId::Menu__Breach_Map__AGB_Lower_Gate_Flask | SpotId::Menu__Breach_Map__AGB_North | SpotId::Menu__Breach_Map__AGB_Smiley_Health | SpotId::Menu__Breach_Map__AGB_Smiley_Northwest | SpotId::Menu__Breach_Map__AGB_South | SpotId::Menu__Breach_Map__Drone_Hover | SpotId::Menu__Breach_Map__Drone_Melee_Charge | SpotId::Menu__Breach_Map__Exit_Breach | SpotId::Menu__Breach_Map__GlB_Arena | SpotId::Menu__Breach_Map__GlB_Center | SpotId::Menu__Breach_Map__GlB_Control_Flask | SpotId::Menu__Breach_Map__GlB_East | SpotId::Menu__Breach_Map__GlB_Floaters | SpotId::Menu__Breach_Map__GlB_Grate_Flask | SpotId::Menu__Breach_Map__GlB_South | SpotId::Menu__Breach_Map__GlB_Spidery_Connector_Flask | SpotId::Menu__Breach_Map__GlB_West | SpotId::Menu__Breach_Map__GSB_Chimney_Flask | SpotId::Menu__Breach_Map__GSB_Crab_Rave_Flask | SpotId::Menu__Breach_Map__GSB_Cubby_Health | SpotId::Menu__Breach_Map__GSB_East | SpotId::Menu__Breach_Map__GSB_Emergence | SpotId::Menu__Breach_Map__GSB_Gate_and_Wall_Health | SpotId::Menu__Breach_Map__GSB_Gate_and_Wall_Portal | SpotId::Menu__Breach_Map__GSB_Labyrinth | SpotId::Menu__Breach_Map__GSB_Labyrinth_Flask | SpotId::Menu__Breach_Map__GSB_Lower_Machinery_Flask | SpotId::Menu__Breach_Map__GSB_Peak | SpotId::Menu__Breach_Map__GSB_Portal_Exchange_Lower | SpotId::Menu__Breach_Map__GSB_Portal_Exchange_Upper | SpotId::Menu__Breach_Map__GSB_South | SpotId::Menu__Breach_Map__GSB_SW_Save | SpotId::Menu__Breach_Map__Halusan | SpotId::Menu__Breach_Map__IB_Basement | SpotId::Menu__Breach_Map__IB_Beach_Flask | SpotId::Menu__Breach_Map__IB_Buried_Treasure | SpotId::Menu__Breach_Map__IB_Gauntlet | SpotId::Menu__Breach_Map__IB_Gauntlet_Flask | SpotId::Menu__Breach_Map__IB_Rocky_Dunes_Health | SpotId::Menu__Breach_Map__IB_Save_Room | SpotId::Menu__Breach_Map__IB_Uhrum_Connector_Flask | SpotId::Menu__Breach_Map__IB_West | SpotId::Menu__Breach_Map__IB_Worm_Rave_Flask | SpotId::Menu__Breach_Map__LAB_Center | SpotId::Menu__Breach_Map__LAB_Center_Ruins_Flask | SpotId::Menu__Breach_Map__LAB_East | SpotId::Menu__Breach_Map__LAB_East_Health | SpotId::Menu__Breach_Map__LAB_East_Ruins_Flask | SpotId::Menu__Breach_Map__LAB_Enclosed | SpotId::Menu__Breach_Map__LAB_Southeast | SpotId::Menu__Breach_Map__LAB_Southwest | SpotId::Menu__Breach_Map__LAB_Twisty_Passages_Flask | SpotId::Menu__Breach_Map__LAB_Upper_Lake_Health | SpotId::Menu__Breach_Map__LAB_West_Ruins_Flask | SpotId::Menu__Breach_Map__LAB_West_Ruins_Health | SpotId::Menu__Breach_Map__MEB_Drone | SpotId::Menu__Breach_Map__MEB_In_n_Out_Matrix | SpotId::Menu__Breach_Map__MEB_Lake_Gate | SpotId::Menu__Breach_Map__MEB_Peak | SpotId::Menu__Breach_Map__MEB_Rock_Block_Flask | SpotId::Menu__Breach_Map__MEB_Rocky_Cages_Flask | SpotId::Menu__Breach_Map__MEB_Snowy_Rocks_Flask | SpotId::Menu__Breach_Map__MEB_Southeast | SpotId::Menu__Breach_Map__MEB_Southwest | SpotId::Menu__Breach_Map__MEB_Spider_Guards_Button | SpotId::Menu__Breach_Map__MEB_Spider_Guards_Flask | SpotId::Menu__Breach_Map__MEB_Vertical_Room_Bottom | SpotId::Menu__Breach_Map__MEB_Vertical_Room_Flask | SpotId::Menu__Breach_Map__MEB_Vertical_Room_Portal | SpotId::Menu__Breach_Map__Nano_Lattice_3 | SpotId::Menu__Breach_Map__Robopede_Flask | SpotId::Menu__Breach_Map__Slingshot_Hook | SpotId::Menu__Breach_Map__Slingshot_Weapon | SpotId::Menu__Breach_Map__UVB_Blob | SpotId::Menu__Breach_Map__UVB_Center | SpotId::Menu__Breach_Map__UVB_Dead_end | SpotId::Menu__Breach_Map__UVB_East | SpotId::Menu__Breach_Map__UVB_East_Copse_Flask | SpotId::Menu__Breach_Map__UVB_East_Glitch_Health | SpotId::Menu__Breach_Map__UVB_East_Glitches | SpotId::Menu__Breach_Map__UVB_Overgrown_Ruins_Flask | SpotId::Menu__Breach_Map__UVB_Overgrown_Ruins_Portal | SpotId::Menu__Breach_Map__UVB_Save_and_Go_Portal | SpotId::Menu__Breach_Map__UVB_West | SpotId::Menu__Breach_Map__UVB_West_Glitches | SpotId::Menu__Emergence_Map__Audience_Chamber_Flask | SpotId::Menu__Emergence_Map__Camp | SpotId::Menu__Emergence_Map__Drop_off_Flask | SpotId::Menu__Emergence_Map__Slingshot_Charge | SpotId::Menu__Emergence_Map__Slingshot_Charge_East | SpotId::Menu__Emergence_Map__Storage_Portal | SpotId::Menu__Kiengir_Map__Aansur | SpotId::Menu__Kiengir_Map__Amagi_Divided_Room_Portal | SpotId::Menu__Kiengir_Map__Amagi_Dragon_Eye_Flask | SpotId::Menu__Kiengir_Map__Amagi_East_Lake | SpotId::Menu__Kiengir_Map__Amagi_Gated_Community_Button | SpotId::Menu__Kiengir_Map__Amagi_Gated_Community_Flask | SpotId::Menu__Kiengir_Map__Amagi_Isolation | SpotId::Menu__Kiengir_Map__Amagi_Isolation_Portal | SpotId::Menu__Kiengir_Map__Amagi_Main_Area | SpotId::Menu__Kiengir_Map__Amagi_Main_Area_Cave_Entrance | SpotId::Menu__Kiengir_Map__Amagi_Main_Area_Cave_Flask | SpotId::Menu__Kiengir_Map__Amagi_Main_Area_Water_Flask | SpotId::Menu__Kiengir_Map__Amagi_Secret_Chamber_Flask | SpotId::Menu__Kiengir_Map__Amagi_Secret_Chamber_Health | SpotId::Menu__Kiengir_Map__Amagi_West_Lake_Refill_Station | SpotId::Menu__Kiengir_Map__Amagi_Wiggly_Room_Gate | SpotId::Menu__Kiengir_Map__Amagi_Wiggly_Room_Portal | SpotId::Menu__Kiengir_Map__Annuna_Center_Save | SpotId::Menu__Kiengir_Map__Annuna_Double_Trouble_Bosses | SpotId::Menu__Kiengir_Map__Annuna_East_Bridge_Flask | SpotId::Menu__Kiengir_Map__Annuna_East_Hideout_Flask | SpotId::Menu__Kiengir_Map__Annuna_Egg_Room_Flask | SpotId::Menu__Kiengir_Map__Annuna_Egg_Room_SE | SpotId::Menu__Kiengir_Map__Annuna_Factory_Entrance | SpotId::Menu__Kiengir_Map__Annuna_Final_Cache | SpotId::Menu__Kiengir_Map__Annuna_Invisible_Enemies_Flask | SpotId::Menu__Kiengir_Map__Annuna_Invisible_Enemies_Portal | SpotId::Menu__Kiengir_Map__Annuna_Invisible_Enemies_Switch | SpotId::Menu__Kiengir_Map__Annuna_Mirror_Match | SpotId::Menu__Kiengir_Map__Annuna_Mirror_Match_Flask | SpotId::Menu__Kiengir_Map__Annuna_Secret_Passage_Flask | SpotId::Menu__Kiengir_Map__Annuna_Smiley_Room_Portal | SpotId::Menu__Kiengir_Map__Annuna_Smiley_Room_Southeast | SpotId::Menu__Kiengir_Map__Annuna_Sniper_Valley_Core | SpotId::Menu__Kiengir_Map__Annuna_Sniper_Valley_Flask | SpotId::Menu__Kiengir_Map__Annuna_Spider_Room_Core | SpotId::Menu__Kiengir_Map__Annuna_Twisty_Passages_Core | SpotId::Menu__Kiengir_Map__Annuna_Udug_Lair_Health | SpotId::Menu__Kiengir_Map__Annuna_Upper | SpotId::Menu__Kiengir_Map__Annuna_Upper_Hallway_Portal | SpotId::Menu__Kiengir_Map__Annuna_Vertical_Room | SpotId::Menu__Kiengir_Map__Annuna_West_Bridge | SpotId::Menu__Kiengir_Map__Annuna_West_Bridge_Upper | SpotId::Menu__Kiengir_Map__Anuman | SpotId::Menu__Kiengir_Map__Apocalypse | SpotId::Menu__Kiengir_Map__Assault_Sword | SpotId::Menu__Kiengir_Map__Becoming_An_Arm | SpotId::Menu__Kiengir_Map__Beware_the_Patternmind | SpotId::Menu__Kiengir_Map__Boomerang | SpotId::Menu__Kiengir_Map__Bounty_List | SpotId::Menu__Kiengir_Map__Breach_Attractor | SpotId::Menu__Kiengir_Map__Breach_Sight | SpotId::Menu__Kiengir_Map__Bronze_Axe | SpotId::Menu__Kiengir_Map__Building_of_the_School | SpotId::Menu__Kiengir_Map__Carnelian_Ring | SpotId::Menu__Kiengir_Map__Commemorative_Speech | SpotId::Menu__Kiengir_Map__Compass | SpotId::Menu__Kiengir_Map__Dangerous_Ideas | SpotId::Menu__Kiengir_Map__Destruction_Pogrom | SpotId::Menu__Kiengir_Map__Diviners_Gem | SpotId::Menu__Kiengir_Map__Does_Technology | SpotId::Menu__Kiengir_Map__Double_Axe | SpotId::Menu__Kiengir_Map__Dr_Gloria | SpotId::Menu__Kiengir_Map__Ebih_Alu_Flask | SpotId::Menu__Kiengir_Map__Ebih_Alu_Health | SpotId::Menu__Kiengir_Map__Ebih_Base_Camp | SpotId::Menu__Kiengir_Map__Ebih_Base_Camp_East | SpotId::Menu__Kiengir_Map__Ebih_Base_Camp_Health | SpotId::Menu__Kiengir_Map__Ebih_Base_Camp_West | SpotId::Menu__Kiengir_Map__Ebih_By_Garage_Health | SpotId::Menu__Kiengir_Map__Ebih_By_Garage_NW | SpotId::Menu__Kiengir_Map__Ebih_Drone_Pit_Flask | SpotId::Menu__Kiengir_Map__Ebih_East_Health | SpotId::Menu__Kiengir_Map__Ebih_East_Waterfall_Bottom | SpotId::Menu__Kiengir_Map__Ebih_East_Waterfall_Door | SpotId::Menu__Kiengir_Map__Ebih_East_Waterfall_Flask | SpotId::Menu__Kiengir_Map__Ebih_Garage | SpotId::Menu__Kiengir_Map__Ebih_Gem_Room_Button | SpotId::Menu__Kiengir_Map__Ebih_Gem_Room_Flask | SpotId::Menu__Kiengir_Map__Ebih_Guarded_Health | SpotId::Menu__Kiengir_Map__Ebih_Hidden_Portal_Button | SpotId::Menu__Kiengir_Map__Ebih_Hidden_Portal_Portal | SpotId::Menu__Kiengir_Map__Ebih_High_Flask | SpotId::Menu__Kiengir_Map__Ebih_Observatory | SpotId::Menu__Kiengir_Map__Ebih_Party_Central_Flask | SpotId::Menu__Kiengir_Map__Ebih_Party_Central_Portal | SpotId::Menu__Kiengir_Map__Ebih_Peak_Center | SpotId::Menu__Kiengir_Map__Ebih_Peak_Portal | SpotId::Menu__Kiengir_Map__Ebih_Peak_West | SpotId::Menu__Kiengir_Map__Ebih_Truck_Gate | SpotId::Menu__Kiengir_Map__Ebih_Waterfall_Cave | SpotId::Menu__Kiengir_Map__Ebih_Waterfall_West_9 | SpotId::Menu__Kiengir_Map__Ebih_West_Face_Flask | SpotId::Menu__Kiengir_Map__Ebih_West_Flask | SpotId::Menu__Kiengir_Map__Ebih_West_Lower | SpotId::Menu__Kiengir_Map__Ebih_West_Mid | SpotId::Menu__Kiengir_Map__Ebih_West_Upper | SpotId::Menu__Kiengir_Map__Ensis_Bracelet | SpotId::Menu__Kiengir_Map__Eye_Ring | SpotId::Menu__Kiengir_Map__Family_Tragedy | SpotId::Menu__Kiengir_Map__Filter_Flask | SpotId::Menu__Kiengir_Map__Filter_Spiders | SpotId::Menu__Kiengir_Map__Forbidden_Knowledge | SpotId::Menu__Kiengir_Map__Freedom_from_Aansur | SpotId::Menu__Kiengir_Map__Giguna_Base | SpotId::Menu__Kiengir_Map__Giguna_Breachable_Wall_Flask | SpotId::Menu__Kiengir_Map__Giguna_Cache | SpotId::Menu__Kiengir_Map__Giguna_Caverns_Core | SpotId::Menu__Kiengir_Map__Giguna_Caverns_Matrix | SpotId::Menu__Kiengir_Map__Giguna_Clouds_Flask | SpotId::Menu__Kiengir_Map__Giguna_Dual_Path_Health | SpotId::Menu__Kiengir_Map__Giguna_East_Caverns_Flask | SpotId::Menu__Kiengir_Map__Giguna_Far_Corner | SpotId::Menu__Kiengir_Map__Giguna_Gateway_Block | SpotId::Menu__Kiengir_Map__Giguna_Gateway_Flask | SpotId::Menu__Kiengir_Map__Giguna_Gubi_Flask | SpotId::Menu__Kiengir_Map__Giguna_Highest_Flask | SpotId::Menu__Kiengir_Map__Giguna_Labyrinth | SpotId::Menu__Kiengir_Map__Giguna_Labyrinth_Empty_Plinth | SpotId::Menu__Kiengir_Map__Giguna_Labyrinth_Hole | SpotId::Menu__Kiengir_Map__Giguna_Lamassu_Flask | SpotId::Menu__Kiengir_Map__Giguna_Mural_Flask | SpotId::Menu__Kiengir_Map__Giguna_Mural_NE | SpotId::Menu__Kiengir_Map__Giguna_Mural_Portal | SpotId::Menu__Kiengir_Map__Giguna_Northeast | SpotId::Menu__Kiengir_Map__Giguna_Northeast_Flask | SpotId::Menu__Kiengir_Map__Giguna_Northeast_Gate | SpotId::Menu__Kiengir_Map__Giguna_Ruins_Top | SpotId::Menu__Kiengir_Map__Giguna_Ruins_Top_Flask | SpotId::Menu__Kiengir_Map__Giguna_Ruins_Top_Portal | SpotId::Menu__Kiengir_Map__Giguna_Ruins_Top_SW | SpotId::Menu__Kiengir_Map__Giguna_Ruins_West | SpotId::Menu__Kiengir_Map__Giguna_Secrecy_Flask | SpotId::Menu__Kiengir_Map__Giguna_Secrecy_Portal | SpotId::Menu__Kiengir_Map__Giguna_Separator | SpotId::Menu__Kiengir_Map__Giguna_Separator_Save | SpotId::Menu__Kiengir_Map__Giguna_Vertical_Interchange_Flask | SpotId::Menu__Kiengir_Map__Giguna_Wasteland_Core | SpotId::Menu__Kiengir_Map__Giguna_Wasteland_Flask | SpotId::Menu__Kiengir_Map__Giguna_Wasteland_Health | SpotId::Menu__Kiengir_Map__Giguna_Wasteland_Switch | SpotId::Menu__Kiengir_Map__Glacier_Angry_Guards_Portal | SpotId::Menu__Kiengir_Map__Glacier_Dock_Flask | SpotId::Menu__Kiengir_Map__Glacier_East_Revival_Flask | SpotId::Menu__Kiengir_Map__Glacier_Fortress_Flask_Left | SpotId::Menu__Kiengir_Map__Glacier_Fortress_Health | SpotId::Menu__Kiengir_Map__Glacier_Fortress_Northeast | SpotId::Menu__Kiengir_Map__Glacier_Fortress_Portal | SpotId::Menu__Kiengir_Map__Glacier_Gudam | SpotId::Menu__Kiengir_Map__Glacier_Lake_Main_Entrance_Flask | SpotId::Menu__Kiengir_Map__Glacier_Peak_Birds_Eye | SpotId::Menu__Kiengir_Map__Glacier_Revival | SpotId::Menu__Kiengir_Map__Glacier_Sea_Burial_Health | SpotId::Menu__Kiengir_Map__Glacier_Sliced_Cavern_Health | SpotId::Menu__Kiengir_Map__Glacier_Vertical_Room_Flask | SpotId::Menu__Kiengir_Map__Hammond | SpotId::Menu__Kiengir_Map__Hammonds_Health | SpotId::Menu__Kiengir_Map__Heretics_Granddaughter | SpotId::Menu__Kiengir_Map__Heretics_Tablet | SpotId::Menu__Kiengir_Map__Infect | SpotId::Menu__Kiengir_Map__Irikar_10_25 | SpotId::Menu__Kiengir_Map__Irikar_Abandoned_Room | SpotId::Menu__Kiengir_Map__Irikar_Airy_Flask | SpotId::Menu__Kiengir_Map__Irikar_Basement_Core | SpotId::Menu__Kiengir_Map__Irikar_Beach_Cache | SpotId::Menu__Kiengir_Map__Irikar_Beach_Save | SpotId::Menu__Kiengir_Map__Irikar_Broken_Wall | SpotId::Menu__Kiengir_Map__Irikar_Fast_Travel | SpotId::Menu__Kiengir_Map__Irikar_Gudam | SpotId::Menu__Kiengir_Map__Irikar_Gudam_Health | SpotId::Menu__Kiengir_Map__Irikar_Hub | SpotId::Menu__Kiengir_Map__Irikar_Mid_air_Flask | SpotId::Menu__Kiengir_Map__Irikar_Midwest | SpotId::Menu__Kiengir_Map__Irikar_Royal_Storage | SpotId::Menu__Kiengir_Map__Irikar_Well | SpotId::Menu__Kiengir_Map__Journal_10_29_2049 | SpotId::Menu__Kiengir_Map__Kari | SpotId::Menu__Kiengir_Map__Lament_for_Fools | SpotId::Menu__Kiengir_Map__Ledge_Grab | SpotId::Menu__Kiengir_Map__MUS_A_M20_Flask | SpotId::Menu__Kiengir_Map__Nanite_Mist | SpotId::Menu__Kiengir_Map__Nano_Lattice_1 | SpotId::Menu__Kiengir_Map__Nano_Lattice_2 | SpotId::Menu__Kiengir_Map__Plague_of_Thoughts | SpotId::Menu__Kiengir_Map__Remote_Boomerang | SpotId::Menu__Kiengir_Map__Remote_Drone | SpotId::Menu__Kiengir_Map__Royal_Ring | SpotId::Menu__Kiengir_Map__Sagiga_on_the_Worldstream | SpotId::Menu__Kiengir_Map__Shockwave | SpotId::Menu__Kiengir_Map__Storm_Bomb | SpotId::Menu__Kiengir_Map__Suspension_Bridge | SpotId::Menu__Kiengir_Map__Terminal_Breakthrough_1 | SpotId::Menu__Kiengir_Map__Terminal_Breakthrough_2 | SpotId::Menu__Kiengir_Map__The_Eternal_Arm | SpotId::Menu__Kiengir_Map__The_Ideal_Kiengir | SpotId::Menu__Kiengir_Map__The_Student | SpotId::Menu__Kiengir_Map__Udusan | SpotId::Menu__Kiengir_Map__Uhrum_Annuna_Corridor_Udugs | SpotId::Menu__Kiengir_Map__Uhrum_Annuna_Flask | SpotId::Menu__Kiengir_Map__Uhrum_Center | SpotId::Menu__Kiengir_Map__Uhrum_East | SpotId::Menu__Kiengir_Map__Uhrum_East_Lake_Cave | SpotId::Menu__Kiengir_Map__Uhrum_Emergence | SpotId::Menu__Kiengir_Map__Uhrum_Emergence_Facility_Entrance | SpotId::Menu__Kiengir_Map__Uhrum_Emergence_Facility_Flask | SpotId::Menu__Kiengir_Map__Uhrum_Emergence_Facility_Wall | SpotId::Menu__Kiengir_Map__Uhrum_Fast_Travel | SpotId::Menu__Kiengir_Map__Uhrum_Glen_Matrix | SpotId::Menu__Kiengir_Map__Uhrum_Glitchy_Corridor_East_Glitch | SpotId::Menu__Kiengir_Map__Uhrum_Glitchy_Corridor_Flask | SpotId::Menu__Kiengir_Map__Uhrum_Glitchy_Corridor_Portal | SpotId::Menu__Kiengir_Map__Uhrum_Glitchy_Corridor_Rock | SpotId::Menu__Kiengir_Map__Uhrum_Rocky_Guardpost_Flask | SpotId::Menu__Kiengir_Map__Uhrum_Seclusion_Flask | SpotId::Menu__Kiengir_Map__Uhrum_Seclusion_Portal | SpotId::Menu__Kiengir_Map__Uhrum_Siege_Corridor_Core | SpotId::Menu__Kiengir_Map__Uhrum_Siege_Corridor_Health | SpotId::Menu__Kiengir_Map__Uhrum_Spring_Health | SpotId::Menu__Kiengir_Map__Uhrum_Waterfalls_Flask | SpotId::Menu__Kiengir_Map__Uhrum_West | SpotId::Menu__Kiengir_Map__Uhrum_West_Portal_Entrance | SpotId::Menu__Kiengir_Map__Uhrum_West_Portal_Exit | SpotId::Menu__Kiengir_Map__Ul_Gishru | SpotId::Menu__Kiengir_Map__Under_Siege | SpotId::Menu__Kiengir_Map__Urgent_Message | SpotId::Menu__Kiengir_Map__Wall_Climb | SpotId::Menu__Kiengir_Map__Water_Movement | SpotId::Menu__Kiengir_Map__Weekly_Notes_2_27_2053 | SpotId::Menu__Upgrade_Menu__Combat | SpotId::Menu__Upgrade_Menu__Drone | SpotId::Menu__Upgrade_Menu__Infection | SpotId::Menu__Upgrade_Menu__Physiology | SpotId::Uhrum__Annuna_Corridor__Between_Two_Flowers | SpotId::Uhrum__Annuna_Corridor__Block_East | SpotId::Uhrum__Annuna_Corridor__Block_West | SpotId::Uhrum__Annuna_Corridor__East_25 | SpotId::Uhrum__Annuna_Corridor__East_Cubby | SpotId::Uhrum__Annuna_Corridor__Open_Pillar | SpotId::Uhrum__Annuna_Corridor__Pedestal | SpotId::Uhrum__Annuna_Corridor__Save_Point | SpotId::Uhrum__Annuna_Corridor__Statue | SpotId::Uhrum__Annuna_Corridor__Upper_Trees | SpotId::Uhrum__Annuna_Corridor__Wall_Remnant | SpotId::Uhrum__Annuna_Corridor__West_25 | SpotId::Uhrum__Annuna_Corridor__West_26 | SpotId::Uhrum__Artillery_Practice__East_23 | SpotId::Uhrum__Artillery_Practice__East_24 | SpotId::Uhrum__Artillery_Practice__Tunnel_Entrance | SpotId::Uhrum__Artillery_Practice__West_23 | SpotId::Uhrum__Cavern__Pedestal | SpotId::Uhrum__Cavern__Portal_Hole | SpotId::Uhrum__Cavern__Wall_Left | SpotId::Uhrum__Cavern__Wall_Right | SpotId::Uhrum__Cavern__West | SpotId::Uhrum__Cavern__West_Ledge | SpotId::Uhrum__East_Lake__East | SpotId::Uhrum__East_Lake__East_Block | SpotId::Uhrum__East_Lake__East_Ledge | SpotId::Uhrum__East_Lake__Island_East | SpotId::Uhrum__East_Lake__Island_West | SpotId::Uhrum__East_Lake__Waterfall | SpotId::Uhrum__East_Lake__West_27 | SpotId::Uhrum__East_Lake__West_28 | SpotId::Uhrum__East_Lake__West_Block | SpotId::Uhrum__East_Lake__West_Underwater_Ledge | SpotId::Uhrum__Emergence_Facility__Balcony | SpotId::Uhrum__Emergence_Facility__Door | SpotId::Uhrum__Emergence_Facility__East | SpotId::Uhrum__Emergence_Facility__Middle_Level_Mid_air | SpotId::Uhrum__Emergence_Facility__Roof_Middle | SpotId::Uhrum__Emergence_Facility__Wall_East_Ledge | SpotId::Uhrum__Emergence_Facility__Wall_East_Mid_air | SpotId::Uhrum__Emergence_Facility__Wall_West_Ledge | SpotId::Uhrum__Emergence_Facility__Wall_West_Mid_air | SpotId::Uhrum__Emergence_Facility__West | SpotId::Uhrum__Emergence_Save__East | SpotId::Uhrum__Emergence_Save__Save_Point | SpotId::Uhrum__Emergence_Save__West | SpotId::Uhrum__Glen__Center | SpotId::Uhrum__Glen__East | SpotId::Uhrum__Glitchy_Corridor__Behind_West_Glitch | SpotId::Uhrum__Glitchy_Corridor__Center_Platform_East | SpotId::Uhrum__Glitchy_Corridor__Center_Platform_West | SpotId::Uhrum__Glitchy_Corridor__East_27 | SpotId::Uhrum__Glitchy_Corridor__East_28 | SpotId::Uhrum__Glitchy_Corridor__East_Glitch_East | SpotId::Uhrum__Glitchy_Corridor__East_Glitch_West | SpotId::Uhrum__Glitchy_Corridor__East_Hookable | SpotId::Uhrum__Glitchy_Corridor__East_Platform | SpotId::Uhrum__Glitchy_Corridor__Middle_Lower | SpotId::Uhrum__Glitchy_Corridor__Northwest_Platform | SpotId::Uhrum__Glitchy_Corridor__Portal_Stand | SpotId::Uhrum__Glitchy_Corridor__Wall_East | SpotId::Uhrum__Glitchy_Corridor__Wall_East_Platform | SpotId::Uhrum__Glitchy_Corridor__Wall_West | SpotId::Uhrum__Glitchy_Corridor__Wall_West_Platform | SpotId::Uhrum__Glitchy_Corridor__West_28 | SpotId::Uhrum__Glitchy_Corridor__West_Glitch_Ledge | SpotId::Uhrum__Glitchy_Corridor__West_Glitch_West | SpotId::Uhrum__Glitchy_Corridor__West_Pillar_East | SpotId::Uhrum__Grassy_Guardpost__East_22 | SpotId::Uhrum__Grassy_Guardpost__East_23 | SpotId::Uhrum__Grassy_Guardpost__East_Ledge | SpotId::Uhrum__Grassy_Guardpost__West_22 | SpotId::Uhrum__Rocky_Gate__East | SpotId::Uhrum__Rocky_Gate__East_Ledge | SpotId::Uhrum__Rocky_Gate__Wall_East | SpotId::Uhrum__Rocky_Gate__Wall_East_Ledge | SpotId::Uhrum__Rocky_Gate__Wall_West | SpotId::Uhrum__Rocky_Gate__Wall_West_Ledge | SpotId::Uhrum__Rocky_Gate__West | SpotId::Uhrum__Rocky_Guardpost__East_22 | SpotId::Uhrum__Rocky_Guardpost__Lower_Step | SpotId::Uhrum__Rocky_Guardpost__Middle_Step | SpotId::Uhrum__Rocky_Guardpost__Under_The_Ledge | SpotId::Uhrum__Rocky_Guardpost__Upper_Step | SpotId::Uhrum__Rocky_Guardpost__West_22 | SpotId::Uhrum__Rocky_Guardpost__West_23 | SpotId::Uhrum__Save_Room__East | SpotId::Uhrum__Save_Room__Save_Point | SpotId::Uhrum__Save_Room__West | SpotId::Uhrum__Seclusion__Boome_Range | SpotId::Uhrum__Seclusion__Close_Range | SpotId::Uhrum__Seclusion__Portal_East | SpotId::Uhrum__Seclusion__Portal_Stand | SpotId::Uhrum__Seclusion__Portal_West | SpotId::Uhrum__Seclusion__Shock_Range | SpotId::Uhrum__Siege_Corridor__Center_Box | SpotId::Uhrum__Siege_Corridor__East_25 | SpotId::Uhrum__Siege_Corridor__Northwest_Door | SpotId::Uhrum__Siege_Corridor__Pond | SpotId::Uhrum__Siege_Corridor__Upper_Rock_Item | SpotId::Uhrum__Siege_Corridor__West_26 | SpotId::Uhrum__Siege_Corridor__Western_Cache | SpotId::Uhrum__Spring__Center | SpotId::Uhrum__Spring__West | SpotId::Uhrum__Tulip_Tower__East_23 | SpotId::Uhrum__Tulip_Tower__Lower_Column | SpotId::Uhrum__Tulip_Tower__Middle_Plateau | SpotId::Uhrum__Tulip_Tower__North | SpotId::Uhrum__Tulip_Tower__Still_Hovering | SpotId::Uhrum__Tulip_Tower__Upper_Middle_Ledge | SpotId::Uhrum__Tulip_Tower__West_22 | SpotId::Uhrum__Tulip_Tower__West_24 | SpotId::Uhrum__Waterfalls__Above_Block | SpotId::Uhrum__Waterfalls__Barrier_East | SpotId::Uhrum__Waterfalls__Barrier_West | SpotId::Uhrum__Waterfalls__Below_Block | SpotId::Uhrum__Waterfalls__Ceiling_Cache | SpotId::Uhrum__Waterfalls__Center_Island_East | SpotId::Uhrum__Waterfalls__Center_Island_Middle | SpotId::Uhrum__Waterfalls__Center_Island_West | SpotId::Uhrum__Waterfalls__East_24 | SpotId::Uhrum__Waterfalls__East_25 | SpotId::Uhrum__Waterfalls__East_26 | SpotId::Uhrum__Waterfalls__East_27 | SpotId::Uhrum__Waterfalls__East_28 | SpotId::Uhrum__Waterfalls__East_Waters_Edge | SpotId::Uhrum__Waterfalls__Sloped_Rock | SpotId::Uhrum__Waterfalls__Water_Pillar | SpotId::Uhrum__Waterfalls__West_24 | SpotId::Uhrum__Waterfalls__West_25 | SpotId::Uhrum__Waterfalls__West_27 | SpotId::Uhrum__Waterfalls__West_Water_Ledge | SpotId::Uhrum__Waterfalls__West_Water_Nook | SpotId::Uhrum__West_Entrance__East_26 | SpotId::Uhrum__West_Entrance__East_28 | SpotId::Uhrum__West_Entrance__Gate_Switch | SpotId::Uhrum__West_Entrance__Gate_West | SpotId::Uhrum__West_Entrance__Gate_West_Ledge | SpotId::Uhrum__West_Entrance__Hidden_Passage_West | SpotId::Uhrum__West_Entrance__Inner_Dais | SpotId::Uhrum__West_Entrance__Lower_Path_Rock | SpotId::Uhrum__West_Entrance__Lower_Path_Tree_Ledge | SpotId::Uhrum__West_Entrance__Lower_Wall_East | SpotId::Uhrum__West_Entrance__Lower_Wall_West | SpotId::Uhrum__West_Entrance__Portal_Exit | SpotId::Uhrum__West_Entrance__Portal_Stand | SpotId::Uhrum__West_Entrance__Sand | SpotId::Uhrum__West_Entrance__Save_Point | SpotId::Uhrum__West_Entrance__South_Platform | SpotId::Uhrum__West_Entrance__Top_West_Ledge | SpotId::Uhrum__West_Entrance__Upper_Wall_East | SpotId::Uhrum__West_Entrance__Upper_Wall_West | SpotId::Uhrum__West_Entrance__West_25 | SpotId::Uhrum__West_Entrance__West_26 | SpotId::Uhrum__West_Entrance__West_27 | SpotId::Uhrum_Breach__Annuna_Gate__Button | SpotId::Uhrum_Breach__Annuna_Gate__East | SpotId::Uhrum_Breach__Annuna_Gate__North | SpotId::Uhrum_Breach__Annuna_Gate__Save_Point | SpotId::Uhrum_Breach__Annuna_Gate__Structure | SpotId::Uhrum_Breach__Annuna_Gate__Structure_West | SpotId::Uhrum_Breach__Annuna_Gate__West | SpotId::Uhrum_Breach__Blob__East_Side | SpotId::Uhrum_Breach__Blob__Plinth | SpotId::Uhrum_Breach__Blob__West_Side | SpotId::Uhrum_Breach__East_Copse__Center_East | SpotId::Uhrum_Breach__East_Copse__East | SpotId::Uhrum_Breach__East_Copse__Hidden_Cache | SpotId::Uhrum_Breach__East_Copse__Lower_West_Ledge | SpotId::Uhrum_Breach__East_Copse__Platform_East | SpotId::Uhrum_Breach__East_Copse__Platform_West | SpotId::Uhrum_Breach__East_Copse__West | SpotId::Uhrum_Breach__East_Glitch__East_27 | SpotId::Uhrum_Breach__East_Glitch__East_28 | SpotId::Uhrum_Breach__East_Glitch__East_Hookable | SpotId::Uhrum_Breach__East_Glitch__Glitch_East | SpotId::Uhrum_Breach__East_Glitch__Glitch_West | SpotId::Uhrum_Breach__East_Glitch__Middle_Lower | SpotId::Uhrum_Breach__East_Glitch__Northeast_Flower | SpotId::Uhrum_Breach__East_Glitch__Save_Point | SpotId::Uhrum_Breach__East_Glitch__Wall_East_Platform | SpotId::Uhrum_Breach__East_Glitch__Wall_West_Platform | SpotId::Uhrum_Breach__East_Glitch__West | SpotId::Uhrum_Breach__Locked_Down__Button | SpotId::Uhrum_Breach__Locked_Down__East_Enemy | SpotId::Uhrum_Breach__Locked_Down__Item_Behind_Gate | SpotId::Uhrum_Breach__Locked_Down__Save_Point | SpotId::Uhrum_Breach__Locked_Down__South | SpotId::Uhrum_Breach__Locked_Down__West | SpotId::Uhrum_Breach__Locked_Down__West_Path | SpotId::Uhrum_Breach__Locked_Down__West_Stone_East | SpotId::Uhrum_Breach__North_Slime__Center | SpotId::Uhrum_Breach__North_Slime__East | SpotId::Uhrum_Breach__North_Slime__West | SpotId::Uhrum_Breach__Overgrown_Ruins__Cache | SpotId::Uhrum_Breach__Overgrown_Ruins__East | SpotId::Uhrum_Breach__Overgrown_Ruins__Portal_Stand | SpotId::Uhrum_Breach__Overgrown_Ruins__Two_Guards | SpotId::Uhrum_Breach__Overgrown_Ruins__Wall_East | SpotId::Uhrum_Breach__Overgrown_Ruins__Wall_West | SpotId::Uhrum_Breach__Rock_and_Gate__Button | SpotId::Uhrum_Breach__Rock_and_Gate__Center_Area_West | SpotId::Uhrum_Breach__Rock_and_Gate__East | SpotId::Uhrum_Breach__Rock_and_Gate__East_Ledge | SpotId::Uhrum_Breach__Rock_and_Gate__Gate_West | SpotId::Uhrum_Breach__Rock_and_Gate__North | SpotId::Uhrum_Breach__Rock_and_Gate__Rock_East | SpotId::Uhrum_Breach__Rock_and_Gate__Rock_East_Ledge | SpotId::Uhrum_Breach__Rock_and_Gate__Rock_West | SpotId::Uhrum_Breach__Rock_and_Gate__South | SpotId::Uhrum_Breach__Rock_and_Gate__West | SpotId::Uhrum_Breach__Save_and_Go__Mild_Slope | SpotId::Uhrum_Breach__Save_and_Go__North | SpotId::Uhrum_Breach__Save_and_Go__Portal_Stand | SpotId::Uhrum_Breach__Save_and_Go__Save_Point | SpotId::Uhrum_Breach__Save_and_Go__South | SpotId::Uhrum_Breach__Save_and_Go__Stone_Mouth | SpotId::Uhrum_Breach__Save_and_Go__West_Rock_Ledge | SpotId::Uhrum_Breach__Save_and_Go__West_Stone_Ledge | SpotId::Uhrum_Breach__Save_and_Go__West_Stone_Wall | SpotId::Uhrum_Breach__South_Slime__Center | SpotId::Uhrum_Breach__South_Slime__East | SpotId::Uhrum_Breach__South_Slime__West | SpotId::Uhrum_Breach__West_Copse__Center_East | SpotId::Uhrum_Breach__West_Copse__Center_Pillar | SpotId::Uhrum_Breach__West_Copse__Center_West | SpotId::Uhrum_Breach__West_Copse__East | SpotId::Uhrum_Breach__West_Copse__East_Ledge | SpotId::Uhrum_Breach__West_Copse__Lower_West_Ledge | SpotId::Uhrum_Breach__West_Copse__West_27 | SpotId::Uhrum_Breach__West_Copse__West_28 | SpotId::Uhrum_Breach__West_Glitch__Narrow_Glitch | SpotId::Uhrum_Breach__West_Glitch__North | SpotId::Uhrum_Breach__West_Glitch__Past_Glitch | SpotId::Uhrum_Breach__West_Glitch__Pillar_West => true,
@@ -14817,9 +14844,18 @@ unsafe impl bytemuck::Zeroable for World {}
 impl World {
     pub fn update_skippable_locations(&mut self) {
         let unused_items = self.get_unused_items();
+        let (goal_items, goal_locations): (FxHashSet<_>, FxHashSet<_>) = match &self.goal {
+            Some(goal) => (
+                goal.items.iter().map(|(item, _)| *item).collect(),
+                goal.locations.iter().copied().collect(),
+            ),
+            None => Default::default(),
+        };
         for loc in self.locations.values_mut() {
             let item = loc.item();
-            let skippable = unused_item(item) || unused_items.contains(&item);
+            let skippable = (unused_item(item) || unused_items.contains(&item))
+                && !goal_items.contains(&item)
+                && !goal_locations.contains(&loc.id());
             loc.set_skippable(skippable);
         }
     }
@@ -14859,6 +14895,10 @@ impl World {
     }
 
     fn get_unused_items(&self) -> FxHashSet<Item> {
+        // The goal may need any item, which the rules can't tell us.
+        if self.goal.is_some() {
+            return FxHashSet::default();
+        }
         match self.rule_victory {
             RuleVictory::Default => {
                 let mut set = match self.rule_objective {
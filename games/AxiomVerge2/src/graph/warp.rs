@@ -254,6 +254,20 @@ impl world::Warp for Warp {
             WarpId::Portal => true,
         }
     }
+    fn dest_from_context(&self) -> bool {
+        self.dest == SpotId::None
+            && match self.id {
+                WarpId::BreachSave => true,
+                WarpId::EarthSave => true,
+                WarpId::ExitBreach => false,
+                WarpId::ExitMenu => true,
+                WarpId::FastTravelBreach => false,
+                WarpId::FastTravelKiengir => false,
+                WarpId::MainSave => true,
+                WarpId::Menu => false,
+                WarpId::Portal => false,
+            }
+    }
     fn observe_effects(&self, ctx: &mut Context, world: &World, full_obs: &mut FullObservation) {
         match self.id {
             WarpId::BreachSave => {
@@ -0,0 +1,15 @@
+//! AUTOGENERATED FOR Axiom Verge 2 - MODIFICATIONS WILL BE LOST
+
+use analyzer::testlib::fuzz::*;
+use libaxiom_verge2::context::Context;
+use libaxiom_verge2::graph::World;
+
+// Set FUZZ_SEED to try a different seed.
+#[test]
+fn fuzz_logic() {
+    let world = World::new();
+    let options = FuzzOptions::from_env();
+    if let Err(failure) = fuzz_world(&*world, &Context::default(), &options) {
+        panic!("{}", failure);
+    }
+}
@@ -20,7 +20,7 @@ use yaml_rust::Yaml;
 
 pub mod enums {
 use std::fmt;
-#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Ord, PartialOrd, Default, serde_repr::Serialize_repr, serde_repr::Deserialize_repr)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Ord, PartialOrd, enum_map::Enum, Default, serde_repr::Serialize_repr, serde_repr::Deserialize_repr)]
 #[repr(u8)]
 pub enum Tod {
     #[default]
@@ -861,6 +861,17 @@ impl context::Ctx for Context {
         Ok(())
     }
 
+    // soundness helper for context vars
+    fn mutate_context(&mut self, field: usize, value: u64) {
+        match field % 4 {
+            0 => self.set_child(value & 1 == 1),
+            1 => self.set_tod(<enums::Tod as enum_map::Enum>::from_usize(value as usize % <enums::Tod as enum_map::Enum>::LENGTH)),
+            2 => self.set_rupees(value as i32),
+            3 => self.set_deku_tree__compass_room__ctx__torch(value & 1 == 1),
+            _ => unreachable!(),
+        }
+    }
+
     fn parse_expect_context(ckey: &str, cval: &Yaml) -> Result<Expectation, String> {
         Ok(match (ckey, cval) {
             ("position", Yaml::String(s)) => Expectation::Position(SpotId::from_str(s).map_err(|e| format!("{}", e))?),
@@ -2060,6 +2071,17 @@ fn observe_reset_area(&mut self, area_id: AreaId, world: &World, full_obs: &mut
         }
     }
 
+    fn dominates(&self, other: &Context) -> bool {
+        self.position == other.position
+            && self.tod == other.tod
+            && self.rupees >= other.rupees
+            && self.gold_skulltula_token >= other.gold_skulltula_token
+            && self.progressive_wallet >= other.progressive_wallet
+            && self.triforce_piece >= other.triforce_piece
+            && context::dominates_bits(self.cbits1.bits().into(), other.cbits1.bits().into(), 0x7ffffffc, 0x0)
+            && self.cbits2 == other.cbits2
+    }
+
     fn diff(&self, old: &Context) -> String {
         let mut list: Vec<String> = Vec::new();
 
@@ -105,6 +105,11 @@ impl world::Action for Action {
             _ => SpotId::None,
         }
     }
+    fn dest_from_context(&self) -> bool {
+        match self.id {
+            _ => false,
+        }
+    }
     fn observe_effects(&self, ctx: &mut Context, world: &World, full_obs: &mut FullObservation) {
         match self.id {
             ActionId::Global__Change_Time => {
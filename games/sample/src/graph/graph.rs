@@ -10,6 +10,7 @@ use crate::items::*;
 use crate::movements;
 use analyzer::condense::{condense_graph, CondensedEdge};
 use analyzer::context::Ctx;
+use analyzer::goal::Goal;
 use analyzer::world;
 use analyzer::world::Location as _;
 use enum_map::{enum_map, Enum, EnumMap};
@@ -226,6 +227,8 @@ lazy_static! {
 pub struct World {
     pub rule_victory: RuleVictory,
     pub rule_objective: RuleObjective,
+    // runtime goal from the settings file, replacing the victory rules
+    pub goal: Option<Goal<World>>,
     // settings
     pub triforce_count: i16,
     pub logic_deku_b1_skip: bool,
@@ -260,6 +263,7 @@ impl world::World for World {
 
         world.rule_victory = RuleVictory::default();
         world.rule_objective = RuleObjective::default();
+        world.goal = None;
         world.triforce_count = Default::default();
         world.logic_deku_b1_skip = Default::default();
         world.global_actions = Range {
@@ -278,6 +282,9 @@ impl world::World for World {
     }
 
     fn ruleset(&self) -> String {
+        if let Some(goal) = &self.goal {
+            return format!("[goal: {}]", goal);
+        }
         format!(
             "[$victory: {}, $objective: {}]",
             self.rule_victory,
@@ -356,6 +363,9 @@ impl world::World for World {
     fn same_area(sp1: SpotId, sp2: SpotId) -> bool {
         get_area(sp1) == get_area(sp2)
     }
+    fn get_spot_region(spot_id: SpotId) -> RegionId {
+        get_region(spot_id)
+    }
     fn get_area_spots(&self, spot_id: SpotId) -> &[SpotId] {
         if spot_id == SpotId::None {
             &RAW_SPOTS[0..0]
@@ -396,10 +406,20 @@ impl world::World for World {
     }
 
     fn won(&self, ctx: &Context) -> bool {
+        if let Some(goal) = &self.goal {
+            return goal.met(ctx);
+        }
         crate::rule__victory!(ctx, self)
     }
 
+    fn goal(&self) -> Option<&Goal<World>> {
+        self.goal.as_ref()
+    }
+
     fn items_needed(&self, ctx: &Context) -> Vec<(Item, i16)> {
+        if let Some(goal) = &self.goal {
+            return goal.items_needed(ctx);
+        }
         let mut map = FxHashMap::default();
 
         match self.rule_victory {
@@ -444,6 +464,9 @@ impl world::World for World {
     }
 
     fn required_items(&self) -> Vec<(Item, i16)> {
+        if let Some(goal) = &self.goal {
+            return goal.items.clone();
+        }
         let mut map = FxHashMap::default();
 
         match self.rule_victory {
@@ -641,6 +664,10 @@ impl world::World for World {
         spot_distance_or_inf(a, b)
     }
 
+    fn spot_coordinate(spot: SpotId) -> Option<(f32, f32)> {
+        coordinate(spot).ok()
+    }
+
     fn spot_of_interest(&self, sp: SpotId) -> bool {
         match sp {
             SpotId::Deku_Tree__Back_Room__East | SpotId::Deku_Tree__Back_Room__Northwest | SpotId::Deku_Tree__Basement_1__Center | SpotId::Deku_Tree__Basement_1__Corner | SpotId::Deku_Tree__Basement_1__South_Door | SpotId::Deku_Tree__Basement_2__Boss_Door | SpotId::Deku_Tree__Basement_2__Pool | SpotId::Deku_Tree__Basement_Ledge__Block | SpotId::Deku_Tree__Basement_Ledge__Web | SpotId::Deku_Tree__Boss_Room__Arena | SpotId::Deku_Tree__Compass_Room__Compass | SpotId::Deku_Tree__Compass_Room__Entry | SpotId::Deku_Tree__Compass_Room__Ledge | SpotId::Deku_Tree__Floor_2__Lower | SpotId::Deku_Tree__Floor_2__Slingshot_Door | SpotId::Deku_Tree__Floor_2__Vines | SpotId::Deku_Tree__Floor_3__Door | SpotId::Deku_Tree__Lobby__Center | SpotId::Deku_Tree__Lobby__Entry | SpotId::Deku_Tree__Lobby__Vines | SpotId::Deku_Tree__Scrub_Room__Entry | SpotId::Deku_Tree__Scrub_Room__Rear | SpotId::Deku_Tree__Skull_Room__Entry | SpotId::Deku_Tree__Slingshot_Room__Entry | SpotId::Deku_Tree__Slingshot_Room__Slingshot | SpotId::Deku_Tree__Slingshot_Upper__Ledge | SpotId::Kak__Spider_House__Entry | SpotId::KF__Baba_Corridor__Deku_Babas | SpotId::KF__Baba_Corridor__Tree_Side | SpotId::KF__Baba_Corridor__Village_Side | SpotId::KF__Boulder_Maze__Entry | SpotId::KF__Boulder_Maze__Reward | SpotId::KF__Know_it_all_House__Entry | SpotId::KF__Kokiri_Village__Know_it_all_Porch | SpotId::KF__Kokiri_Village__Links_Porch | SpotId::KF__Kokiri_Village__Midos_Guardpost | SpotId::KF__Kokiri_Village__Midos_Porch | SpotId::KF__Kokiri_Village__Sarias_Porch | SpotId::KF__Kokiri_Village__Shop_Porch | SpotId::KF__Kokiri_Village__Training_Center | SpotId::KF__Links_House__Entry | SpotId::KF__Midos_House__Entry | SpotId::KF__Outside_Deku_Tree__Entry | SpotId::KF__Outside_Deku_Tree__Left | SpotId::KF__Outside_Deku_Tree__Mouth | SpotId::KF__Outside_Deku_Tree__Right | SpotId::KF__Shop__Entry => true,
@@ -686,9 +713,18 @@ unsafe impl bytemuck::Zeroable for World {}
 impl World {
     pub fn update_skippable_locations(&mut self) {
         let unused_items = self.get_unused_items();
+        let (goal_items, goal_locations): (FxHashSet<_>, FxHashSet<_>) = match &self.goal {
+            Some(goal) => (
+                goal.items.iter().map(|(item, _)| *item).collect(),
+                goal.locations.iter().copied().collect(),
+            ),
+            None => Default::default(),
+        };
         for loc in self.locations.values_mut() {
             let item = loc.item();
-            let skippable = unused_item(item) || unused_items.contains(&item);
+            let skippable = (unused_item(item) || unused_items.contains(&item))
+                && !goal_items.contains(&item)
+                && !goal_locations.contains(&loc.id());
             loc.set_skippable(skippable);
         }
     }
@@ -712,6 +748,10 @@ impl World {
     }
 
     fn get_unused_items(&self) -> FxHashSet<Item> {
+        // The goal may need any item, which the rules can't tell us.
+        if self.goal.is_some() {
+            return FxHashSet::default();
+        }
         match self.rule_victory {
             RuleVictory::Default => {
                 let mut set = match self.rule_objective {
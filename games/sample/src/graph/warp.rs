@@ -112,6 +112,13 @@ impl world::Warp for Warp {
             WarpId::Save => false,
         }
     }
+    fn dest_from_context(&self) -> bool {
+        self.dest == SpotId::None
+            && match self.id {
+                WarpId::Minuet => false,
+                WarpId::Save => false,
+            }
+    }
     fn observe_effects(&self, ctx: &mut Context, world: &World, full_obs: &mut FullObservation) {
         match self.id {
             WarpId::Minuet => {
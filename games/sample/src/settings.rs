@@ -4,6 +4,7 @@ use crate::context::Context;
 use crate::graph;
 use analyzer::context::ContextWrapper;
 use analyzer::estimates::ContextScorer;
+use analyzer::goal::Goal;
 use analyzer::route::route_from_yaml_string;
 use analyzer::settings::*;
 use analyzer::world::World;
@@ -31,6 +32,9 @@ fn read_key_value(
                 }
             }
         }
+        Some("goal") => {
+            world.goal = Some(Goal::from_yaml(world, val)?);
+        }
         Some("triforce_count") => {
             world.triforce_count = parse_int(key, val)?;
         }
@@ -50,6 +54,8 @@ pub fn load_settings(filename: Option<&PathBuf>) -> (Box<graph::World>, Context,
     let ctx = Context::default();
     let mut vec = Vec::new();
     let route_key = Yaml::String(String::from("routes"));
+    let db_key = Yaml::String(String::from("db"));
+    let passes_key = Yaml::String(String::from("passes"));
     if let Some(filename) = filename {
         let mut file = File::open(filename).unwrap_or_else(|e| panic!("Couldn't open file \"{:?}\": {:?}", filename, e));
         let mut settings = String::new();
@@ -68,6 +74,8 @@ pub fn load_settings(filename: Option<&PathBuf>) -> (Box<graph::World>, Context,
                 } else {
                     errs.push(format!("routes must be list of strings, but was {:?}", value));
                 }
+            } else if key == &db_key || key == &passes_key {
+                // db tuning and minimize passes are read by the cli
             } else if let Err(e) = read_key_value(world.as_mut(), key, value) {
                 errs.push(e);
             }
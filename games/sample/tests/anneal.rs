@@ -0,0 +1,136 @@
+use analyzer::anneal::{anneal_collection_order, AnnealOptions, AnnealStats};
+use analyzer::context::*;
+use analyzer::direct::DirectPathsMap;
+use analyzer::estimates::ContextScorer;
+use analyzer::goal::Goal;
+use analyzer::route::{route_from_string, PartialRoute};
+use analyzer::world::World;
+use libsample::context::Context;
+use libsample::graph;
+use libsample::observe::ObservationMatcher;
+use std::time::Duration;
+use yaml_rust::YamlLoader;
+
+type Paths = DirectPathsMap<
+    graph::World,
+    Context,
+    ObservationMatcher<PartialRoute<Context>, Option<PartialRoute<Context>>>,
+>;
+
+const CHESTS: &str = r#"
+locations:
+  - KF > Boulder Maze > Reward > Chest
+  - KF > Shop > Entry > Blue Rupee
+  - KF > Mido's House > Entry > Top Left Chest
+  - KF > Mido's House > Entry > Top Right Chest
+"#;
+
+// Goes back and forth between Mido's House and the Shop.
+const SLOW_ROUTE: &str = r#"
+* Collect Kokiri_Sword from KF > Boulder Maze > Reward > Chest
+* Collect Rupees_50 from KF > Mido's House > Entry > Top Right Chest
+* Collect Rupees_5 from KF > Shop > Entry > Blue Rupee
+* Collect Rupees_5 from KF > Mido's House > Entry > Top Left Chest
+"#;
+
+fn options(seed: u64, initial_temp: f64, final_temp: f64) -> AnnealOptions {
+    AnnealOptions {
+        seed,
+        time_budget: Duration::from_secs(3600),
+        max_iterations: Some(100),
+        initial_temp,
+        final_temp,
+        max_depth: 2,
+        max_states: 4096,
+    }
+}
+
+fn anneal(
+    world: &graph::World,
+    options: &AnnealOptions,
+) -> (
+    ContextWrapper<Context>,
+    Option<ContextWrapper<Context>>,
+    Vec<u32>,
+    AnnealStats,
+) {
+    let startctx = Context::default();
+    let scorer = ContextScorer::shortest_paths(world, &startctx, 32_768);
+    let direct_paths = Paths::new(ContextScorer::shortest_paths_tree_free_edges(
+        world, &startctx,
+    ));
+    let slow = route_from_string(world, &startctx, SLOW_ROUTE, scorer.get_algo()).unwrap();
+    assert!(world.won(slow.get()));
+
+    let mut improvements = Vec::new();
+    let (best, stats) = anneal_collection_order(
+        world,
+        &startctx,
+        slow.to_solution(),
+        options,
+        scorer.get_algo(),
+        &direct_paths,
+        |better| improvements.push(better.elapsed()),
+    );
+    (slow, best, improvements, stats)
+}
+
+fn chests_world() -> Box<graph::World> {
+    let mut world = graph::World::new();
+    let goal = YamlLoader::load_from_str(CHESTS).unwrap().remove(0);
+    world.goal = Some(Goal::from_yaml(&*world, &goal).unwrap());
+    world
+}
+
+#[test]
+fn test_anneal_improves() {
+    let world = chests_world();
+    let (slow, best, improvements, stats) = anneal(&world, &options(7, 500.0, 5.0));
+    assert_eq!(stats.iterations, 100);
+
+    // Every reported route is a strict improvement on the last, starting from the input.
+    let best = best.expect("Annealing didn't improve the route");
+    assert!(!improvements.is_empty());
+    assert!(improvements[0] < slow.elapsed());
+    assert!(improvements.windows(2).all(|w| w[1] < w[0]));
+    assert_eq!(*improvements.last().unwrap(), best.elapsed());
+
+    let mut replay = ContextWrapper::new(Context::default());
+    for step in best.recent_history() {
+        replay.assert_and_replay(&*world, *step);
+    }
+    assert_eq!(replay.elapsed(), best.elapsed());
+    assert!(world.won(replay.get()));
+}
+
+#[test]
+fn test_anneal_seed_reproduces_run() {
+    let world = chests_world();
+    // A high temperature accepts worse routes, so the run depends on every random draw.
+    let opts = options(42, 5000.0, 100.0);
+    let (_, best1, improvements1, stats1) = anneal(&world, &opts);
+    let (_, best2, improvements2, stats2) = anneal(&world, &opts);
+    assert_eq!(improvements1, improvements2);
+    assert_eq!(
+        best1.as_ref().map(|c| c.recent_history()),
+        best2.as_ref().map(|c| c.recent_history())
+    );
+    assert_eq!(
+        (stats1.accepted, stats1.worse_accepted, stats1.failed),
+        (stats2.accepted, stats2.worse_accepted, stats2.failed)
+    );
+}
+
+#[test]
+#[should_panic(expected = "must be positive")]
+fn test_anneal_rejects_zero_temperature() {
+    let world = chests_world();
+    anneal(&world, &options(1, 500.0, 0.0));
+}
+
+#[test]
+#[should_panic(expected = "must be positive")]
+fn test_anneal_rejects_negative_temperature() {
+    let world = chests_world();
+    anneal(&world, &options(1, -10.0, 5.0));
+}
@@ -0,0 +1,95 @@
+use analyzer::backward::BackwardReachability;
+use analyzer::context::*;
+use analyzer::estimates::UNREASONABLE_TIME;
+use analyzer::goal::Goal;
+use analyzer::greedy::greedy_search;
+use analyzer::search::single_step;
+use analyzer::world::World;
+use libsample::context::Context;
+use libsample::graph;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use yaml_rust::YamlLoader;
+
+/// Finds the fastest win by exhaustive uniform-cost search over the search's own steps.
+fn optimal_win(
+    world: &graph::World,
+    startctx: &ContextWrapper<Context>,
+) -> ContextWrapper<Context> {
+    let mut best = HashMap::new();
+    let mut states = vec![startctx.clone()];
+    let mut heap = BinaryHeap::new();
+    best.insert(startctx.get().clone(), startctx.elapsed());
+    heap.push(Reverse((startctx.elapsed(), 0)));
+    while let Some(Reverse((elapsed, idx))) = heap.pop() {
+        let ctx = states[idx].clone();
+        if elapsed > best[ctx.get()] {
+            continue;
+        }
+        if world.won(ctx.get()) {
+            return ctx;
+        }
+        for next in single_step(world, ctx, UNREASONABLE_TIME) {
+            if best.get(next.get()).is_some_and(|&t| t <= next.elapsed()) {
+                continue;
+            }
+            best.insert(next.get().clone(), next.elapsed());
+            heap.push(Reverse((next.elapsed(), states.len())));
+            states.push(next);
+        }
+    }
+    panic!("No win found from {:?}", startctx);
+}
+
+#[test]
+fn test_lower_bound_along_optimal_route() {
+    let mut world = graph::World::new();
+    let goal = YamlLoader::load_from_str("items: [Kokiri_Sword, Buy_Deku_Shield]")
+        .unwrap()
+        .remove(0);
+    world.goal = Some(Goal::from_yaml(&*world, &goal).unwrap());
+    let startctx = ContextWrapper::new(Context::default());
+    let backward = BackwardReachability::new(&*world, startctx.get());
+
+    let win = optimal_win(&world, &startctx);
+    let greedy = greedy_search(&*world, &startctx, u32::MAX, 2).expect("Greedy search didn't win");
+    assert!(win.elapsed() <= greedy.elapsed());
+    assert!(backward.lower_bound(&*world, startctx.get()) > 0);
+
+    // Every state along an optimal route has the rest of that route as its best remaining time.
+    let mut ctx = startctx;
+    for (i, step) in win.recent_history().iter().enumerate() {
+        let bound = backward.lower_bound(&*world, ctx.get());
+        assert!(
+            ctx.elapsed().saturating_add(bound) <= win.elapsed(),
+            "Bound {} before step {} ({}) exceeds the optimal remaining {}ms",
+            bound,
+            i,
+            step,
+            win.elapsed() - ctx.elapsed()
+        );
+        ctx.assert_and_replay(&*world, *step);
+    }
+    assert_eq!(ctx.elapsed(), win.elapsed());
+    assert_eq!(backward.lower_bound(&*world, ctx.get()), 0);
+}
+
+#[test]
+fn test_last_pickups() {
+    let world = graph::World::new();
+    let startctx = Context::default();
+    let backward = BackwardReachability::new(&*world, &startctx);
+    let start = startctx.position();
+    let last = backward.last_pickups(start);
+    assert!(!last.is_empty());
+
+    // The last pickups are the farthest required items, and bound the time from the start.
+    let farthest = backward.time_to_collect(last[0], start).unwrap();
+    for (item, _) in world.required_items() {
+        assert!(backward.time_to_collect(item, start).unwrap() <= farthest);
+    }
+    for &item in last {
+        assert_eq!(backward.time_to_collect(item, start), Some(farthest));
+    }
+    assert!(backward.lower_bound(&*world, &startctx) <= farthest);
+}
@@ -1,17 +1,30 @@
 #![allow(unused)]
 
 use analyzer::access::move_to;
-use analyzer::context::{history_to_partial_route, ContextWrapper, Ctx, Wrapper};
-use analyzer::db::RouteDb;
+use analyzer::context::{history_to_partial_route, ContextWrapper, Ctx, History, Wrapper};
+use analyzer::db::{check_db_dir, DbMetadata, HeapDB, RouteDb, SolutionDb, VersionMismatch};
 use analyzer::estimates::ContextScorer;
+use analyzer::greedy::greedy_search;
+use analyzer::matchertrie::MatcherTrie;
+use analyzer::observer::record_stored_observations;
 use analyzer::route::route_from_string;
+use analyzer::scoring::{ScoreMetric, TimeSinceAndElapsed};
+use analyzer::search::{Search, SearchOptions};
+use analyzer::solutions::{Solution, SolutionSuffix};
 use analyzer::storage::ContextDB;
-use analyzer::testlib::db::{all_keys_cf, TestRouteDb};
+use analyzer::testlib::db::{all_keys_cf, stamp_db, stamp_route_db, stamp_state_db, TestRouteDb};
 use analyzer::world::World;
+use analyzer::CommonHasher;
 use base64::prelude::*;
 use libsample::context::Context;
-use libsample::graph;
+use libsample::graph::{self, LocationId, SpotId};
 use libsample::items::Item;
+use libsample::observe::ObservationMatcher;
+use std::collections::HashSet;
+
+type Matcher =
+    ObservationMatcher<SolutionSuffix<Context>, HashSet<SolutionSuffix<Context>, CommonHasher>>;
+type Db = HeapDB<'static, graph::World, Context, 16, TimeSinceAndElapsed<'static, graph::World>>;
 
 #[test]
 fn test_route_db() {
@@ -137,6 +150,246 @@ fn test_route_db() {
     assert_ne!(&r3, &*route.route);
 }
 
+#[test]
+fn test_route_db_list_prune_migrate() {
+    let world = graph::World::new();
+    let startctx = Context::default();
+    let mut entryctx = startctx.clone();
+    entryctx.set_position_raw(SpotId::KF__Links_House__Entry);
+    let scorer = ContextScorer::shortest_paths(&*world, &startctx, 32_768);
+    let db_path = std::env::temp_dir().join(format!("sample-routes-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&db_path);
+    let other = DbMetadata {
+        version: String::from("other"),
+        ..DbMetadata::current::<Context>()
+    };
+    let open = || {
+        let (opts, cache) = RouteDb::<Context>::test_options();
+        RouteDb::<Context>::open_migrating(&db_path, opts, cache, &startctx, &*world).unwrap()
+    };
+    let route_to = |ctx: &Context, dest| {
+        let moved = move_to(
+            &*world,
+            ContextWrapper::new(ctx.clone()),
+            dest,
+            scorer.get_algo(),
+        )
+        .unwrap();
+        history_to_partial_route(ctx, &*world, moved.recent_history().iter().copied())
+    };
+
+    // The route from the house entry starts with an exit, and from the start point with a local move.
+    let midos = route_to(&entryctx, SpotId::KF__Kokiri_Village__Midos_Porch);
+    let reward = route_to(&startctx, SpotId::KF__Boulder_Maze__Reward);
+    let rdb = open();
+    rdb.insert_route(
+        &entryctx,
+        &*world,
+        SpotId::KF__Kokiri_Village__Midos_Porch,
+        &midos,
+    );
+    rdb.insert_route(
+        &startctx,
+        &*world,
+        SpotId::KF__Boulder_Maze__Reward,
+        &reward,
+    );
+
+    let routes = rdb.list_routes(None).unwrap();
+    assert_eq!(routes.len(), 2);
+    assert_eq!(routes[0].route_id, 1);
+    assert_eq!(
+        routes[0].dest,
+        Some(SpotId::KF__Kokiri_Village__Midos_Porch)
+    );
+    assert_eq!(routes[0].steps, midos.route.len());
+    assert_eq!(routes[0].time, midos.time);
+    assert_eq!(routes[1].route_id, 2);
+    assert_eq!(routes[1].dest, Some(SpotId::KF__Boulder_Maze__Reward));
+    assert!(routes.iter().all(|r| r.references > 0));
+    let routes = rdb
+        .list_routes(Some(SpotId::KF__Boulder_Maze__Reward))
+        .unwrap();
+    assert_eq!(routes.len(), 1);
+    assert_eq!(routes[0].route_id, 2);
+
+    // Pruning a destination forgets its observations and then its unreferenced routes.
+    let (removed_keys, removed_routes) = rdb.prune(Some(SpotId::KF__Boulder_Maze__Reward)).unwrap();
+    assert!(removed_keys > 0);
+    assert_eq!(removed_routes, 1);
+    assert_eq!(rdb.list_routes(None).unwrap().len(), 1);
+    assert!(rdb
+        .best_known_route(&startctx, SpotId::KF__Boulder_Maze__Reward)
+        .unwrap()
+        .is_none());
+    assert_eq!(rdb.prune(None).unwrap(), (0, 0));
+
+    // Migrating keeps the routes that replay from the spot they start at.
+    rdb.insert_route(
+        &startctx,
+        &*world,
+        SpotId::KF__Boulder_Maze__Reward,
+        &reward,
+    );
+    drop(rdb);
+    stamp_route_db(&db_path, &other);
+    let rdb = open();
+    let routes = rdb.list_routes(None).unwrap();
+    assert_eq!(routes.len(), 1);
+    assert_eq!(
+        routes[0].dest,
+        Some(SpotId::KF__Kokiri_Village__Midos_Porch)
+    );
+    let r = rdb
+        .best_known_route(&entryctx, SpotId::KF__Kokiri_Village__Midos_Porch)
+        .unwrap()
+        .expect("No route to Mido's Porch after migrating");
+    assert_eq!(&r, &*midos.route);
+    drop(rdb);
+
+    // Opening without migrating resets the routes.
+    stamp_route_db(&db_path, &other);
+    let (opts, cache) = RouteDb::<Context>::test_options();
+    let rdb = RouteDb::<Context>::open(&db_path, opts, cache, false).unwrap();
+    assert!(rdb.list_routes(None).unwrap().is_empty());
+    assert_eq!(rdb.num_routes(), 1);
+    drop(rdb);
+    std::fs::remove_dir_all(&db_path).unwrap();
+}
+
+#[test]
+fn test_solution_db() {
+    let world = graph::World::new();
+    let startctx = Context::default();
+    let db_path = std::env::temp_dir().join(format!("sample-solutions-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&db_path);
+    let scorer = ContextScorer::shortest_paths(&*world, &startctx, 32_768);
+    let win = greedy_search(&*world, &ContextWrapper::new(startctx.clone()), u32::MAX, 2)
+        .expect("Greedy search didn't win");
+    let detour = move_to(
+        &*world,
+        win.clone(),
+        SpotId::KF__Kokiri_Village__Midos_Porch,
+        scorer.get_algo(),
+    )
+    .unwrap();
+    let loaded = |sdb: &SolutionDb<Context>| {
+        let trie = MatcherTrie::<Matcher, SolutionSuffix<Context>>::default();
+        let count = record_stored_observations(&startctx, &*world, sdb, &trie).unwrap();
+        (count, trie.lookup(&startctx))
+    };
+
+    let sdb = SolutionDb::<Context>::open(&db_path).unwrap();
+    sdb.insert(&startctx, &*world, &win.to_solution()).unwrap();
+    // Storing the same solution again is a no-op.
+    sdb.insert(&startctx, &*world, &win.to_solution()).unwrap();
+    // A solution that doesn't replay from the start is an error.
+    let bad = Solution {
+        elapsed: 0,
+        history: vec![History::G(
+            Item::Kokiri_Sword,
+            LocationId::KF__Boulder_Maze__Reward__Chest,
+        )],
+    };
+    assert!(sdb.insert(&startctx, &*world, &bad).is_err());
+    drop(sdb);
+
+    // Solutions persist across reopening, and new ones get new ids.
+    let sdb = SolutionDb::<Context>::open(&db_path).unwrap();
+    let solutions = sdb.solutions().unwrap();
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solutions[0].history, win.recent_history());
+    assert_eq!(solutions[0].elapsed, win.elapsed());
+    sdb.insert(&startctx, &*world, &detour.to_solution())
+        .unwrap();
+    let mut ids: Vec<_> = sdb
+        .solutions_by_id()
+        .unwrap()
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect();
+    ids.sort();
+    assert_eq!(ids, vec![0, 1]);
+
+    // The stored trie finds the solutions from the start state.
+    let (count, found) = loaded(&sdb);
+    assert_eq!(count, 2);
+    assert!(found
+        .iter()
+        .any(|SolutionSuffix(s, idx)| *idx == 0 && s.history == win.recent_history()));
+    drop(sdb);
+
+    // A different build discards them, keeping their routes as text if asked.
+    stamp_db(
+        &db_path,
+        &DbMetadata {
+            version: String::from("other"),
+            ..DbMetadata::current::<Context>()
+        },
+    );
+    let (sdb, stale) = SolutionDb::<Context>::open_keeping_stale(&db_path, true).unwrap();
+    assert_eq!(stale.len(), 2);
+    assert!(sdb.solutions().unwrap().is_empty());
+    assert_eq!(loaded(&sdb), (0, Vec::new()));
+    for route in &stale {
+        route_from_string(&*world, &startctx, route, scorer.get_algo()).unwrap();
+    }
+    drop(sdb);
+
+    let (sdb, stale) = SolutionDb::<Context>::open_keeping_stale(&db_path, true).unwrap();
+    assert!(stale.is_empty());
+    drop(sdb);
+    std::fs::remove_dir_all(&db_path).unwrap();
+}
+
+#[test]
+fn test_migrate_rebuilds_stale_dbs() {
+    let world: &'static graph::World = Box::leak(graph::World::new());
+    let db_path = std::env::temp_dir().join(format!("sample-migrate-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&db_path);
+    stamp_state_db(
+        &db_path,
+        &DbMetadata {
+            version: String::from("other"),
+            ..DbMetadata::for_states::<Context>(16)
+        },
+    );
+    assert!(check_db_dir::<Context, _>(&db_path, 16)
+        .unwrap_err()
+        .is::<VersionMismatch>());
+
+    let open = |migrate| {
+        let startctx = Context::default();
+        let metric = TimeSinceAndElapsed::new(world, &startctx);
+        let options = SearchOptions {
+            migrate,
+            queue_memory: 1 << 20,
+            ..SearchOptions::default()
+        };
+        Search::<_, _, Matcher, 16, _, Db>::new(
+            world,
+            startctx,
+            Vec::new(),
+            metric,
+            &db_path,
+            None,
+            options,
+        )
+    };
+
+    // Without --migrate, the search refuses the dbs and leaves them alone.
+    let err = open(false)
+        .err()
+        .expect("Search opened a db from another build");
+    assert!(err.to_string().contains("--migrate"), "{}", err);
+    assert!(check_db_dir::<Context, _>(&db_path, 16).is_err());
+
+    // With it, they're rebuilt and stamped by this build.
+    drop(open(true).unwrap());
+    check_db_dir::<Context, _>(&db_path, 16).unwrap();
+    std::fs::remove_dir_all(&db_path).unwrap();
+}
+
 #[cfg(feature = "mysql")]
 #[test]
 fn test_mysql() {
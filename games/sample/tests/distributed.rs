@@ -0,0 +1,113 @@
+use analyzer::db::{DbMetadata, HeapDB};
+use analyzer::distributed::{
+    read_message, run_worker, write_message, CoordinatorReply, Reply, WorkerRequest,
+};
+use analyzer::goal::Goal;
+use analyzer::scoring::{ScoreMetric, TimeSinceAndElapsed};
+use analyzer::search::{Search, SearchOptions};
+use analyzer::solutions::SolutionSuffix;
+use analyzer::CommonHasher;
+use libsample::context::Context;
+use libsample::graph;
+use libsample::observe::ObservationMatcher;
+use std::collections::HashSet;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::time::Duration;
+use yaml_rust::YamlLoader;
+
+type Matcher =
+    ObservationMatcher<SolutionSuffix<Context>, HashSet<SolutionSuffix<Context>, CommonHasher>>;
+type Db = HeapDB<'static, graph::World, Context, 16, TimeSinceAndElapsed<'static, graph::World>>;
+
+fn connect(addr: SocketAddr) -> TcpStream {
+    for _ in 0..100 {
+        if let Ok(stream) = TcpStream::connect(addr) {
+            stream
+                .set_read_timeout(Some(Duration::from_secs(60)))
+                .unwrap();
+            return stream;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    panic!("Couldn't connect to coordinator at {}", addr);
+}
+
+#[test]
+fn test_coordinator_and_worker() {
+    // A small goal so the search finishes within the test.
+    let mut world = graph::World::new();
+    let goal = YamlLoader::load_from_str("items: [Kokiri_Sword, Buy_Deku_Shield]")
+        .unwrap()
+        .remove(0);
+    world.goal = Some(Goal::from_yaml(&*world, &goal).unwrap());
+    let world: &'static graph::World = Box::leak(world);
+    let addr = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let db_path = std::env::temp_dir().join(format!("sample-distributed-{}", std::process::id()));
+
+    let coordinator = {
+        let db_path = db_path.clone();
+        std::thread::spawn(move || {
+            let startctx = Context::default();
+            let metric = TimeSinceAndElapsed::new(world, &startctx);
+            let options = SearchOptions {
+                listen: Some(addr),
+                queue_memory: 1 << 20,
+                ..SearchOptions::default()
+            };
+            Search::<_, _, Matcher, 16, _, Db>::new(
+                world,
+                startctx,
+                Vec::new(),
+                metric,
+                &db_path,
+                None,
+                options,
+            )
+            .unwrap()
+            .search()
+            .unwrap();
+        })
+    };
+
+    // Results before Hello are rejected.
+    let mut stream = connect(addr);
+    write_message(
+        &mut stream,
+        &WorkerRequest::<Context>::Work {
+            results: Vec::new(),
+            pull: 1,
+        },
+    )
+    .unwrap();
+    match read_message::<CoordinatorReply<Context>>(&mut stream).unwrap() {
+        Some(Reply::Rejected(_)) => (),
+        r => panic!("Expected rejection, got {:?}", r),
+    }
+
+    // So is a worker from another build.
+    let mut stream = connect(addr);
+    write_message(
+        &mut stream,
+        &WorkerRequest::<Context>::Hello {
+            metadata: DbMetadata {
+                version: String::from("other"),
+                ..DbMetadata::current::<Context>()
+            },
+            pull: 1,
+        },
+    )
+    .unwrap();
+    match read_message::<CoordinatorReply<Context>>(&mut stream).unwrap() {
+        Some(Reply::Rejected(e)) => assert!(e.contains("does not match"), "{}", e),
+        r => panic!("Expected rejection, got {:?}", r),
+    }
+
+    // A worker runs until the coordinator tells it the search is finished.
+    let worker = run_worker(world, addr, 4);
+    coordinator.join().unwrap();
+    std::fs::remove_dir_all(&db_path).unwrap();
+    worker.unwrap();
+}
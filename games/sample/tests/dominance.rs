@@ -0,0 +1,94 @@
+use analyzer::context::{ContextWrapper, Ctx};
+use analyzer::db::HeapDB;
+use analyzer::dbprofile::DbProfile;
+use analyzer::encoding::StateCodec;
+use analyzer::scoring::TimeSinceAndElapsed;
+use analyzer::storage::ContextDB;
+use libsample::context::Context;
+use libsample::graph::{self, LocationId, SpotId};
+use libsample::items::Item;
+
+type Db<'w> = HeapDB<'w, graph::World, Context, 16, TimeSinceAndElapsed<'w, graph::World>>;
+
+fn with_rupees(rupees: i32) -> Context {
+    let mut ctx = Context::default();
+    ctx.rupees = rupees;
+    ctx
+}
+
+#[test]
+fn test_monotone_fields() {
+    // Game.yaml declares rupees and items increasing; everything else is exact.
+    let base = Context::default();
+    let mut more = base.clone();
+    more.add_item(Item::Kokiri_Sword);
+    more.add_item(Item::Gold_Skulltula_Token);
+    more.rupees = 5;
+    assert!(more.dominates(&base));
+    assert!(!base.dominates(&more));
+    assert!(base.dominates(&base));
+
+    // Item flags share a bit group with context vars, which must still match.
+    let mut adult = more.clone();
+    adult.set_child(false);
+    assert!(!adult.dominates(&base));
+    assert!(!base.dominates(&adult));
+
+    let mut visited = more.clone();
+    visited.visit(LocationId::KF__Boulder_Maze__Reward__Chest);
+    assert!(!visited.dominates(&base));
+    assert!(!more.dominates(&visited));
+
+    let mut moved = more.clone();
+    moved.set_position_raw(SpotId::KF__Boulder_Maze__Reward);
+    assert!(!moved.dominates(&base));
+}
+
+#[test]
+fn test_dominated_states_skipped() {
+    let world = graph::World::new();
+    let startctx = Context::default();
+    let metric = TimeSinceAndElapsed::new(&*world, &startctx);
+    let db_path = std::env::temp_dir().join(format!("sample-dominance-{}", std::process::id()));
+    let db = Db::open(
+        &db_path,
+        u32::MAX,
+        metric,
+        true,
+        StateCodec::rmp(),
+        true,
+        &DbProfile::default(),
+    )
+    .unwrap();
+
+    db.push(ContextWrapper::with_times(with_rupees(20), 1000, 0), None)
+        .unwrap();
+    assert_eq!(db.len(), 1);
+
+    // Fewer rupees, no faster: dominated.
+    db.push(ContextWrapper::with_times(with_rupees(5), 2000, 0), None)
+        .unwrap();
+    db.push(ContextWrapper::with_times(with_rupees(5), 1000, 0), None)
+        .unwrap();
+    assert_eq!(db.len(), 1);
+    assert_eq!(db.seen(), 1);
+
+    // Faster, more rupees, or a different position: not dominated.
+    db.push(ContextWrapper::with_times(with_rupees(5), 500, 0), None)
+        .unwrap();
+    db.push(ContextWrapper::with_times(with_rupees(30), 3000, 0), None)
+        .unwrap();
+    let mut moved = with_rupees(0);
+    moved.set_position_raw(SpotId::KF__Boulder_Maze__Reward);
+    db.push(ContextWrapper::with_times(moved, 3000, 0), None)
+        .unwrap();
+    assert_eq!(db.len(), 4);
+    assert_eq!(db.seen(), 4);
+
+    // A slower copy of a seen state is a duplicate, and the faster state now dominates too.
+    db.push(ContextWrapper::with_times(with_rupees(5), 800, 0), None)
+        .unwrap();
+    db.push(ContextWrapper::with_times(with_rupees(1), 600, 0), None)
+        .unwrap();
+    assert_eq!(db.len(), 4);
+}
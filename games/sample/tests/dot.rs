@@ -0,0 +1,80 @@
+use analyzer::context::*;
+use analyzer::dot::world_to_dot;
+use analyzer::world::World;
+use libsample::context::Context;
+use libsample::graph;
+use std::collections::HashMap;
+
+/// Returns the labels of the clusters each node is drawn in, outermost first.
+fn node_clusters(dot: &str) -> HashMap<String, Vec<String>> {
+    let mut nodes = HashMap::new();
+    let mut stack: Vec<String> = Vec::new();
+    for line in dot.lines().map(str::trim) {
+        if line.starts_with("subgraph cluster_") {
+            stack.push(String::new());
+        } else if let Some(label) = line.strip_prefix("label = \"") {
+            *stack.last_mut().unwrap() = label.trim_end_matches("\";").to_owned();
+        } else if line == "}" {
+            stack.pop();
+        } else if let Some((node, _)) = line.split_once(" [label = ") {
+            if !stack.is_empty() {
+                nodes.insert(node.to_owned(), stack.clone());
+            }
+        }
+    }
+    nodes
+}
+
+#[test]
+fn test_world_to_dot() {
+    let world = graph::World::new();
+    let ctx = ContextWrapper::new(Context::default());
+    let dot = world_to_dot(&*world, &ctx, false);
+    assert!(dot.starts_with("digraph world {"));
+
+    // Every spot the world draws is a node, clustered by region and area.
+    let nodes = node_clusters(&dot);
+    let drawn: Vec<_> = world
+        .get_all_spots()
+        .iter()
+        .filter(|&&s| s != graph::SpotId::None && world.should_draw_spot(s))
+        .collect();
+    assert_eq!(nodes.len(), drawn.len());
+    assert!(!nodes.contains_key("None"));
+    assert_eq!(
+        nodes["KF__Kokiri_Village__Midos_Porch"],
+        ["KF", "Kokiri Village"]
+    );
+    assert_eq!(nodes["Deku_Tree__Lobby__Entry"], ["Deku Tree", "Lobby"]);
+    assert_eq!(nodes["Kak__Spider_House__Entry"], ["Kak", "Spider House"]);
+    assert!(dot.contains("KF__Kokiri_Village__Midos_Porch [label = \"Mido's Porch\"];"));
+
+    // Exits, local movements and warps are colored by kind.
+    assert!(dot.contains(
+        "KF__Links_House__Entry -> KF__Kokiri_Village__Links_Porch [color = blue, \
+         tooltip = \"KF > Link's House > Entry ==> Kokiri Village > Link's Porch (1)\"];"
+    ));
+    assert!(
+        dot.contains("KF__Links_House__Start_Point -> KF__Links_House__Entry [color = gray50];")
+    );
+    assert!(dot.contains("warp_Save [label = \"Save\", shape = diamond, color = red];"));
+    assert!(dot.contains("warp_Save -> KF__Links_House__Start_Point [color = red];"));
+    assert!(dot.contains("warp_Minuet -> KF__Kokiri_Village__Shop_Porch [color = red];"));
+}
+
+#[test]
+fn test_world_to_dot_reachable() {
+    let world = graph::World::new();
+    let ctx = ContextWrapper::new(Context::default());
+    let dot = world_to_dot(&*world, &ctx, true);
+
+    // Mido blocks the way to the Deku Tree, and we don't know the song to warp with.
+    let nodes = node_clusters(&dot);
+    assert!(nodes.contains_key("KF__Kokiri_Village__Midos_Porch"));
+    assert!(!nodes.contains_key("Deku_Tree__Lobby__Entry"));
+    assert!(!nodes.contains_key("KF__Baba_Corridor__Village_Side"));
+    assert!(!dot.contains("Deku Tree"));
+    assert!(dot.contains("warp_Save"));
+    assert!(!dot.contains("warp_Minuet"));
+    assert!(!dot.contains("-> KF__Baba_Corridor__Village_Side"));
+}
@@ -0,0 +1,60 @@
+use analyzer::encoding::{make_codec, resolve_codec, sample_states, StateCodec, StateEncoding};
+use libsample::context::Context;
+use libsample::graph;
+
+#[test]
+fn test_zstd_roundtrip() {
+    let world = graph::World::new();
+    let samples = sample_states(&*world, &[Context::default()], 1024, 0);
+    let codec = StateCodec::train(&samples).unwrap();
+    assert_eq!(codec.encoding(), StateEncoding::ZstdDict);
+    assert!(!codec.record().dictionary.is_empty());
+
+    // A codec rebuilt from the record, as when the db is reopened, reads the same states.
+    let reopened = StateCodec::from_record(codec.record().clone());
+    let rmp = StateCodec::rmp();
+    let mut zstd_bytes = 0;
+    let mut rmp_bytes = 0;
+    for s in &samples {
+        let buf = codec.encode(s).unwrap();
+        assert_eq!(&codec.decode::<Context>(&buf).unwrap(), s);
+        assert_eq!(&reopened.decode::<Context>(&buf).unwrap(), s);
+        zstd_bytes += buf.len();
+        rmp_bytes += rmp.encode(s).unwrap().len();
+    }
+    assert!(zstd_bytes < rmp_bytes, "{} >= {}", zstd_bytes, rmp_bytes);
+}
+
+#[test]
+fn test_resolve_codec_keeps_stored_encoding() {
+    let world = graph::World::new();
+    let samples = sample_states(&*world, &[Context::default()], 1024, 0);
+    let trained = StateCodec::train(&samples).unwrap();
+    let buf = trained.encode(&samples[1]).unwrap();
+
+    let codec = resolve_codec(trained.record().clone(), StateEncoding::Rmp);
+    assert_eq!(codec.encoding(), StateEncoding::ZstdDict);
+    assert_eq!(codec.record().dictionary, trained.record().dictionary);
+    assert_eq!(codec.decode::<Context>(&buf).unwrap(), samples[1]);
+
+    let codec = resolve_codec(StateCodec::rmp().record().clone(), StateEncoding::ZstdDict);
+    assert_eq!(codec.encoding(), StateEncoding::Rmp);
+
+    // make_codec uses a stored encoding instead of training a new dictionary.
+    let codec = make_codec(
+        &*world,
+        &[Context::default()],
+        StateEncoding::ZstdDict,
+        Some(StateCodec::rmp().record().clone()),
+    )
+    .unwrap();
+    assert_eq!(codec.encoding(), StateEncoding::Rmp);
+    let codec = make_codec(
+        &*world,
+        &[Context::default()],
+        StateEncoding::Rmp,
+        Some(trained.record().clone()),
+    )
+    .unwrap();
+    assert_eq!(codec.record().dictionary, trained.record().dictionary);
+}
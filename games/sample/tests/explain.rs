@@ -0,0 +1,61 @@
+use analyzer::context::*;
+use analyzer::explain::*;
+use libsample::context::Context;
+use libsample::graph::{self, LocationId, SpotId};
+use libsample::items::Item;
+
+fn fix_items(fix: &Option<ItemFix<Item>>) -> Vec<Item> {
+    match fix {
+        Some(ItemFix::Smallest(items) | ItemFix::Reduced(items)) => {
+            let mut items = items.clone();
+            items.sort();
+            items
+        }
+        _ => panic!("No item fix found"),
+    }
+}
+
+#[test]
+fn test_explain_needs_items() {
+    let world = graph::World::new();
+    let ctx = ContextWrapper::new(Context::default());
+
+    // Mido wants to see a sword and shield before letting us by.
+    let show_mido = LocationId::KF__Kokiri_Village__Midos_Guardpost__Show_Mido;
+    let explanation = explain_location(&*world, ctx.clone(), show_mido, u32::MAX, 2);
+    assert!(!explanation.visited && !explanation.accessible);
+    assert_eq!(
+        explanation.spot,
+        SpotId::KF__Kokiri_Village__Midos_Guardpost
+    );
+    assert!(explanation.at_spot.is_some());
+    assert!(explanation.rule.is_some());
+    assert!(matches!(explanation.fix, Some(ItemFix::Smallest(_))));
+    let mut needed = vec![Item::Kokiri_Sword, Item::Buy_Deku_Shield];
+    needed.sort();
+    assert_eq!(fix_items(&explanation.fix), needed);
+    assert!(explanation.summary().contains("Smallest set"));
+
+    // Without searching sets that large, items are removed from the full set until none can be.
+    let explanation = explain_location(&*world, ctx, show_mido, u32::MAX, 1);
+    assert!(matches!(explanation.fix, Some(ItemFix::Reduced(_))));
+    assert_eq!(fix_items(&explanation.fix), needed);
+}
+
+#[test]
+fn test_explain_accessible() {
+    let world = graph::World::new();
+    let ctx = ContextWrapper::new(Context::default());
+    let chest = LocationId::KF__Boulder_Maze__Reward__Chest;
+
+    let explanation = explain_location(&*world, ctx.clone(), chest, u32::MAX, 2);
+    assert!(explanation.accessible);
+    assert!(explanation.fix.is_none());
+    assert!(explanation.summary().contains("is accessible"));
+
+    let mut ctx = ctx;
+    ctx.get_mut().visit(chest);
+    let explanation = explain_location(&*world, ctx, chest, u32::MAX, 2);
+    assert!(explanation.visited);
+    assert!(explanation.summary().contains("already visited"));
+}
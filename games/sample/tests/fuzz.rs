@@ -0,0 +1,15 @@
+//! AUTOGENERATED FOR sample - MODIFICATIONS WILL BE LOST
+
+use analyzer::testlib::fuzz::*;
+use libsample::context::Context;
+use libsample::graph::World;
+
+// Set FUZZ_SEED to try a different seed.
+#[test]
+fn fuzz_logic() {
+    let world = World::new();
+    let options = FuzzOptions::from_env();
+    if let Err(failure) = fuzz_world(&*world, &Context::default(), &options) {
+        panic!("{}", failure);
+    }
+}
@@ -0,0 +1,180 @@
+use analyzer::context::*;
+use analyzer::goal::Goal;
+use analyzer::greedy::greedy_search;
+use analyzer::world::World;
+use libsample::context::{enums::Tod, Context};
+use libsample::graph::{self, LocationId, SpotId};
+use libsample::items::Item;
+use yaml_rust::{Yaml, YamlLoader};
+
+fn load(s: &str) -> Yaml {
+    YamlLoader::load_from_str(s).unwrap().remove(0)
+}
+
+fn parse(world: &graph::World, s: &str) -> Result<Goal<graph::World>, String> {
+    Goal::from_yaml(world, &load(s))
+}
+
+#[test]
+fn test_goal_items() {
+    let world = graph::World::new();
+    let goal = parse(&world, "items: {Kokiri_Sword: 1, Gold_Skulltula_Token: 3}").unwrap();
+    assert_eq!(goal.items.len(), 2);
+    assert!(goal.items.contains(&(Item::Kokiri_Sword, 1)));
+    assert!(goal.items.contains(&(Item::Gold_Skulltula_Token, 3)));
+    assert!(goal.locations.is_empty());
+    assert_eq!(goal.spot, None);
+
+    // A list collects each once, and duplicates collapse.
+    let goal = parse(&world, "items: [Kokiri_Sword, Bow, Kokiri_Sword]").unwrap();
+    assert_eq!(goal.items.len(), 2);
+    assert!(goal.items.contains(&(Item::Kokiri_Sword, 1)));
+    assert!(goal.items.contains(&(Item::Bow, 1)));
+
+    let goal = parse(&world, "items: Kokiri_Sword").unwrap();
+    assert_eq!(goal.items, vec![(Item::Kokiri_Sword, 1)]);
+}
+
+#[test]
+fn test_goal_regions_and_locations() {
+    let world = graph::World::new();
+    let goal = parse(&world, "regions: [Deku Tree]").unwrap();
+    assert_eq!(goal.locations.len(), 27);
+    assert!(goal
+        .locations
+        .contains(&LocationId::Deku_Tree__Basement_1__Corner__Chest));
+    assert!(!goal
+        .locations
+        .contains(&LocationId::KF__Boulder_Maze__Reward__Chest));
+
+    // Locations already in a listed region aren't repeated.
+    let goal = parse(
+        &world,
+        r#"
+regions: Deku Tree
+locations:
+  - Deku Tree > Basement 1 > Corner > Chest
+  - KF > Boulder Maze > Reward > Chest
+  - KF > Boulder Maze > Reward > Chest
+spot: KF > Kokiri Village > Training Center
+"#,
+    )
+    .unwrap();
+    assert_eq!(goal.locations.len(), 28);
+    assert!(goal
+        .locations
+        .contains(&LocationId::KF__Boulder_Maze__Reward__Chest));
+    assert!(goal.locations.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(goal.spot, Some(SpotId::KF__Kokiri_Village__Training_Center));
+}
+
+#[test]
+fn test_goal_errors() {
+    let world = graph::World::new();
+    assert_eq!(parse(&world, "{}").unwrap_err(), "goal has no objectives");
+    assert_eq!(
+        parse(&world, "items: []").unwrap_err(),
+        "goal has no objectives"
+    );
+    assert!(parse(&world, "[Kokiri_Sword]")
+        .unwrap_err()
+        .contains("key-value map"));
+    assert!(parse(&world, "victory: true")
+        .unwrap_err()
+        .contains("Unrecognized goal key"));
+    assert!(parse(&world, "context: [tod]")
+        .unwrap_err()
+        .contains("key-value map"));
+    assert!(parse(&world, "context: {tod: Dusk}").is_err());
+    assert!(parse(&world, "context: {rupees: lots}").is_err());
+    assert!(parse(&world, "context: {nothing: 1}")
+        .unwrap_err()
+        .contains("Unrecognized"));
+
+    // Every bad entry is reported.
+    let err = parse(
+        &world,
+        "items: [Not_An_Item]\nregions: [Hyrule]\nspot: Nowhere",
+    )
+    .unwrap_err();
+    assert_eq!(err.lines().count(), 3, "{}", err);
+    assert!(parse(&world, "items: {Kokiri_Sword: lots}").is_err());
+}
+
+#[test]
+fn test_goal_met() {
+    let world = graph::World::new();
+    let goal = parse(
+        &world,
+        r#"
+items: {Kokiri_Sword: 1}
+locations: [KF > Boulder Maze > Reward > Chest]
+spot: KF > Kokiri Village > Training Center
+"#,
+    )
+    .unwrap();
+
+    let mut ctx = Context::default();
+    assert!(!goal.met_except_spot(&ctx));
+    assert_eq!(goal.items_needed(&ctx), vec![(Item::Kokiri_Sword, 1)]);
+    assert_eq!(
+        goal.locations_needed(&ctx),
+        vec![LocationId::KF__Boulder_Maze__Reward__Chest]
+    );
+
+    ctx.add_item(Item::Kokiri_Sword);
+    assert!(!goal.met_except_spot(&ctx));
+    ctx.visit(LocationId::KF__Boulder_Maze__Reward__Chest);
+    assert!(goal.met_except_spot(&ctx));
+    assert!(goal.items_needed(&ctx).is_empty());
+    assert!(goal.locations_needed(&ctx).is_empty());
+    assert!(!goal.met(&ctx));
+
+    ctx.set_position_raw(SpotId::KF__Kokiri_Village__Training_Center);
+    assert!(goal.met(&ctx));
+}
+
+#[test]
+fn test_goal_context() {
+    let world = graph::World::new();
+    let goal = parse(&world, "context: {tod: Night, rupees: 20}").unwrap();
+    assert_eq!(goal.context.len(), 2);
+    assert!(goal.items.is_empty());
+
+    let mut ctx = Context::default();
+    assert!(!goal.met_except_spot(&ctx));
+    assert!(!goal.met(&ctx));
+    ctx.set_tod(Tod::Night);
+    assert!(!goal.met(&ctx));
+    ctx.set_rupees(20);
+    assert!(goal.met_except_spot(&ctx));
+    assert!(goal.met(&ctx));
+    ctx.set_rupees(25);
+    assert!(!goal.met(&ctx));
+
+    // Context values are checked along with the rest of the goal.
+    let goal = parse(&world, "items: [Kokiri_Sword]\ncontext: {child: true}").unwrap();
+    ctx.set_child(true);
+    assert!(!goal.met(&ctx));
+    ctx.add_item(Item::Kokiri_Sword);
+    assert!(goal.met(&ctx));
+    ctx.set_child(false);
+    assert!(!goal.met(&ctx));
+}
+
+#[test]
+fn test_greedy_reaches_goal_spot() {
+    let mut world = graph::World::new();
+    world.goal = Some(
+        parse(
+            &world,
+            "items: [Kokiri_Sword]\nspot: KF > Boulder Maze > Entry",
+        )
+        .unwrap(),
+    );
+    let startctx = ContextWrapper::new(Context::default());
+    let win = greedy_search(&*world, &startctx, u32::MAX, 2).expect("Greedy search didn't win");
+    assert!(world.won(win.get()));
+    assert!(win.get().has(Item::Kokiri_Sword));
+    assert_eq!(win.get().position(), SpotId::KF__Boulder_Maze__Entry);
+}
@@ -0,0 +1,107 @@
+use analyzer::context::*;
+use analyzer::direct::DirectPathsMap;
+use analyzer::estimates::ContextScorer;
+use analyzer::goal::Goal;
+use analyzer::minimize::mutate_segment_windows;
+use analyzer::route::{route_from_string, PartialRoute};
+use analyzer::world::World;
+use libsample::context::Context;
+use libsample::graph::{self, LocationId};
+use libsample::observe::ObservationMatcher;
+use yaml_rust::YamlLoader;
+
+type Paths = DirectPathsMap<
+    graph::World,
+    Context,
+    ObservationMatcher<PartialRoute<Context>, Option<PartialRoute<Context>>>,
+>;
+
+const CHESTS: &str = r#"
+locations:
+  - KF > Boulder Maze > Reward > Chest
+  - KF > Shop > Entry > Blue Rupee
+  - KF > Mido's House > Entry > Top Left Chest
+  - KF > Mido's House > Entry > Top Right Chest
+"#;
+
+// Goes back and forth between Mido's House and the Shop.
+const SLOW_ROUTE: &str = r#"
+* Collect Kokiri_Sword from KF > Boulder Maze > Reward > Chest
+* Collect Rupees_50 from KF > Mido's House > Entry > Top Right Chest
+* Collect Rupees_5 from KF > Shop > Entry > Blue Rupee
+* Collect Rupees_5 from KF > Mido's House > Entry > Top Left Chest
+"#;
+
+fn collections(ctx: &ContextWrapper<Context>) -> Vec<LocationId> {
+    ctx.recent_history()
+        .iter()
+        .filter_map(|h| match h {
+            History::G(_, loc_id) => Some(*loc_id),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_segment_windows() {
+    let mut world = graph::World::new();
+    let goal = YamlLoader::load_from_str(CHESTS).unwrap().remove(0);
+    world.goal = Some(Goal::from_yaml(&*world, &goal).unwrap());
+    let startctx = Context::default();
+    let scorer = ContextScorer::shortest_paths(&*world, &startctx, 32_768);
+    let direct_paths = Paths::new(ContextScorer::shortest_paths_tree_free_edges(
+        &*world, &startctx,
+    ));
+
+    let slow = route_from_string(&*world, &startctx, SLOW_ROUTE, scorer.get_algo()).unwrap();
+    assert!(world.won(slow.get()));
+    let solved = mutate_segment_windows(
+        &*world,
+        &startctx,
+        u32::MAX,
+        3,
+        2,
+        16_384,
+        slow.to_solution(),
+        scorer.get_algo(),
+        &direct_paths,
+    )
+    .expect("No window was improved");
+    assert!(solved.elapsed() < slow.elapsed());
+    assert!(world.won(solved.get()));
+
+    // The window's collections are reordered to visit Mido's House once.
+    let order = collections(&solved);
+    assert_eq!(order.len(), 4, "{:?}", order);
+    assert_eq!(
+        order[..2],
+        [
+            LocationId::KF__Boulder_Maze__Reward__Chest,
+            LocationId::KF__Shop__Entry__Blue_Rupee,
+        ],
+        "{:?}",
+        order
+    );
+
+    // The spliced route replays from the start as a valid winning route.
+    let mut replay = ContextWrapper::new(startctx.clone());
+    for step in solved.recent_history() {
+        replay.assert_and_replay(&*world, *step);
+    }
+    assert_eq!(replay.elapsed(), solved.elapsed());
+    assert!(world.won(replay.get()));
+
+    // Windows can't be larger than the number of collections.
+    assert!(mutate_segment_windows(
+        &*world,
+        &startctx,
+        u32::MAX,
+        5,
+        2,
+        16_384,
+        slow.to_solution(),
+        scorer.get_algo(),
+        &direct_paths,
+    )
+    .is_none());
+}
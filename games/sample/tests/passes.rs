@@ -0,0 +1,294 @@
+use analyzer::access::move_to;
+use analyzer::context::*;
+use analyzer::direct::DirectPathsMap;
+use analyzer::estimates::ContextScorer;
+use analyzer::greedy::greedy_search;
+use analyzer::matchertrie::MatcherTrie;
+use analyzer::minimize::SEGMENT_WINDOW_SIZES;
+use analyzer::passes::*;
+use analyzer::route::PartialRoute;
+use analyzer::solutions::{Solution, SolutionSuffix};
+use analyzer::world::World;
+use analyzer::CommonHasher;
+use libsample::context::Context;
+use libsample::graph::{self, SpotId};
+use libsample::observe::ObservationMatcher;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use yaml_rust::YamlLoader;
+
+type Matcher =
+    ObservationMatcher<SolutionSuffix<Context>, HashSet<SolutionSuffix<Context>, CommonHasher>>;
+type Paths = DirectPathsMap<
+    graph::World,
+    Context,
+    ObservationMatcher<PartialRoute<Context>, Option<PartialRoute<Context>>>,
+>;
+type Step = PassStep<graph::World, Context, Matcher, Paths>;
+type Env<'a> = PassEnv<'a, graph::World, Context, Matcher, Paths>;
+
+fn by_name(
+    name: &str,
+) -> Result<Box<dyn MinimizePass<graph::World, Context, Matcher, Paths>>, String> {
+    pass_by_name(name)
+}
+
+fn parse(s: &str) -> Result<Vec<Step>, String> {
+    parse_pipeline(&YamlLoader::load_from_str(s).unwrap()[0])
+}
+
+fn names(steps: &[Step]) -> Vec<(String, Repeat)> {
+    steps.iter().map(|s| (s.pass.name(), s.repeat)).collect()
+}
+
+fn named(list: &[(&str, Repeat)]) -> Vec<(String, Repeat)> {
+    list.iter().map(|&(n, r)| (n.to_owned(), r)).collect()
+}
+
+const ONCE: Repeat = Repeat::Times(1);
+
+#[test]
+fn test_pass_by_name() {
+    for &name in PASS_NAMES {
+        if name == "segment_windows" {
+            continue;
+        }
+        let pass = by_name(name).unwrap();
+        assert_eq!(pass.name(), name);
+    }
+    for size in SEGMENT_WINDOW_SIZES {
+        let name = format!("segment_windows_{}", size);
+        let pass = by_name(&name).unwrap();
+        assert_eq!(pass.name(), name);
+    }
+
+    let err = |name| by_name(name).err().expect("Pass name should be rejected");
+    assert!(err("bogus").contains("Unrecognized minimize pass 'bogus'"));
+    // The plain name expands to every size in a pipeline, but isn't a single pass.
+    assert!(err("segment_windows").contains("Unrecognized"));
+    assert!(err("segment_windows_99").contains("must be in"));
+    assert!(err("segment_windows_x").contains("Bad segment window size"));
+}
+
+#[test]
+fn test_parse_pipeline() {
+    let steps = parse(
+        r#"
+passes:
+  - trie
+  - parallel: [greedy_collections, spot_revisits]
+  - name: reorder_collections
+    repeat: until_stable
+  - name: segment_windows_4
+    repeat: 2
+  - segment_windows
+  - parallel: [trie, canon_locations]
+    repeat: until_stable
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        names(&steps),
+        named(&[
+            ("trie", ONCE),
+            ("greedy_collections+spot_revisits", ONCE),
+            ("reorder_collections", Repeat::UntilStable),
+            ("segment_windows_4", Repeat::Times(2)),
+            ("segment_windows_3", ONCE),
+            ("segment_windows_4", ONCE),
+            ("segment_windows_5", ONCE),
+            ("trie+canon_locations", Repeat::UntilStable),
+        ])
+    );
+
+    // Segment windows in parallel run every size together.
+    let steps = parse("passes: [{parallel: [segment_windows]}]").unwrap();
+    assert_eq!(
+        names(&steps),
+        named(&[(
+            "segment_windows_3+segment_windows_4+segment_windows_5",
+            ONCE
+        )])
+    );
+}
+
+#[test]
+fn test_parse_pipeline_errors() {
+    let err = |s| parse(s).err().expect("Pipeline should be rejected");
+    assert!(err("{}").contains("Expected a list of 'passes'"));
+    assert!(err("passes: trie").contains("Expected a list of 'passes'"));
+
+    // Every bad entry is reported.
+    let e = err(r#"
+passes:
+  - trie
+  - bogus
+  - parallel: [spot_revisits, nonsense]
+  - parallel: [[trie]]
+  - repeat: 2
+  - name: trie
+    repeat: often
+  - 3
+"#);
+    assert_eq!(e.lines().count(), 6, "{}", e);
+    assert!(e.contains("'bogus'"), "{}", e);
+    assert!(e.contains("'nonsense'"), "{}", e);
+    assert!(e.contains("Parallel passes should be names"), "{}", e);
+    assert!(e.contains("Pass is missing a name"), "{}", e);
+    assert!(e.contains("Expected a pass name or map"), "{}", e);
+}
+
+#[test]
+fn test_default_pipeline() {
+    let steps: Vec<Step> = default_pipeline();
+    assert_eq!(
+        names(&steps),
+        named(&[
+            ("trie", ONCE),
+            ("greedy_collections+spot_revisits", ONCE),
+            ("canon_locations", Repeat::UntilStable),
+            ("reorder_collections", Repeat::UntilStable),
+            ("segment_windows_3", Repeat::UntilStable),
+            ("segment_windows_4", Repeat::UntilStable),
+            ("segment_windows_5", Repeat::UntilStable),
+            ("trie", ONCE),
+        ])
+    );
+}
+
+#[test]
+fn test_read_pipeline() {
+    let path = std::env::temp_dir().join(format!("sample-passes-{}.yaml", std::process::id()));
+    let read = |required| -> Result<Option<Vec<Step>>, String> { read_pipeline(&path, required) };
+
+    std::fs::write(&path, "name: sample\n").unwrap();
+    assert!(read(false).unwrap().is_none());
+    assert!(read(true).is_err());
+
+    std::fs::write(
+        &path,
+        "passes: [trie, {name: canon_locations, repeat: 3}]\n",
+    )
+    .unwrap();
+    let steps = read(false).unwrap().unwrap();
+    assert_eq!(
+        names(&steps),
+        named(&[("trie", ONCE), ("canon_locations", Repeat::Times(3))])
+    );
+
+    std::fs::write(&path, "passes: [bogus]\n").unwrap();
+    assert!(read(false).is_err());
+    std::fs::remove_file(&path).unwrap();
+    assert!(read(false).err().unwrap().contains("Couldn't read"));
+}
+
+/// Returns the queued routes one per run, last first, as if each run found the next.
+struct Queued(&'static str, Mutex<Vec<ContextWrapper<Context>>>);
+
+impl Queued {
+    fn new(name: &'static str, routes: &[&ContextWrapper<Context>]) -> Self {
+        Self(
+            name,
+            Mutex::new(routes.iter().map(|&c| c.clone()).collect()),
+        )
+    }
+}
+
+impl MinimizePass<graph::World, Context, Matcher, Paths> for Queued {
+    fn name(&self) -> String {
+        self.0.to_owned()
+    }
+
+    fn run(
+        &self,
+        _env: &Env,
+        _solution: Arc<Solution<Context>>,
+    ) -> Option<ContextWrapper<Context>> {
+        self.1.lock().unwrap().pop()
+    }
+}
+
+#[test]
+fn test_run_pipeline() {
+    let world = graph::World::new();
+    let startctx = Context::default();
+    let scorer = ContextScorer::shortest_paths(&*world, &startctx, 32_768);
+    let direct_paths = Paths::new(ContextScorer::shortest_paths_tree_free_edges(
+        &*world, &startctx,
+    ));
+    let trie = MatcherTrie::<Matcher, SolutionSuffix<Context>>::default();
+    let env = Env {
+        world: &*world,
+        startctx: &startctx,
+        max_depth: 2,
+        max_states: 1024,
+        shortest_paths: scorer.get_algo(),
+        direct_paths: &direct_paths,
+        trie: &trie,
+    };
+
+    // Winning routes that each take a detour after the last one wins.
+    let fast = greedy_search(&*world, &ContextWrapper::new(startctx.clone()), u32::MAX, 2)
+        .expect("Greedy search didn't win");
+    let mid = move_to(
+        &*world,
+        fast.clone(),
+        SpotId::KF__Kokiri_Village__Midos_Porch,
+        scorer.get_algo(),
+    )
+    .unwrap();
+    let slow = move_to(
+        &*world,
+        mid.clone(),
+        SpotId::KF__Boulder_Maze__Reward,
+        scorer.get_algo(),
+    )
+    .unwrap();
+    assert!(world.won(slow.get()));
+    assert!(fast.elapsed() < mid.elapsed() && mid.elapsed() < slow.elapsed());
+
+    // Repeating until stable takes every improvement, and feeds each into the next run.
+    let pipeline = vec![Step {
+        pass: Box::new(Queued::new("queued", &[&fast, &mid])),
+        repeat: Repeat::UntilStable,
+    }];
+    let (best, reports) = run_pipeline(&env, &pipeline, slow.to_solution());
+    assert_eq!(best.unwrap().elapsed(), fast.elapsed());
+    assert_eq!(reports[0].name, "queued");
+    assert_eq!(reports[0].runs, 3);
+    assert_eq!(reports[0].improvements, 2);
+    assert_eq!(reports[0].saved, slow.elapsed() - fast.elapsed());
+
+    // A fixed number of runs stops there, and a slower route ends the step.
+    let pipeline = vec![
+        Step {
+            pass: Box::new(Queued::new("once", &[&fast, &mid])),
+            repeat: ONCE,
+        },
+        Step {
+            pass: Box::new(Queued::new("worse", &[&fast, &slow])),
+            repeat: Repeat::Times(2),
+        },
+    ];
+    let (best, reports) = run_pipeline(&env, &pipeline, slow.to_solution());
+    assert_eq!(best.unwrap().elapsed(), mid.elapsed());
+    assert_eq!((reports[0].runs, reports[0].improvements), (1, 1));
+    assert_eq!((reports[1].runs, reports[1].improvements), (1, 0));
+
+    // Parallel passes run on the same route and the fastest result is kept.
+    let pipeline = vec![Step {
+        pass: Box::new(ParallelPass(vec![
+            Box::new(Queued::new("a", &[&mid])),
+            Box::new(Queued::new("b", &[&fast])),
+            Box::new(Queued::new("c", &[])),
+        ])),
+        repeat: ONCE,
+    }];
+    let (best, reports) = run_pipeline(&env, &pipeline, slow.to_solution());
+    assert_eq!(best.unwrap().elapsed(), fast.elapsed());
+    assert_eq!(reports[0].name, "a+b+c");
+    assert_eq!(reports[0].improvements, 1);
+
+    let (best, reports) = run_pipeline(&env, &[], slow.to_solution());
+    assert!(best.is_none() && reports.is_empty());
+}
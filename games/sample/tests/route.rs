@@ -33,3 +33,65 @@ fn test_parse() {
         ))
     )
 }
+
+#[test]
+fn test_migrate_route() {
+    let world = graph::World::new();
+    let startctx = context::Context::default();
+    let scorer = ContextScorer::shortest_paths(&*world, &startctx, 32_768);
+
+    // Line numbers count from the top of the solution, including its header.
+    let route = "Solution #0-0, est. 10000ms:\n\
+                 in full:\n\
+                 Move to KF > Kokiri Village > Midos Porch\n\
+                 # The chest isn't at Mido's Porch, but collecting paths to it.\n\
+                 * Collect Kokiri_Sword from KF > Boulder Maze > Reward > Chest\n\
+                 * Collect Rupees_5 from KF > Mido's House > Entry > Top Left Chst\n\
+                 * Collect Kokiri_Sword from KF > Boulder Maze > Reward > Chest\n";
+    let migrated = migrate_route(&*world, &startctx, route, scorer.get_algo());
+
+    assert_eq!(migrated.changes.len(), 3);
+    match &migrated.changes[0] {
+        RouteChange::Renamed { line, old, new, .. } => {
+            assert_eq!(*line, 3);
+            assert_eq!(old, "Move to KF > Kokiri Village > Midos Porch");
+            assert_eq!(new, "Move to KF > Kokiri Village > Mido's Porch");
+        }
+        c => panic!("Expected a renamed spot, got {}", c),
+    }
+    match &migrated.changes[1] {
+        RouteChange::Renamed { line, new, .. } => {
+            assert_eq!(*line, 6);
+            assert_eq!(
+                new,
+                "* Collect Rupees_5 from KF > Mido's House > Entry > Top Left Chest"
+            );
+        }
+        c => panic!("Expected a renamed location, got {}", c),
+    }
+    // The chest was already collected, so the repeat can't be repaired.
+    match &migrated.changes[2] {
+        RouteChange::Dropped { line, error, .. } => {
+            assert_eq!(*line, 7);
+            assert!(error.contains("route step 7"), "{}", error);
+        }
+        c => panic!("Expected a dropped collection, got {}", c),
+    }
+
+    assert_eq!(
+        migrated.route,
+        "Move to KF > Kokiri Village > Mido's Porch\n\
+         # The chest isn't at Mido's Porch, but collecting paths to it.\n\
+         * Collect Kokiri_Sword from KF > Boulder Maze > Reward > Chest\n\
+         * Collect Rupees_5 from KF > Mido's House > Entry > Top Left Chest"
+    );
+    assert!(migrated.ctx.get().has(Item::Kokiri_Sword));
+    assert!(migrated
+        .ctx
+        .get()
+        .visited(graph::LocationId::KF__Midos_House__Entry__Top_Left_Chest));
+
+    // The migrated route replays as it is.
+    let ctx = route_from_string(&*world, &startctx, &migrated.route, scorer.get_algo()).unwrap();
+    assert_eq!(ctx.elapsed(), migrated.ctx.elapsed());
+}
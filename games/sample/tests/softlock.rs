@@ -0,0 +1,41 @@
+use analyzer::access::can_win;
+use analyzer::context::*;
+use analyzer::softlock::find_softlocks;
+use analyzer::world::World;
+use libsample::context::Context;
+use libsample::graph::{self, ExitId, SpotId};
+use libsample::items::Item;
+
+#[test]
+fn test_one_way_dungeon_entry() {
+    let world = graph::World::new();
+    // Mido lets us through to the Deku Tree, but we have nothing to fight with inside,
+    // and saving warps back to the dungeon entrance.
+    let mut startctx = Context::default();
+    startctx.add_item(Item::Showed_Mido);
+    assert!(can_win(&*world, &startctx, u32::MAX).is_ok());
+
+    let report = find_softlocks(&*world, ContextWrapper::new(startctx), u32::MAX, 1);
+    assert_eq!(report.explored, 1);
+    let entry = History::E(ExitId::KF__Outside_Deku_Tree__Mouth__ex__Deku_Tree__Lobby__Entry_1);
+    let softlock = report
+        .softlocks
+        .iter()
+        .find(|s| s.step == entry)
+        .unwrap_or_else(|| {
+            panic!(
+                "Entering the Deku Tree wasn't a softlock:\n{}",
+                report.summary()
+            )
+        });
+
+    assert_eq!(
+        softlock.after.get().position(),
+        SpotId::Deku_Tree__Lobby__Entry
+    );
+    assert_eq!(softlock.after.recent_history().last(), Some(&entry));
+    assert!(can_win(&*world, softlock.before.get(), u32::MAX).is_ok());
+    assert!(can_win(&*world, softlock.after.get(), u32::MAX).is_err());
+    assert!(!softlock.after.get().has(Item::Kokiri_Sword));
+    assert!(report.summary().contains(&entry.to_string()));
+}
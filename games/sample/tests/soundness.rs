@@ -0,0 +1,51 @@
+use analyzer::context::*;
+use analyzer::greedy::greedy_search;
+use analyzer::observer::Observer;
+use analyzer::soundness::*;
+use libsample::context::Context;
+use libsample::graph;
+use libsample::observe::OneObservation;
+
+#[test]
+fn test_solution_is_sound() {
+    let world = graph::World::new();
+    let startctx = Context::default();
+    let win = greedy_search(&*world, &ContextWrapper::new(startctx.clone()), u32::MAX, 2)
+        .expect("Greedy search didn't win");
+    let options = SoundnessOptions::default();
+
+    let checked = check_observation_soundness(&*world, &startctx, &[win.to_solution()], &options)
+        .unwrap_or_else(|unsound| panic!("{}", unsound.summary()));
+    assert_eq!(checked, win.recent_history().len());
+}
+
+#[test]
+fn test_dropped_observation_is_unsound() {
+    let world = graph::World::new();
+    let startctx = Context::default();
+    let win = greedy_search(&*world, &ContextWrapper::new(startctx.clone()), u32::MAX, 2)
+        .expect("Greedy search didn't win");
+    let options = SoundnessOptions::default();
+
+    // Every step but global actions and warps depends on where it's taken from.
+    let unsound = check_soundness_with(
+        &*world,
+        &startctx,
+        &[win.to_solution()],
+        &options,
+        |state, step| {
+            let mut obs = <Context as Ctx>::Observer::default();
+            state.observe_replay(&*world, step, &mut obs);
+            obs.apply_observations();
+            let mut observations = obs.to_vec(state);
+            observations.retain(|o| !matches!(o, OneObservation::Position(_)));
+            observations
+        },
+    )
+    .err()
+    .expect("Dropping the position observation wasn't caught");
+    assert_eq!(unsound.solution, 0);
+    assert!(unsound.expected);
+    assert_eq!(unsound.step, win.recent_history()[unsound.index]);
+    assert!(unsound.summary().contains("Unsound observations"));
+}
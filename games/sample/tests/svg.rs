@@ -0,0 +1,72 @@
+use analyzer::context::*;
+use analyzer::estimates::ContextScorer;
+use analyzer::route::route_from_string;
+use analyzer::svg::route_to_svg;
+use analyzer::world::World;
+use libsample::context::Context;
+use libsample::graph::{self, SpotId, WarpId};
+use libsample::items::Item;
+use std::collections::BTreeSet;
+
+#[test]
+fn test_route_to_svg() {
+    let world = graph::World::new();
+    let mut startctx = Context::default();
+    startctx.add_item(Item::Showed_Mido);
+    let scorer = ContextScorer::shortest_paths(&*world, &startctx, 32_768);
+
+    let route = r#"
+    * Collect Kokiri_Sword from KF > Boulder Maze > Reward > Chest
+    * Collect Deku_Stick_Drop from KF > Baba Corridor > Deku Babas > Sticks
+    * Collect Gossip_Stone_Deku_Left from KF > Outside Deku Tree > Left > Gossip Stone
+    "#;
+    let mut ctx = route_from_string(&*world, &startctx, route, scorer.get_algo()).unwrap();
+    ctx.replay(
+        &*world,
+        History::W(WarpId::Save, SpotId::KF__Links_House__Start_Point),
+    );
+    let svg = route_to_svg(&*world, &startctx, ctx.recent_history());
+    assert!(svg.starts_with("<svg "));
+    assert!(svg.ends_with("</svg>\n"));
+
+    // One panel for each area the route moves through.
+    let mut replay = ContextWrapper::new(startctx.clone());
+    let mut areas = BTreeSet::new();
+    for step in ctx.recent_history() {
+        replay.replay(&*world, *step);
+        let pos = replay.get().position();
+        if graph::World::spot_coordinate(pos).is_some() {
+            areas.insert(format!("{}", pos).rsplit_once(" > ").unwrap().0.to_owned());
+        }
+    }
+    assert!(areas.contains("KF > Boulder Maze"));
+    assert!(areas.contains("KF > Outside Deku Tree"));
+    assert_eq!(svg.matches("<rect ").count(), areas.len());
+    for area in &areas {
+        assert!(
+            svg.contains(&format!(">{}</text>", area.replace('>', "&gt;"))),
+            "No panel for {}",
+            area
+        );
+    }
+    assert!(svg.contains(&format!(
+        "Route of {}ms: 3 visits in {} areas",
+        ctx.elapsed(),
+        areas.len()
+    )));
+
+    // Visits are numbered in order.
+    let sword = svg.find("<title>1. Kokiri_Sword at ").unwrap();
+    let sticks = svg.find("<title>2. Deku_Stick_Drop at ").unwrap();
+    let stone = svg.find("<title>3. Gossip_Stone_Deku_Left at ").unwrap();
+    assert!(sword < sticks && sticks < stone);
+    assert!(svg.contains(r#"text-anchor="middle" font-size="9">3</text>"#));
+    assert!(!svg.contains("<title>4. "));
+
+    // Exits into an area are solid arrows, and warps are dashed.
+    assert!(svg.contains("<title>from KF &gt; Kokiri Village</title>"));
+    let warp = svg.find("<title>Save</title>").expect("No warp arrow");
+    let line_end = warp + svg[warp..].find("/>").unwrap();
+    assert!(svg[warp..line_end].contains(r#"stroke="purple""#));
+    assert!(svg[warp..line_end].contains("stroke-dasharray"));
+}
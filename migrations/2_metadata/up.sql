@@ -0,0 +1,6 @@
+
+CREATE TABLE db_metadata (
+    name VARCHAR(32) NOT NULL, -- which table the metadata is for
+    data BLOB NOT NULL, -- serialized DbMetadata
+    PRIMARY KEY(name)
+);